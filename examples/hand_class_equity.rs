@@ -0,0 +1,34 @@
+//! Sample the equity table compiled into `src/hand_class/equity.rs`.
+//!
+//! ```text
+//! cargo run --release --example hand_class_equity > src/hand_class/equity.rs
+//! ```
+
+use poker_rs::equity::equity_vs_random;
+use poker_rs::hand_class::HandClass;
+use rand::{rngs::StdRng, SeedableRng};
+
+const TRIALS: usize = 100_000;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut rng = StdRng::seed_from_u64(169);
+    let mut equity = Vec::with_capacity(HandClass::COUNT);
+    for class in HandClass::all() {
+        equity.push(equity_vs_random(&class.combos()[0], 1, &[], TRIALS, &mut rng)?);
+    }
+    println!("//! Generated by `cargo run --release --example hand_class_equity`; do not edit.");
+    println!("//!");
+    println!("//! Equity of each class against one random hand, in grid order, from");
+    println!("//! {TRIALS} run-outs per class.");
+    println!();
+    println!("use super::HandClass;");
+    println!();
+    println!("#[rustfmt::skip]");
+    println!("pub(super) const VS_RANDOM: [f32; HandClass::COUNT] = [");
+    for row in equity.chunks(13) {
+        let cells: Vec<String> = row.iter().map(|v| format!("{v:.4}")).collect();
+        println!("    {},", cells.join(", "));
+    }
+    println!("];");
+    Ok(())
+}
@@ -0,0 +1,51 @@
+//! Solve the push/fold charts compiled into `src/push_fold/tables.rs`.
+//!
+//! ```text
+//! cargo run --release --example push_fold_tables > src/push_fold/tables.rs
+//! ```
+
+use poker_rs::push_fold::{chart_stacks, chart_values, EquityMatrix, MAX_BEHIND};
+use rand::{rngs::StdRng, SeedableRng};
+
+const TRIALS: usize = 1000;
+const ITERATIONS: usize = 300;
+
+fn print_table(name: &str, tables: &[Vec<f64>]) {
+    println!("#[rustfmt::skip]");
+    println!("pub(super) const {name}: [[f32; CLASSES]; MAX_BEHIND] = [");
+    for table in tables {
+        println!("    [");
+        for row in table.chunks(13) {
+            let cells: Vec<String> = row.iter().map(|v| format!("{v:.1}")).collect();
+            println!("        {},", cells.join(", "));
+        }
+        println!("    ],");
+    }
+    println!("];");
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut rng = StdRng::seed_from_u64(169);
+    let matrix = EquityMatrix::compute(TRIALS, &mut rng)?;
+    let mut push = Vec::new();
+    let mut call = Vec::new();
+    for behind in 1..=MAX_BEHIND {
+        let (p, c) = chart_values(&matrix, behind, chart_stacks(), ITERATIONS)?;
+        eprintln!("solved {behind} behind");
+        push.push(p);
+        call.push(c);
+    }
+    println!("//! Generated by `cargo run --release --example push_fold_tables`; do not edit.");
+    println!("//!");
+    println!("//! One table per number of opponents behind the pusher, in chart grid order.");
+    println!(
+        "//! Solved from {TRIALS} run-outs per pair of classes and {ITERATIONS} iterations per stack."
+    );
+    println!();
+    println!("use super::{{CLASSES, MAX_BEHIND}};");
+    println!();
+    print_table("PUSH", &push);
+    println!();
+    print_table("CALL", &call);
+    Ok(())
+}
@@ -6,6 +6,7 @@
 //! implement bot coordination.
 
 use crate::engine::GameEngine;
+use crate::view::PlayerView;
 use core::fmt;
 use std::time::{Duration, Instant};
 
@@ -18,7 +19,7 @@ pub enum AgentKind {
 }
 
 /// Seat-level action intents, typically produced by a UI for a human player.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum Action {
     Fold,
@@ -29,14 +30,27 @@ pub enum Action {
     RaiseTo(u64),
 }
 
+/// What a losing player does with their hand at showdown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShowdownChoice {
+    #[default]
+    Show,
+    Muck,
+}
+
 /// A seat controller that can act for a player when it is their turn.
+///
+/// Agents only ever see a [`PlayerView`] of their own seat, never the engine,
+/// so they cannot read opponents' hole cards or act out of turn.
 pub trait PlayerAgent {
-    /// Called when `seat` is the current actor. Implementations may throttle internally.
-    fn on_turn(
-        &mut self,
-        engine: &mut dyn GameEngine,
-        seat: usize,
-    ) -> Result<bool, crate::game::ActionError>;
+    /// Called with this seat's view while a hand is in progress. Return the
+    /// action to take, or `None` to wait (implementations may throttle internally).
+    fn on_turn(&mut self, view: &PlayerView) -> Option<Action>;
+    /// Called at showdown when this seat's hand is beaten by one already shown
+    /// and it may muck instead; default is to show.
+    fn on_showdown(&mut self, _view: &PlayerView) -> ShowdownChoice {
+        ShowdownChoice::Show
+    }
     /// The kind of this agent (human, bot, etc.).
     fn kind(&self) -> AgentKind {
         AgentKind::Human
@@ -47,6 +61,52 @@ pub trait PlayerAgent {
     }
 }
 
+/// Ask `agent` for a decision from `seat`'s view and apply it to the engine.
+///
+/// Returns `Ok(true)` when an action was taken.
+///
+/// ```
+/// use poker_rs::agents::{play_turn, BotAgent, BotProfile};
+/// use poker_rs::game::Game;
+///
+/// let mut game = Game::new(3, 1000, 5, 10);
+/// game.new_hand().unwrap();
+/// let seat = game.current();
+/// let mut bot = BotAgent::new(BotProfile::default().with_seed(1));
+/// assert!(play_turn(&mut bot, &mut game, seat).unwrap());
+/// assert_ne!(game.current(), seat);
+/// ```
+pub fn play_turn(
+    agent: &mut dyn PlayerAgent,
+    engine: &mut dyn GameEngine,
+    seat: usize,
+) -> Result<bool, crate::game::ActionError> {
+    let view = engine.player_view(seat);
+    match agent.on_turn(&view) {
+        Some(action) => engine.act(seat, action).map(|_| true),
+        None => Ok(false),
+    }
+}
+
+/// Ask `agent` whether to show or muck `seat`'s losing hand and apply it.
+///
+/// Returns `Ok(false)` when the seat has no show/muck decision pending.
+pub fn play_showdown(
+    agent: &mut dyn PlayerAgent,
+    engine: &mut dyn GameEngine,
+    seat: usize,
+) -> Result<bool, crate::game::ShowdownError> {
+    if !engine.pending_mucks().contains(&seat) {
+        return Ok(false);
+    }
+    let view = engine.player_view(seat);
+    match agent.on_showdown(&view) {
+        ShowdownChoice::Show => engine.show(seat)?,
+        ShowdownChoice::Muck => engine.muck(seat)?,
+    }
+    Ok(true)
+}
+
 mod bots;
 
 pub use bots::{BotAgent, BotConfig, BotProfile, Difficulty};
@@ -79,30 +139,15 @@ impl PlayerAgent for HumanAgent {
         self.pending = Some(action);
         true
     }
-    fn on_turn(
-        &mut self,
-        engine: &mut dyn GameEngine,
-        seat: usize,
-    ) -> Result<bool, crate::game::ActionError> {
-        if matches!(engine.street(), crate::game::Street::Showdown) {
+    fn on_turn(&mut self, view: &PlayerView) -> Option<Action> {
+        if matches!(view.street, crate::game::Street::Showdown) {
             self.pending = None;
-            return Ok(false);
+            return None;
         }
-        if engine.current() != seat {
-            return Ok(false);
+        if !view.is_my_turn() {
+            return None;
         }
-        if let Some(act) = self.pending.take() {
-            return match act {
-                Action::Fold => engine.action_fold(),
-                Action::CheckCall => engine.action_check_call(),
-                Action::BetMin => engine.action_bet_min(),
-                Action::RaiseMin => engine.action_raise_min(),
-                Action::Bet(amount) => engine.action_bet(amount),
-                Action::RaiseTo(amount) => engine.action_raise_to(amount),
-            }
-            .map(|_| true);
-        }
-        Ok(false)
+        self.pending.take()
     }
 }
 
@@ -204,7 +249,7 @@ impl AgentTable {
                     }
                 }
             }
-            let acted = agent.on_turn(engine, seat)?;
+            let acted = play_turn(agent.as_mut(), engine, seat)?;
             if acted && self.min_action_delay > Duration::from_millis(0) {
                 self.next_action_at = Some(now + self.min_action_delay);
             }
@@ -213,6 +258,24 @@ impl AgentTable {
         Ok(false)
     }
 
+    /// Let agents decide, in showdown order, whether to show or muck losing
+    /// hands. Seats without an agent show. Returns the number of decisions.
+    pub fn on_showdown(
+        &mut self,
+        engine: &mut dyn GameEngine,
+    ) -> Result<usize, crate::game::ShowdownError> {
+        let pending = engine.pending_mucks();
+        for &seat in &pending {
+            match self.seats.get_mut(seat) {
+                Some(Some(agent)) => {
+                    play_showdown(agent.as_mut(), engine, seat)?;
+                }
+                _ => engine.show(seat)?,
+            }
+        }
+        Ok(pending.len())
+    }
+
     /// Remove all agents.
     pub fn clear(&mut self) {
         for a in &mut self.seats {
@@ -236,7 +299,7 @@ mod tests {
     #[test]
     fn delay_ms_throttle_actions() {
         let mut g = mk_game(3);
-        g.new_hand();
+        g.new_hand().unwrap();
         let seat = g.current;
         let mut profile = BotProfile::for_difficulty(Difficulty::Easy).with_seed(7);
         profile.min_delay_ms = 15;
@@ -252,24 +315,24 @@ mod tests {
         }
 
         // First tick should schedule the bot and not act yet.
-        let _ = bot.on_turn(&mut g, seat).unwrap();
+        let _ = play_turn(&mut bot, &mut g, seat).unwrap();
         assert_eq!(g.current, seat, "should remain on same seat due to delay");
         assert!(g.players[seat].last_action.is_none(), "no action before delay");
 
         // After delay, the waiting bot should run.
         thread::sleep(Duration::from_millis(20));
-        let _ = bot.on_turn(&mut g, seat).unwrap();
+        let _ = play_turn(&mut bot, &mut g, seat).unwrap();
         assert_ne!(g.current, seat, "bot should act once delay elapsed");
     }
 
     #[test]
     fn showdown_noop() {
         let mut g = mk_game(3);
-        g.new_hand();
+        g.new_hand().unwrap();
         g.street = Street::Showdown; // ensure we are in terminal state
         let cur = g.current;
         let mut bot = BotAgent::new(BotProfile::default());
-        let _ = bot.on_turn(&mut g, cur).unwrap();
+        let _ = play_turn(&mut bot, &mut g, cur).unwrap();
         assert_eq!(g.current, cur, "no change at showdown");
         assert!(g.players[cur].last_action.is_none());
     }
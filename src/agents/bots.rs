@@ -1,11 +1,15 @@
 use crate::cards::Card;
-use crate::engine::GameEngine;
-use crate::evaluator::{evaluate_five, evaluate_seven, Evaluation};
+use crate::evaluator::{evaluate_five, evaluate_seven, Category, Evaluation};
+use crate::game::{LegalActions, PlayerStatus, Street};
 use crate::hand::HoleCards;
+use crate::icm::{call_fold_ev, push_fold_ev, CallFoldSpot, Caller, PushFoldSpot};
+use crate::push_fold::{call_chart, push_chart, MAX_BEHIND};
+use crate::variants::{short_deck, VariantId};
+use crate::view::PlayerView;
 use rand::{rngs::StdRng, Rng, RngCore, SeedableRng};
 use std::time::{Duration, Instant};
 
-use super::{Action, AgentKind, PlayerAgent};
+use super::{Action, AgentKind, PlayerAgent, ShowdownChoice};
 
 /// Difficulty tiers for bot play style and mistake rate.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -30,6 +34,12 @@ pub struct BotProfile {
     pub min_delay_ms: u64,
     pub max_delay_ms: u64,
     pub rng_seed: Option<u64>,
+    /// Tournament prizes by finishing place. When set, short stacks play
+    /// all-in or fold preflop by ICM instead of the usual policy.
+    pub payouts: Vec<f64>,
+    /// Effective stack, in big blinds, at or below which the bot plays
+    /// preflop by push/fold charts; `0` turns the charts off.
+    pub push_fold_bb: u64,
 }
 
 impl BotProfile {
@@ -51,6 +61,8 @@ impl BotProfile {
             min_delay_ms: 0,
             max_delay_ms: 0,
             rng_seed: None,
+            payouts: Vec::new(),
+            push_fold_bb: 0,
         }
     }
 
@@ -59,6 +71,18 @@ impl BotProfile {
         self.rng_seed = Some(seed);
         self
     }
+
+    /// Play a tournament paying `payouts`, first place first.
+    pub fn with_payouts(mut self, payouts: Vec<f64>) -> Self {
+        self.payouts = payouts;
+        self
+    }
+
+    /// Play push/fold at or below `big_blinds` effective; `0` never does.
+    pub fn with_push_fold_bb(mut self, big_blinds: u64) -> Self {
+        self.push_fold_bb = big_blinds;
+        self
+    }
 }
 
 impl Default for BotProfile {
@@ -103,7 +127,7 @@ struct BotPolicy;
 impl BotPolicy {
     fn decide(ctx: &BotContext<'_>, profile: &BotProfile, state: &mut BotState) -> BotDecision {
         let position = position_bucket(ctx.seat, ctx.dealer, ctx.num_players);
-        let strength = estimate_strength(ctx.hole, ctx.board, position);
+        let strength = estimate_strength(ctx.hole, ctx.board, ctx.variant, position);
         let pot_odds = if ctx.to_call == 0 {
             0.0
         } else {
@@ -249,6 +273,7 @@ struct BotContext<'a> {
     bet: u64,
     hole: &'a HoleCards,
     board: &'a crate::hand::Board,
+    variant: VariantId,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -274,76 +299,227 @@ impl BotAgent {
         let state = BotState::new(profile.rng_seed);
         Self { profile, state, next_action_at: None }
     }
+
+    /// All-in or fold by ICM when short-stacked in an unopened Hold'em pot
+    /// of a tournament, or call or fold against a lone all-in the same way.
+    /// `None` when the spot is not a push/fold decision.
+    fn icm_push_fold(&mut self, view: &PlayerView, legal: &LegalActions) -> Option<Action> {
+        if self.profile.payouts.is_empty()
+            || view.variant != VariantId::Holdem
+            || view.street != Street::Preflop
+            || view.to_call == 0
+        {
+            return None;
+        }
+        let hole = view.hole.filter(|h| h.as_slice().len() == 2)?;
+        let bb_pos = view.bb_pos?;
+        let hero = view.seat;
+        let payouts = &self.profile.payouts;
+        let stacks = view.seats.iter().map(|s| s.stack).collect();
+        let posted = view.seats.iter().map(|s| s.bet).collect();
+
+        if view.current_bet != view.min_raise {
+            let pusher = lone_pusher(view, bb_pos)?;
+            let big_blind = view.seats[bb_pos].bet;
+            let effective = total(view, hero).min(total(view, pusher));
+            if effective > PUSH_FOLD_BIG_BLINDS * big_blind {
+                return None;
+            }
+            // Expect the pusher to shove the equilibrium range.
+            let chart = push_chart(behind(view, bb_pos, pusher).min(MAX_BEHIND))?;
+            let range = chart.range(effective as f64 / big_blind as f64);
+            let spot = CallFoldSpot::new(stacks, posted, hero, pusher, range);
+            let ev = call_fold_ev(&spot, payouts, &hole, ICM_TRIALS, &mut self.state.rng).ok()?;
+            return Some(if ev.should_call() { Action::CheckCall } else { Action::Fold });
+        }
+
+        let big_blind = view.current_bet;
+        if total(view, hero) > PUSH_FOLD_BIG_BLINDS * big_blind {
+            return None;
+        }
+        // Expect each opponent to call with the equilibrium range for their
+        // effective stack.
+        let chart = call_chart(behind(view, bb_pos, hero).min(MAX_BEHIND))?;
+        let n = view.num_players();
+        let callers = (1..n)
+            .map(|i| (hero + i) % n)
+            .filter(|&seat| view.seats[seat].status == PlayerStatus::Active)
+            .map(|seat| {
+                let effective = total(view, hero).min(total(view, seat));
+                Caller::new(seat, chart.range(effective as f64 / big_blind as f64))
+            })
+            .collect();
+        let spot = PushFoldSpot::new(stacks, posted, hero, callers, bb_pos);
+        let ev = push_fold_ev(&spot, payouts, &hole, ICM_TRIALS, &mut self.state.rng).ok()?;
+        if ev.should_push() {
+            Some(legalize(Action::RaiseTo(legal.all_in), legal))
+        } else {
+            Some(Action::Fold)
+        }
+    }
+
+    /// Push or fold first in, or call or fold against a lone all-in, by the
+    /// equilibrium charts when the effective stack is short enough.
+    fn chart_push_fold(&self, view: &PlayerView, legal: &LegalActions) -> Option<Action> {
+        let limit = self.profile.push_fold_bb as f64;
+        if limit == 0.0
+            || view.variant != VariantId::Holdem
+            || view.street != Street::Preflop
+            || view.to_call == 0
+        {
+            return None;
+        }
+        let hole = view.hole.filter(|h| h.as_slice().len() == 2)?;
+        let bb_pos = view.bb_pos?;
+        let n = view.num_players();
+        let hero = view.seat;
+
+        if view.current_bet == view.min_raise {
+            let big_blind = view.current_bet;
+            let deepest = (0..n)
+                .filter(|&s| s != hero && view.seats[s].status == PlayerStatus::Active)
+                .map(|s| total(view, s))
+                .max()?;
+            let effective = total(view, hero).min(deepest) as f64 / big_blind as f64;
+            if effective > limit {
+                return None;
+            }
+            let chart = push_chart(behind(view, bb_pos, hero).min(MAX_BEHIND))?;
+            return Some(if chart.contains(&hole, effective) {
+                legalize(Action::RaiseTo(legal.all_in), legal)
+            } else {
+                Action::Fold
+            });
+        }
+
+        let pusher = lone_pusher(view, bb_pos)?;
+        let big_blind = view.seats[bb_pos].bet;
+        let effective = total(view, hero).min(total(view, pusher)) as f64 / big_blind as f64;
+        if effective > limit {
+            return None;
+        }
+        let chart = call_chart(behind(view, bb_pos, pusher).min(MAX_BEHIND))?;
+        Some(if chart.contains(&hole, effective) { Action::CheckCall } else { Action::Fold })
+    }
+}
+
+/// Stack, in big blinds, at or below which tournament bots push or fold.
+const PUSH_FOLD_BIG_BLINDS: u64 = 15;
+
+/// Run-outs sampled per caller when weighing a push.
+const ICM_TRIALS: usize = 300;
+
+/// Chips `seat` had before betting this round: its stack plus its bet.
+fn total(view: &PlayerView, seat: usize) -> u64 {
+    view.seats[seat].stack + view.seats[seat].bet
+}
+
+/// Dealt-in seats acting after `seat` up to and including the big blind.
+fn behind(view: &PlayerView, bb_pos: usize, seat: usize) -> usize {
+    let n = view.num_players();
+    let dealt = |s: usize| {
+        matches!(
+            view.seats[s].status,
+            PlayerStatus::Active | PlayerStatus::AllIn | PlayerStatus::Folded
+        )
+    };
+    (1..n).map(|i| (seat + i) % n).take_while(|&s| s != bb_pos).filter(|&s| dealt(s)).count()
+        + usize::from(seat != bb_pos)
+}
+
+/// The one opponent all-in above the big blind, when that all-in is the
+/// only bet the viewer faces.
+fn lone_pusher(view: &PlayerView, bb_pos: usize) -> Option<usize> {
+    let big_blind = view.seats[bb_pos].bet;
+    let mut all_in =
+        (0..view.num_players()).filter(|&s| s != view.seat && view.seats[s].bet > big_blind);
+    let pusher = all_in.next()?;
+    if all_in.next().is_some()
+        || big_blind == 0
+        || view.seats[pusher].status != PlayerStatus::AllIn
+        || view.seats[pusher].bet != view.current_bet
+    {
+        return None;
+    }
+    Some(pusher)
 }
 
 impl PlayerAgent for BotAgent {
     fn kind(&self) -> AgentKind {
         AgentKind::Bot
     }
-    fn on_turn(
-        &mut self,
-        engine: &mut dyn GameEngine,
-        seat: usize,
-    ) -> Result<bool, crate::game::ActionError> {
-        if matches!(engine.street(), crate::game::Street::Showdown) {
-            return Ok(false);
-        }
-        if engine.current() != seat {
-            return Ok(false);
-        }
+    fn on_showdown(&mut self, _view: &PlayerView) -> ShowdownChoice {
+        ShowdownChoice::Muck
+    }
+    fn on_turn(&mut self, view: &PlayerView) -> Option<Action> {
+        let legal = view.legal?;
         let now = Instant::now();
         let delay = choose_delay_ms(&self.profile, &mut self.state);
         if delay > 0 {
             match self.next_action_at {
                 None => {
                     self.next_action_at = Some(now + Duration::from_millis(delay));
-                    return Ok(false);
+                    return None;
                 }
                 Some(next) if now < next => {
-                    return Ok(false);
+                    return None;
                 }
                 Some(_) => {}
             }
         }
         self.next_action_at = None;
 
+        if let Some(action) = self.icm_push_fold(view, &legal) {
+            return Some(action);
+        }
+        if let Some(action) = self.chart_push_fold(view, &legal) {
+            return Some(action);
+        }
+
         // Heads-up preflop: avoid folding to the blind.
-        if matches!(engine.street(), crate::game::Street::Preflop)
-            && engine.num_players() == 2
-            && engine.current_bet() == engine.min_raise()
-            && engine.to_call(seat) > 0
+        if matches!(view.street, Street::Preflop)
+            && view.num_players() == 2
+            && view.current_bet == view.min_raise
+            && view.to_call > 0
         {
-            return engine.action_check_call().map(|_| true);
+            return Some(Action::CheckCall);
         }
 
-        let hole = match engine.hole_cards(seat) {
-            Some(h) => h,
-            None => return Ok(false),
-        };
+        let hole = view.hole?;
         let ctx = BotContext {
-            seat,
-            dealer: engine.dealer(),
-            num_players: engine.num_players(),
-            to_call: engine.to_call(seat),
-            pot: engine.pot(),
-            current_bet: engine.current_bet(),
-            min_raise: engine.min_raise(),
-            stack: engine.stack(seat),
-            bet: engine.bet(seat),
+            seat: view.seat,
+            dealer: view.dealer,
+            num_players: view.num_players(),
+            to_call: view.to_call,
+            pot: view.pot,
+            current_bet: view.current_bet,
+            min_raise: view.min_raise,
+            stack: view.stack(),
+            bet: view.bet(),
             hole: &hole,
-            board: engine.board(),
+            board: &view.board,
+            variant: view.variant,
         };
 
         let decision = BotPolicy::decide(&ctx, &self.profile, &mut self.state);
-        let result = match decision.action {
-            Action::Fold => engine.action_fold(),
-            Action::CheckCall => engine.action_check_call(),
-            Action::BetMin => engine.action_bet_min(),
-            Action::RaiseMin => engine.action_raise_min(),
-            Action::Bet(amount) => engine.action_bet(amount),
-            Action::RaiseTo(amount) => engine.action_raise_to(amount),
-        };
-        result.map(|_| true)
+        Some(legalize(decision.action, &legal))
+    }
+}
+
+/// Snap a policy decision onto the engine's legal options.
+fn legalize(action: Action, legal: &LegalActions) -> Action {
+    match action {
+        Action::Bet(amount) => match legal.bet {
+            Some(range) => Action::Bet(range.clamp(amount)),
+            None => Action::CheckCall,
+        },
+        Action::RaiseTo(amount) => match legal.raise {
+            Some(range) => Action::RaiseTo(range.clamp(amount)),
+            None => Action::CheckCall,
+        },
+        Action::BetMin if legal.bet.is_none() => Action::CheckCall,
+        Action::RaiseMin if legal.raise.is_none() => Action::CheckCall,
+        other => other,
     }
 }
 
@@ -459,8 +635,12 @@ fn choose_raise_target(ctx: &BotContext<'_>, aggression: f64, strength: f64) ->
 fn estimate_strength(
     hole: &HoleCards,
     board: &crate::hand::Board,
+    variant: VariantId,
     position: PositionBucket,
 ) -> f64 {
+    if hole.as_slice().len() > 2 {
+        return omaha_strength(hole, board, position);
+    }
     let board_cards = board.as_slice();
     if board_cards.is_empty() {
         return preflop_strength_with_position(hole, position);
@@ -470,16 +650,21 @@ fn estimate_strength(
     cards.push(hole.second());
     cards.extend_from_slice(board_cards);
 
+    // Short Deck ranks flushes above full houses and has its own wheel.
+    let (eval, rank): (_, fn(Category) -> u8) = match variant {
+        VariantId::ShortDeck => (short_deck::best_hand(&cards), short_deck::strength),
+        _ => (best_eval(&cards), Category::ordinal),
+    };
     if cards.len() >= 5 {
-        if let Some(eval) = best_eval(&cards) {
-            let base = eval.category.ordinal() as f64 / 8.0;
+        if let Some(eval) = eval {
+            let base = rank(eval.category) as f64 / 8.0;
             let high = eval.best_five[0].rank().value() as f64 / 14.0;
             let mut strength = base * 0.85 + high * 0.15;
             if board_cards.len() < 5 {
                 strength = (strength + draw_bonus(hole, board_cards, &cards)).min(1.0);
             }
             let texture = board_texture(board_cards);
-            let category_weight = eval.category.ordinal() as f64 / 8.0;
+            let category_weight = base;
             let texture_penalty = texture * (0.12 * (1.0 - category_weight));
             return (strength - texture_penalty).clamp(0.0, 1.0);
         }
@@ -487,6 +672,100 @@ fn estimate_strength(
     preflop_strength_with_position(hole, position)
 }
 
+/// Strength of an Omaha hand: the best two-card combination, since exactly
+/// two hole cards must play.
+fn omaha_strength(hole: &HoleCards, board: &crate::hand::Board, position: PositionBucket) -> f64 {
+    let board_cards = board.as_slice();
+    if board_cards.len() < 3 {
+        return omaha_preflop_strength(hole, position);
+    }
+    let mut best: f64 = 0.0;
+    for pair in hole_pairs(hole) {
+        let Some(eval) = best_omaha_eval(&pair, board_cards) else {
+            continue;
+        };
+        let base = eval.category.ordinal() as f64 / 8.0;
+        let high = eval.best_five[0].rank().value() as f64 / 14.0;
+        let mut strength = base * 0.85 + high * 0.15;
+        if board_cards.len() < 5 {
+            let mut cards = pair.as_slice().to_vec();
+            cards.extend_from_slice(board_cards);
+            strength = (strength + draw_bonus(&pair, board_cards, &cards)).min(1.0);
+        }
+        best = best.max(strength);
+    }
+    // Everyone holds more combinations in Omaha, so the same made hand is
+    // worth less against the field than in Hold'em.
+    let texture = board_texture(board_cards);
+    (best - texture * 0.15).clamp(0.0, 1.0)
+}
+
+/// Best five-card hand from both hole cards of `pair` and exactly three
+/// board cards.
+fn best_omaha_eval(pair: &HoleCards, board: &[Card]) -> Option<Evaluation> {
+    let [a, b] = pair.as_array()?;
+    let mut best: Option<Evaluation> = None;
+    for i in 0..board.len() {
+        for j in i + 1..board.len() {
+            for k in j + 1..board.len() {
+                let ev = evaluate_five(&[a, b, board[i], board[j], board[k]]);
+                if best.map_or(true, |b| ev > b) {
+                    best = Some(ev);
+                }
+            }
+        }
+    }
+    best
+}
+
+/// Every two-card combination of the hole cards.
+fn hole_pairs(hole: &HoleCards) -> Vec<HoleCards> {
+    let cards = hole.as_slice();
+    let mut pairs = Vec::with_capacity(6);
+    for i in 0..cards.len() {
+        for j in i + 1..cards.len() {
+            pairs.extend(HoleCards::try_new(cards[i], cards[j]).ok());
+        }
+    }
+    pairs
+}
+
+/// Preflop Omaha heuristic: the best two-card start, plus credit for hands
+/// that work together (double-suited, connected) and a penalty for
+/// duplicated ranks that block each other.
+fn omaha_preflop_strength(hole: &HoleCards, position: PositionBucket) -> f64 {
+    let cards = hole.as_slice();
+    let best_pair = hole_pairs(hole).iter().map(preflop_strength).fold(0.0, f64::max);
+
+    let mut suits = [0u8; 4];
+    let mut rank_counts = [0u8; 15];
+    for c in cards {
+        suits[c.suit() as usize] += 1;
+        rank_counts[c.rank().value() as usize] += 1;
+    }
+    let mut score = best_pair * 0.8;
+    score += match suits.iter().filter(|&&n| n == 2).count() {
+        2 => 0.1,
+        1 => 0.05,
+        _ => 0.0,
+    };
+    let mut ranks: Vec<i32> = cards.iter().map(|c| c.rank().value() as i32).collect();
+    ranks.sort_unstable();
+    ranks.dedup();
+    if ranks.len() == cards.len() && ranks[ranks.len() - 1] - ranks[0] <= 4 {
+        score += 0.08;
+    }
+    if rank_counts.iter().any(|&n| n >= 3) {
+        score -= 0.15;
+    }
+    let bonus = match position {
+        PositionBucket::Button | PositionBucket::Late | PositionBucket::HeadsUp => 0.05,
+        PositionBucket::Early => -0.05,
+        _ => 0.0,
+    };
+    (score + bonus).clamp(0.0, 1.0)
+}
+
 fn best_eval(cards: &[Card]) -> Option<Evaluation> {
     match cards.len() {
         5 => {
@@ -795,6 +1074,27 @@ mod tests {
         assert!(preflop_in_range(&ace_low, PositionBucket::HeadsUp));
     }
 
+    #[test]
+    fn short_deck_hands_use_short_deck_rankings() {
+        let hole: HoleCards = "As 9d".parse().unwrap();
+        let board: crate::hand::Board = "8c 7h 6s Kd Qh".parse().unwrap();
+        let position = PositionBucket::Button;
+        let holdem = estimate_strength(&hole, &board, VariantId::Holdem, position);
+        let short = estimate_strength(&hole, &board, VariantId::ShortDeck, position);
+        assert!(short > holdem, "A-6-7-8-9 is a straight only in Short Deck");
+    }
+
+    #[test]
+    fn legalize_snaps_to_engine_ranges() {
+        use crate::game::Game;
+        let mut g = Game::new(3, 1000, 5, 10);
+        g.new_hand().unwrap();
+        let legal = g.legal_actions().unwrap();
+        assert!(matches!(legalize(Action::RaiseTo(15), &legal), Action::RaiseTo(20)));
+        assert!(matches!(legalize(Action::RaiseTo(5000), &legal), Action::RaiseTo(1000)));
+        assert!(matches!(legalize(Action::Bet(30), &legal), Action::CheckCall));
+    }
+
     #[test]
     fn bot_checks_or_raises_when_bet_exists() {
         let hole = HoleCards::try_new(
@@ -815,6 +1115,7 @@ mod tests {
             bet: 10,
             hole: &hole,
             board: &board,
+            variant: VariantId::Holdem,
         };
         let profile = BotProfile {
             difficulty: Difficulty::Expert,
@@ -826,9 +1127,34 @@ mod tests {
             min_delay_ms: 0,
             max_delay_ms: 0,
             rng_seed: Some(7),
+            payouts: Vec::new(),
+            push_fold_bb: 0,
         };
         let mut state = BotState::new(profile.rng_seed);
         let decision = BotPolicy::decide(&ctx, &profile, &mut state);
         assert!(!matches!(decision.action, Action::Bet(_) | Action::BetMin));
     }
+
+    #[test]
+    fn omaha_preflop_prefers_coordinated_hands() {
+        let strength =
+            |s: &str| omaha_preflop_strength(&s.parse().unwrap(), PositionBucket::Middle);
+        assert!(strength("Ac Ad Kc Kd") > strength("As Kd 7c 2h"));
+        assert!(strength("Jh Th 9c 8c") > strength("Jh Td 9c 8s"));
+        assert!(strength("Kh Kd Kc 5s") < strength("Kh Kd Qc 5s"));
+    }
+
+    #[test]
+    fn omaha_strength_plays_exactly_two_hole_cards() {
+        // One heart in hand: no flush on a four-heart board, but AK makes Broadway.
+        let hole: HoleCards = "Ah Kd 7c 2c".parse().unwrap();
+        let board: Board = "Qh Jh Th 9h 3s".parse().unwrap();
+        let best = hole_pairs(&hole)
+            .iter()
+            .filter_map(|pair| best_omaha_eval(pair, board.as_slice()))
+            .max()
+            .unwrap();
+        assert_eq!(best.category, crate::evaluator::Category::Straight);
+        assert_eq!(hole_pairs(&hole).len(), 6);
+    }
 }
@@ -244,6 +244,20 @@ impl fmt::Display for Card {
     }
 }
 
+/// Cards serialize as their short string form, e.g. `"As"`.
+impl serde::Serialize for Card {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Card {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Card::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
 #[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum CardParseError {
@@ -335,6 +349,15 @@ mod tests {
         assert!(ah > kd);
     }
 
+    #[test]
+    fn card_serde_uses_short_form() {
+        let c = Card::new(Rank::Ten, Suit::Hearts);
+        let json = serde_json::to_string(&c).unwrap();
+        assert_eq!(json, "\"Th\"");
+        assert_eq!(serde_json::from_str::<Card>(&json).unwrap(), c);
+        assert!(serde_json::from_str::<Card>("\"Zz\"").is_err());
+    }
+
     #[test]
     fn parse_many_cards() {
         let xs = parse_cards("As, Kd 10c").unwrap();
@@ -2,6 +2,14 @@ use crate::cards::{Card, Rank, Suit};
 use rand::seq::SliceRandom;
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha8Rng;
+use std::collections::HashSet;
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DeckError {
+    #[error("duplicate card in deck: {0}")]
+    DuplicateCard(Card),
+}
 
 /// A standard 52-card deck.
 #[derive(Debug, Clone)]
@@ -40,6 +48,62 @@ impl Deck {
         Self { cards }
     }
 
+    /// The 36-card short deck used by Short Deck Hold'em: sixes through aces.
+    ///
+    /// ```
+    /// use poker_rs::deck::Deck;
+    ///
+    /// let deck = Deck::short();
+    /// assert_eq!(deck.len(), 36);
+    /// ```
+    pub fn short() -> Self {
+        let mut deck = Self::standard();
+        deck.cards.retain(|c| c.rank() >= Rank::Six);
+        deck
+    }
+
+    /// Whether `card` is still in the deck.
+    pub fn contains(&self, card: Card) -> bool {
+        self.cards.contains(&card)
+    }
+
+    /// Build a stacked deck from cards in draw order: `cards[0]` is drawn first.
+    ///
+    /// The deck may hold fewer than 52 cards; drawing past the end yields `None`.
+    ///
+    /// ```
+    /// use poker_rs::cards::{Card, Rank, Suit};
+    /// use poker_rs::deck::Deck;
+    ///
+    /// let ace = Card::new(Rank::Ace, Suit::Spades);
+    /// let king = Card::new(Rank::King, Suit::Spades);
+    /// let mut deck = Deck::from_draw_order(vec![ace, king]).unwrap();
+    /// assert_eq!(deck.draw(), Some(ace));
+    /// assert_eq!(deck.draw(), Some(king));
+    /// assert_eq!(deck.draw(), None);
+    /// ```
+    pub fn from_draw_order(cards: Vec<Card>) -> Result<Self, DeckError> {
+        let mut seen = HashSet::with_capacity(cards.len());
+        for &c in &cards {
+            if !seen.insert(c) {
+                return Err(DeckError::DuplicateCard(c));
+            }
+        }
+        let mut cards = cards;
+        cards.reverse();
+        Ok(Self { cards })
+    }
+
+    /// Remaining cards in draw order (next card first).
+    pub fn draw_order(&self) -> Vec<Card> {
+        self.cards.iter().rev().copied().collect()
+    }
+
+    /// Remove the given cards from the deck, keeping the order of the rest.
+    pub(crate) fn remove_cards(&mut self, cards: &[Card]) {
+        self.cards.retain(|c| !cards.contains(c));
+    }
+
     pub fn len(&self) -> usize {
         self.cards.len()
     }
@@ -101,4 +165,17 @@ mod tests {
         assert_eq!(hand.len(), 5);
         assert_eq!(d.len(), 45);
     }
+
+    #[test]
+    fn from_draw_order_rejects_duplicates_and_round_trips() {
+        let mut d = Deck::standard();
+        d.shuffle_seeded(3);
+        let order = d.draw_order();
+        let mut copy = Deck::from_draw_order(order.clone()).unwrap();
+        assert_eq!(copy.draw(), d.draw());
+        assert_eq!(copy.draw_order(), d.draw_order());
+
+        let dup = vec![order[0], order[1], order[0]];
+        assert_eq!(Deck::from_draw_order(dup).unwrap_err(), DeckError::DuplicateCard(order[0]));
+    }
 }
@@ -4,9 +4,14 @@
 
 pub trait GameEngine {
     // Hand lifecycle
-    fn new_hand(&mut self);
+    fn new_hand(&mut self) -> Result<(), crate::game::DealError>;
 
     // Player actions (No-Limit Hold'em basics)
+    fn act(
+        &mut self,
+        seat: usize,
+        action: crate::agents::Action,
+    ) -> Result<(), crate::game::ActionError>;
     fn action_fold(&mut self) -> Result<(), crate::game::ActionError>;
     fn action_check_call(&mut self) -> Result<(), crate::game::ActionError>;
     fn action_bet_min(&mut self) -> Result<(), crate::game::ActionError>;
@@ -14,12 +19,18 @@ pub trait GameEngine {
     fn action_raise_min(&mut self) -> Result<(), crate::game::ActionError>;
     fn action_raise_to(&mut self, amount: u64) -> Result<(), crate::game::ActionError>;
 
+    // Showdown decisions for losing hands
+    fn muck(&mut self, seat: usize) -> Result<(), crate::game::ShowdownError>;
+    fn show(&mut self, seat: usize) -> Result<(), crate::game::ShowdownError>;
+    fn pending_mucks(&self) -> Vec<usize>;
+
     // Queries
+    fn legal_actions(&self) -> Option<crate::game::LegalActions>;
     fn to_call(&self, seat: usize) -> u64;
     fn current_bet(&self) -> u64;
     fn min_raise(&self) -> u64;
     fn pot(&self) -> u64;
-    fn hole_cards(&self, seat: usize) -> Option<crate::hand::HoleCards>;
+    fn player_view(&self, seat: usize) -> crate::view::PlayerView;
     fn board(&self) -> &crate::hand::Board;
     fn stack(&self, seat: usize) -> u64;
     fn bet(&self, seat: usize) -> u64;
@@ -30,10 +41,17 @@ pub trait GameEngine {
 }
 
 impl GameEngine for crate::game::Game {
-    fn new_hand(&mut self) {
-        self.new_hand();
+    fn new_hand(&mut self) -> Result<(), crate::game::DealError> {
+        self.new_hand()
     }
 
+    fn act(
+        &mut self,
+        seat: usize,
+        action: crate::agents::Action,
+    ) -> Result<(), crate::game::ActionError> {
+        self.act(seat, action)
+    }
     fn action_fold(&mut self) -> Result<(), crate::game::ActionError> {
         self.action_fold()
     }
@@ -53,6 +71,19 @@ impl GameEngine for crate::game::Game {
         self.action_raise_to(amount)
     }
 
+    fn muck(&mut self, seat: usize) -> Result<(), crate::game::ShowdownError> {
+        self.muck(seat)
+    }
+    fn show(&mut self, seat: usize) -> Result<(), crate::game::ShowdownError> {
+        self.show(seat)
+    }
+    fn pending_mucks(&self) -> Vec<usize> {
+        self.pending_mucks()
+    }
+
+    fn legal_actions(&self) -> Option<crate::game::LegalActions> {
+        self.legal_actions()
+    }
     fn to_call(&self, seat: usize) -> u64 {
         self.to_call(seat)
     }
@@ -65,8 +96,8 @@ impl GameEngine for crate::game::Game {
     fn pot(&self) -> u64 {
         self.pot
     }
-    fn hole_cards(&self, seat: usize) -> Option<crate::hand::HoleCards> {
-        self.players.get(seat).and_then(|p| p.hole)
+    fn player_view(&self, seat: usize) -> crate::view::PlayerView {
+        self.player_view(seat)
     }
     fn board(&self) -> &crate::hand::Board {
         &self.board
@@ -0,0 +1,177 @@
+//! All-in equity for Hold'em hands.
+//!
+//! Equity is the share of the pot a hand wins on average when all the money
+//! goes in now and the board is run out: wins count fully and ties by the
+//! number of hands sharing the pot. The run-outs are sampled, so results carry
+//! Monte Carlo error of roughly `0.5 / sqrt(trials)`.
+
+use crate::cards::{Card, Rank, Suit};
+use crate::evaluator::evaluate_seven;
+use crate::hand::HoleCards;
+use rand::Rng;
+use std::collections::HashSet;
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EquityError {
+    #[error("card {0} appears more than once")]
+    DuplicateCard(Card),
+    #[error("{0} board cards given; at most 5 are allowed")]
+    TooManyBoardCards(usize),
+    #[error("Hold'em equity needs two hole cards, got {0}")]
+    HoleCount(usize),
+    #[error("no hand in the range can be dealt alongside the known cards")]
+    EmptyRange,
+    #[error("at least one trial is required")]
+    NoTrials,
+    #[error("not enough cards to deal {0} opponents and the board")]
+    TooManyOpponents(usize),
+}
+
+/// Every two-card combination of a standard deck: 1326 hands.
+pub fn all_combos() -> Vec<HoleCards> {
+    let deck: Vec<Card> =
+        Rank::ALL.iter().flat_map(|&r| Suit::ALL.iter().map(move |&s| Card::new(r, s))).collect();
+    let mut combos = Vec::with_capacity(1326);
+    for (i, &a) in deck.iter().enumerate() {
+        for &b in &deck[i + 1..] {
+            combos.push(HoleCards::try_new(a, b).expect("distinct cards"));
+        }
+    }
+    combos
+}
+
+/// Equity of `hero` against one hand drawn at random from `range` each
+/// trial, skipping hands that share a card with the hero or the board.
+///
+/// ```
+/// use poker_rs::equity::equity_vs_range;
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let aces = "As Ah".parse().unwrap();
+/// let kings = ["Ks Kh".parse().unwrap()];
+/// let mut rng = StdRng::seed_from_u64(7);
+/// let eq = equity_vs_range(&aces, &kings, &[], 2000, &mut rng).unwrap();
+/// assert!((eq - 0.82).abs() < 0.03);
+/// ```
+pub fn equity_vs_range<R: Rng + ?Sized>(
+    hero: &HoleCards,
+    range: &[HoleCards],
+    board: &[Card],
+    trials: usize,
+    rng: &mut R,
+) -> Result<f64, EquityError> {
+    let known = known_cards(hero, board)?;
+    let range: Vec<[Card; 2]> = range
+        .iter()
+        .filter_map(HoleCards::as_array)
+        .filter(|[a, b]| !known.contains(a) && !known.contains(b))
+        .collect();
+    if range.is_empty() {
+        return Err(EquityError::EmptyRange);
+    }
+    if trials == 0 {
+        return Err(EquityError::NoTrials);
+    }
+    let hero = hero.as_array().ok_or(EquityError::HoleCount(hero.as_slice().len()))?;
+    let mut deck = remaining_deck(&known);
+    let mut total = 0.0;
+    for _ in 0..trials {
+        let villain = range[rng.random_range(0..range.len())];
+        deck.retain(|c| !villain.contains(c));
+        total += showdown_share(hero, &[villain], board, &mut deck, rng);
+        deck.extend(villain);
+    }
+    Ok(total / trials as f64)
+}
+
+/// Equity of `hero` against `opponents` random hands.
+pub fn equity_vs_random<R: Rng + ?Sized>(
+    hero: &HoleCards,
+    opponents: usize,
+    board: &[Card],
+    trials: usize,
+    rng: &mut R,
+) -> Result<f64, EquityError> {
+    let known = known_cards(hero, board)?;
+    if trials == 0 {
+        return Err(EquityError::NoTrials);
+    }
+    let hero = hero.as_array().ok_or(EquityError::HoleCount(hero.as_slice().len()))?;
+    let mut deck = remaining_deck(&known);
+    if 2 * opponents + 5 - board.len() > deck.len() {
+        return Err(EquityError::TooManyOpponents(opponents));
+    }
+    let mut villains = vec![[hero[0]; 2]; opponents];
+    let mut total = 0.0;
+    for _ in 0..trials {
+        let dealt = 2 * opponents;
+        partial_shuffle(&mut deck, dealt, rng);
+        let (holes, rest) = deck.split_at_mut(dealt);
+        for (v, pair) in villains.iter_mut().zip(holes.chunks(2)) {
+            *v = [pair[0], pair[1]];
+        }
+        total += showdown_share(hero, &villains, board, rest, rng);
+    }
+    Ok(total / trials as f64)
+}
+
+fn known_cards(hero: &HoleCards, board: &[Card]) -> Result<HashSet<Card>, EquityError> {
+    if hero.as_slice().len() != 2 {
+        return Err(EquityError::HoleCount(hero.as_slice().len()));
+    }
+    if board.len() > 5 {
+        return Err(EquityError::TooManyBoardCards(board.len()));
+    }
+    let mut known = HashSet::new();
+    for &card in hero.as_slice().iter().chain(board) {
+        if !known.insert(card) {
+            return Err(EquityError::DuplicateCard(card));
+        }
+    }
+    Ok(known)
+}
+
+fn remaining_deck(known: &HashSet<Card>) -> Vec<Card> {
+    Rank::ALL
+        .iter()
+        .flat_map(|&r| Suit::ALL.iter().map(move |&s| Card::new(r, s)))
+        .filter(|c| !known.contains(c))
+        .collect()
+}
+
+/// Move `n` random cards to the front of `deck`.
+fn partial_shuffle<R: Rng + ?Sized>(deck: &mut [Card], n: usize, rng: &mut R) {
+    for i in 0..n {
+        let j = rng.random_range(i..deck.len());
+        deck.swap(i, j);
+    }
+}
+
+/// Run out the board from `deck` and return the hero's share of the pot.
+fn showdown_share<R: Rng + ?Sized>(
+    hero: [Card; 2],
+    villains: &[[Card; 2]],
+    board: &[Card],
+    deck: &mut [Card],
+    rng: &mut R,
+) -> f64 {
+    let missing = 5 - board.len();
+    partial_shuffle(deck, missing, rng);
+    let mut seven = [hero[0]; 7];
+    seven[2..2 + board.len()].copy_from_slice(board);
+    seven[2 + board.len()..].copy_from_slice(&deck[..missing]);
+
+    seven[..2].copy_from_slice(&hero);
+    let hero_eval = evaluate_seven(&seven);
+    let mut tied = 1;
+    for villain in villains {
+        seven[..2].copy_from_slice(villain);
+        match evaluate_seven(&seven).cmp(&hero_eval) {
+            std::cmp::Ordering::Greater => return 0.0,
+            std::cmp::Ordering::Equal => tied += 1,
+            std::cmp::Ordering::Less => {}
+        }
+    }
+    1.0 / tied as f64
+}
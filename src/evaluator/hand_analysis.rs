@@ -2,7 +2,7 @@ use super::rank_groups::RankGroups;
 use super::straight_info::StraightInfo;
 use super::suit_info::SuitInfo;
 use crate::cards::{Card, Rank};
-use crate::evaluator::{Category, Evaluation, HandValue};
+use crate::evaluator::{Category, Evaluation};
 
 /// Pre-computed analysis of a 5-card hand.
 /// Built once and shared by all category detectors.
@@ -50,8 +50,7 @@ impl HandAnalysis {
 
     /// Build an Evaluation from a category and tiebreak ranks.
     pub fn build_evaluation(&self, category: Category, tiebreak: [Rank; 5]) -> Evaluation {
-        let value = HandValue::from_parts(category, &tiebreak);
-        Evaluation { category, best_five: self.sorted_cards, value }
+        Evaluation::new(category, self.sorted_cards, tiebreak)
     }
 }
 
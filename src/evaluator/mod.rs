@@ -11,12 +11,16 @@ use core::cmp::Ordering;
 
 /// Compact, comparable hand strength. Higher is better.
 /// Encodes category and ranked tiebreakers.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
 #[non_exhaustive]
 pub struct HandValue(u64);
 
 /// Poker hand category from weakest to strongest.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum Category {
@@ -38,7 +42,7 @@ impl Category {
 }
 
 /// Detailed evaluation result. `value` drives ordering.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
 #[non_exhaustive]
 pub struct Evaluation {
     pub category: Category,
@@ -67,6 +71,11 @@ impl PartialEq for Evaluation {
 impl Eq for Evaluation {}
 
 impl Evaluation {
+    /// Build an evaluation from a category, its five cards and rank tiebreakers.
+    pub(crate) fn new(category: Category, best_five: [Card; 5], tiebreak: [Rank; 5]) -> Self {
+        Self { category, best_five, value: HandValue::from_parts(category, &tiebreak) }
+    }
+
     /// Return the packed comparable value for ordering/caching.
     pub const fn value(&self) -> HandValue {
         self.value
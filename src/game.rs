@@ -1,17 +1,135 @@
-use crate::deck::Deck;
-use crate::evaluator::{evaluate_holdem, Category};
+use crate::agents::Action;
+use crate::cards::Card;
+use crate::deck::{Deck, DeckError};
+use crate::evaluator::{Category, Evaluation};
 use crate::hand::{Board, HoleCards};
+use crate::invariants::InvariantViolation;
+use crate::ledger::SessionLedger;
+use crate::variants::{Holdem, Variant};
 use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::sync::Arc;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum PlayerStatus {
     Active,
     Folded,
     AllIn,
+    /// Seated but skipping hands until they sit back in
+    SittingOut,
+    /// Nobody is sitting in this seat
+    Empty,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Which winner receives the odd chips when a pot does not split evenly.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum OddChipRule {
+    /// Odd chips go one at a time to winners clockwise from the button.
+    #[default]
+    LeftOfButton,
+    /// Odd chips go first to the winner holding the highest hole card, ties
+    /// broken by suit (spades, hearts, diamonds, clubs).
+    HighCardBySuit,
+}
+
+/// How much a player may bet or raise.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum BetLimit {
+    /// Any amount up to the player's whole stack.
+    #[default]
+    NoLimit,
+    /// At most the size of the pot after calling: a raise may reach the
+    /// current bet plus the pot including the raiser's call.
+    PotLimit,
+}
+
+/// How the dealer button moves when seats are empty, sitting out or busted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum ButtonRule {
+    /// The button moves to the next player dealt in; blinds follow it.
+    #[default]
+    Moving,
+    /// The big blind moves one player forward every hand and the small blind and
+    /// button follow it, even onto seats nobody can post from. No player skips
+    /// the big blind, at the cost of occasional dead small blinds and buttons.
+    Dead,
+}
+
+/// When players may add chips to their stack, and how many.
+///
+/// The default allows nothing: a busted player stays out of hands until they
+/// leave. Cash games cap stacks at a maximum buy-in and may top players up
+/// automatically; tournaments allow rebuys for a number of hands and a single
+/// add-on per player.
+///
+/// ```
+/// use poker_rs::game::{BuyInRules, Game};
+///
+/// let mut game = Game::new(2, 1000, 5, 10);
+/// game.set_buy_in_rules(BuyInRules::cash(2000));
+/// game.top_up(0, 1000).unwrap();
+/// assert_eq!(game.players()[0].stack(), 2000);
+/// assert!(game.top_up(1, 1001).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct BuyInRules {
+    /// Largest stack a rebuy or top-up may leave a player with; `None` is uncapped
+    pub max_buy_in: Option<u64>,
+    /// Whether busted players may buy chips again
+    pub rebuys: bool,
+    /// Whether players who still have chips may add more
+    pub top_ups: bool,
+    /// Before each hand, refill every stack below `max_buy_in` up to it
+    pub auto_top_up: bool,
+    /// Rebuys and the add-on are only allowed until this many hands have
+    /// been played; `None` allows them all session
+    pub rebuy_hands: Option<u64>,
+    /// Chips of the single add-on each player may take while rebuys are open
+    pub add_on: Option<u64>,
+}
+
+impl BuyInRules {
+    /// Cash game: rebuys and top-ups up to `max_buy_in`.
+    pub fn cash(max_buy_in: u64) -> Self {
+        Self { max_buy_in: Some(max_buy_in), rebuys: true, top_ups: true, ..Self::default() }
+    }
+
+    /// Tournament: rebuys for the first `rebuy_hands` hands and an add-on of
+    /// `add_on` chips during them.
+    pub fn tournament(rebuy_hands: u64, add_on: u64) -> Self {
+        Self {
+            rebuys: true,
+            rebuy_hands: Some(rebuy_hands),
+            add_on: Some(add_on),
+            ..Self::default()
+        }
+    }
+
+    /// Top stacks up to the maximum buy-in before every hand.
+    pub fn with_auto_top_up(mut self) -> Self {
+        self.auto_top_up = true;
+        self
+    }
+}
+
+/// Blinds a sitting-out player missed and must post when dealt back in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum MissedBlind {
+    /// Owes a dead small blind
+    Small,
+    /// Owes a live big blind plus a dead small blind
+    Big,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum Street {
     Preflop,
@@ -21,16 +139,25 @@ pub enum Street {
     Showdown,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum HandHistoryVerb {
+    /// Dead money every player posts before a bomb pot
+    Ante,
     SmallBlind,
     BigBlind,
+    DeadBlind,
     Fold,
     Check,
     Call,
     Bet,
     RaiseTo,
+    /// Chips nobody called, returned to the bettor before the pot is awarded
+    UncalledBet,
+    /// Hole cards tabled at showdown; the entry carries the cards
+    Show,
+    /// A losing hand thrown away unseen at showdown
+    Muck,
     Win,
     Split,
 }
@@ -38,13 +165,18 @@ pub enum HandHistoryVerb {
 impl HandHistoryVerb {
     pub fn label(self) -> &'static str {
         match self {
+            HandHistoryVerb::Ante => "Ante",
             HandHistoryVerb::SmallBlind => "SB",
             HandHistoryVerb::BigBlind => "BB",
+            HandHistoryVerb::DeadBlind => "Dead",
             HandHistoryVerb::Fold => "Fold",
             HandHistoryVerb::Check => "Check",
             HandHistoryVerb::Call => "Call",
             HandHistoryVerb::Bet => "Bet",
             HandHistoryVerb::RaiseTo => "Raise to",
+            HandHistoryVerb::UncalledBet => "Uncalled",
+            HandHistoryVerb::Show => "Show",
+            HandHistoryVerb::Muck => "Muck",
             HandHistoryVerb::Win => "Win",
             HandHistoryVerb::Split => "Split",
         }
@@ -68,24 +200,346 @@ pub enum ActionError {
     AmountTooLarge { max: u64, got: u64 },
     #[error("target must exceed current bet: current {current}, target {target}")]
     TargetTooLow { current: u64, target: u64 },
+    #[error("betting was not reopened by a short all-in; only call or fold")]
+    RaiseNotReopened,
+    #[error("seat {seat} acted out of turn; seat {current} is to act")]
+    NotYourTurn { seat: usize, current: usize },
+    #[error("invariant violated after the action: {0}")]
+    Invariant(InvariantViolation),
 }
 
-#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum ShowdownError {
     #[error("hand evaluation failed: {0}")]
     EvaluationFailed(String),
     #[error("invalid game state: {0}")]
     InvalidState(String),
+    #[error("seat {0} cannot muck: it has no losing hand awaiting a show/muck decision")]
+    MuckNotAllowed(usize),
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// Chips one seat received from a pot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PotShare {
+    pub seat: usize,
+    pub amount: u64,
+}
+
+/// How a single pot (main or side) was awarded.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct PotResult {
+    pub amount: u64,
+    /// Seats still holding cards that contributed to this pot
+    pub eligible: Vec<usize>,
+    /// Winners and their shares, in odd-chip order. In double-board hands the
+    /// shares of board one's half come first, so a seat may appear twice.
+    pub winners: Vec<PotShare>,
+}
+
+/// A contender's cards at the end of the hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ShowdownHand {
+    pub seat: usize,
+    /// `None` once the hand is mucked
+    pub hole: Option<HoleCards>,
+    /// Best hand on the final board; `None` if the board was never completed
+    /// or the hand was mucked
+    pub evaluation: Option<Evaluation>,
+    /// Best hand on the second board of a double-board hand
+    pub second_evaluation: Option<Evaluation>,
+    /// Whether the cards were shown rather than mucked or won uncontested
+    pub shown: bool,
+    /// Whether the player may still muck: their hand is beaten in every pot
+    /// they are eligible for by a hand shown before theirs
+    pub can_muck: bool,
+}
+
+/// Outcome of a finished hand: who won which pot, for how much, and with what.
+///
+/// ```
+/// use poker_rs::game::{Game, Street};
+///
+/// let mut game = Game::new(2, 1000, 5, 10);
+/// game.new_hand().unwrap();
+/// while game.street() != Street::Showdown {
+///     game.action_check_call().unwrap();
+/// }
+/// let result = game.showdown_result().unwrap();
+/// assert_eq!(result.pots.len(), 1);
+/// assert_eq!(result.pots[0].amount, 20);
+/// let paid: u64 = result.pots[0].winners.iter().map(|w| w.amount).sum();
+/// assert_eq!(paid, 20);
+/// assert!(result.hands.iter().all(|h| h.shown && h.evaluation.is_some()));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ShowdownResult {
+    /// Main pot first, then side pots in the order they were formed
+    pub pots: Vec<PotResult>,
+    /// Every seat that reached the end of the hand holding cards, in showdown
+    /// order: the last river aggressor first, otherwise the first contender
+    /// left of the button, then clockwise
+    pub hands: Vec<ShowdownHand>,
+    /// Seat and amount of a bet returned because nobody called it
+    pub uncalled: Option<(usize, u64)>,
+}
+
+impl ShowdownResult {
+    /// Total chips `seat` won across all pots.
+    pub fn won_by(&self, seat: usize) -> u64 {
+        self.pots.iter().flat_map(|p| &p.winners).filter(|w| w.seat == seat).map(|w| w.amount).sum()
+    }
+
+    /// The contender entry for `seat`, if they held cards at the end.
+    pub fn hand(&self, seat: usize) -> Option<&ShowdownHand> {
+        self.hands.iter().find(|h| h.seat == seat)
+    }
+}
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DealError {
+    #[error("seat {seat} is out of range for {num_players} players")]
+    SeatOutOfRange { seat: usize, num_players: usize },
+    #[error("seat {0} is empty, sitting out or has no chips and will not be dealt in")]
+    SeatNotDealt(usize),
+    #[error("too many board cards: {0}")]
+    TooManyBoardCards(usize),
+    #[error("card {0} is scripted more than once")]
+    DuplicateCard(Card),
+    #[error("a second board is scripted but double board is off")]
+    NoSecondBoard,
+    #[error("seat {seat} is scripted {got} hole cards but the variant deals {expected}")]
+    HoleCount { seat: usize, expected: usize, got: usize },
+    #[error("card {0} is not in the variant's deck")]
+    CardNotInDeck(Card),
+    #[error("dealing takes {needed} cards but the deck holds {available}")]
+    DeckTooSmall { needed: usize, available: usize },
+    #[error(transparent)]
+    Deck(#[from] DeckError),
+}
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SeatError {
+    #[error("seat {seat} is out of range for {num_seats} seats")]
+    SeatOutOfRange { seat: usize, num_seats: usize },
+    #[error("seat {0} is already taken")]
+    SeatTaken(usize),
+    #[error("seat {0} is empty")]
+    SeatEmpty(usize),
+    #[error("a player named '{0}' is already seated")]
+    NameTaken(String),
+    #[error("buy-in must be greater than zero")]
+    ZeroBuyIn,
+    #[error("seating can only change between hands")]
+    HandInProgress,
+}
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BuyInError {
+    #[error(transparent)]
+    Seat(#[from] SeatError),
+    #[error("rebuys are not allowed at this table")]
+    RebuysNotAllowed,
+    #[error("top-ups are not allowed at this table")]
+    TopUpsNotAllowed,
+    #[error("the rebuy period ended after {0} hands")]
+    RebuyPeriodOver(u64),
+    #[error("seat {0} still has chips and cannot rebuy")]
+    NotBusted(usize),
+    #[error("seat {0} is busted and must rebuy instead")]
+    Busted(usize),
+    #[error("stack would be {stack}, above the maximum buy-in of {max}")]
+    AboveMaxBuyIn { max: u64, stack: u64 },
+    #[error("this table has no add-on")]
+    NoAddOn,
+    #[error("seat {0} has already taken the add-on")]
+    AddOnTaken(usize),
+}
+
+/// Per-hand settings for [`Game::new_hand_with`].
+///
+/// ```
+/// use poker_rs::game::{Game, HandOptions, Street};
+///
+/// let mut game = Game::new(3, 1000, 5, 10);
+/// game.new_hand_with(HandOptions::bomb_pot(20)).unwrap();
+/// assert_eq!(game.street(), Street::Flop);
+/// assert_eq!(game.pot(), 60);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct HandOptions {
+    /// Ante collected from every player dealt in. The hand skips blinds and
+    /// preflop betting and starts on the flop.
+    pub bomb_pot: Option<u64>,
+}
+
+impl HandOptions {
+    /// A bomb pot where everyone antes `ante`.
+    pub fn bomb_pot(ante: u64) -> Self {
+        Self { bomb_pot: Some(ante) }
+    }
+}
+
+/// Predetermined cards for the next hand: hole cards per seat and the board run-out.
+///
+/// Seats without scripted hole cards and any board cards beyond the script are
+/// dealt from the rest of the deck, shuffled with the hand's seed.
+///
+/// ```
+/// use poker_rs::cards::Card;
+/// use poker_rs::game::{DealScript, Game};
+///
+/// let board: Vec<Card> = poker_rs::cards::parse_cards("2c 7d 9s Jh Kc").unwrap();
+/// let script = DealScript::new()
+///     .hole(0, "As Ah".parse().unwrap())
+///     .hole(1, "Ks Kh".parse().unwrap())
+///     .board(board.clone());
+/// let mut game = Game::new(3, 1000, 5, 10);
+/// game.new_hand_scripted(&script).unwrap();
+/// assert_eq!(game.players()[0].hole(), Some("As Ah".parse().unwrap()));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DealScript {
+    hole: Vec<Option<HoleCards>>,
+    board: Vec<Card>,
+    second_board: Vec<Card>,
+}
+
+impl DealScript {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Script the hole cards dealt to `seat`.
+    pub fn hole(mut self, seat: usize, cards: HoleCards) -> Self {
+        if self.hole.len() <= seat {
+            self.hole.resize(seat + 1, None);
+        }
+        self.hole[seat] = Some(cards);
+        self
+    }
+
+    /// Script the board in dealing order (flop, turn, river); up to five cards.
+    pub fn board<I>(mut self, cards: I) -> Self
+    where
+        I: IntoIterator<Item = Card>,
+    {
+        self.board = cards.into_iter().collect();
+        self
+    }
+
+    /// Script the second board of a double-board hand; up to five cards.
+    pub fn second_board<I>(mut self, cards: I) -> Self
+    where
+        I: IntoIterator<Item = Card>,
+    {
+        self.second_board = cards.into_iter().collect();
+        self
+    }
+
+    /// Scripted hole cards for a seat, if any.
+    pub fn hole_for(&self, seat: usize) -> Option<HoleCards> {
+        self.hole.get(seat).copied().flatten()
+    }
+
+    /// Scripted board cards.
+    pub fn board_cards(&self) -> &[Card] {
+        &self.board
+    }
+
+    /// Scripted second board cards.
+    pub fn second_board_cards(&self) -> &[Card] {
+        &self.second_board
+    }
+
+    fn cards(&self) -> Vec<Card> {
+        let mut cards: Vec<Card> =
+            self.hole.iter().flatten().flat_map(|h| h.as_slice().to_vec()).collect();
+        cards.extend_from_slice(&self.board);
+        cards.extend_from_slice(&self.second_board);
+        cards
+    }
+
+    fn validate(&self, num_players: usize) -> Result<(), DealError> {
+        if let Some(seat) = self.hole.iter().rposition(Option::is_some) {
+            if seat >= num_players {
+                return Err(DealError::SeatOutOfRange { seat, num_players });
+            }
+        }
+        if let Some(len) = [self.board.len(), self.second_board.len()].into_iter().find(|&l| l > 5)
+        {
+            return Err(DealError::TooManyBoardCards(len));
+        }
+        let mut seen = HashSet::new();
+        for c in self.cards() {
+            if !seen.insert(c) {
+                return Err(DealError::DuplicateCard(c));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Inclusive range of bet or raise-to totals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BetRange {
+    pub min: u64,
+    pub max: u64,
+}
+
+impl BetRange {
+    /// Whether `amount` is a legal total within this range.
+    pub fn contains(&self, amount: u64) -> bool {
+        (self.min..=self.max).contains(&amount)
+    }
+
+    /// Clamp `amount` into this range.
+    pub fn clamp(&self, amount: u64) -> u64 {
+        amount.clamp(self.min, self.max)
+    }
+}
+
+/// What the current actor may legally do.
+///
+/// `bet` is set only when nobody has bet this street. `raise` is set only
+/// when the player faces a bet they can more than call and the betting is
+/// still open to them. Both ranges are street totals: the player's bet after
+/// acting. A range whose `min` and `max` both equal `all_in` is a short
+/// all-in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct LegalActions {
+    pub seat: usize,
+    pub can_fold: bool,
+    pub can_check: bool,
+    pub can_call: bool,
+    /// Chips needed to call, capped at the player's stack
+    pub call_amount: u64,
+    pub bet: Option<BetRange>,
+    pub raise: Option<BetRange>,
+    /// Street total if the player moves all-in, capped at the call when a
+    /// short all-in has not reopened the betting for them. Under pot limit it
+    /// may exceed the largest legal bet or raise.
+    pub all_in: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub struct HandHistoryEntry {
     pub seat: usize,
     pub verb: HandHistoryVerb,
     pub amount: Option<u64>,
     pub street: Street,
+    /// Cards revealed by a [`HandHistoryVerb::Show`] entry
+    #[serde(default)]
+    pub cards: Option<HoleCards>,
 }
 
 #[derive(Debug, Clone)]
@@ -95,9 +549,14 @@ pub struct Player {
     pub(crate) stack: u64,
     pub(crate) bet: u64,
     pub(crate) contributed: u64,
+    /// Dead blind within `contributed`, which is never returned uncalled
+    pub(crate) dead: u64,
     pub(crate) status: PlayerStatus,
     pub(crate) hole: Option<HoleCards>,
     pub(crate) last_action: Option<String>,
+    pub(crate) missed_blind: Option<MissedBlind>,
+    /// Bet level this player last acted at in the current betting round
+    pub(crate) acted_at: Option<u64>,
 }
 
 impl Player {
@@ -135,12 +594,48 @@ impl Player {
     pub fn last_action(&self) -> Option<&str> {
         self.last_action.as_deref()
     }
+
+    /// Returns the blinds this player owes from sitting out, if any
+    pub fn missed_blind(&self) -> Option<MissedBlind> {
+        self.missed_blind
+    }
+
+    /// Whether someone is sitting in this seat (including sitting out)
+    pub fn is_seated(&self) -> bool {
+        !matches!(self.status, PlayerStatus::Empty)
+    }
+
+    fn seated(name: String, stack: u64) -> Self {
+        Self {
+            name,
+            stack,
+            bet: 0,
+            contributed: 0,
+            dead: 0,
+            status: PlayerStatus::Active,
+            hole: None,
+            last_action: None,
+            missed_blind: None,
+            acted_at: None,
+        }
+    }
+
+    /// Whether the next hand will deal this seat in.
+    fn will_be_dealt(&self) -> bool {
+        self.stack > 0 && !matches!(self.status, PlayerStatus::Empty | PlayerStatus::SittingOut)
+    }
+
+    fn empty_seat() -> Self {
+        Self { status: PlayerStatus::Empty, ..Self::seated(String::new(), 0) }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct PotBreakdown {
     pub(crate) main: u64,
     pub(crate) sides: Vec<u64>,
+    /// Top contribution nobody has matched (yet)
+    pub(crate) uncalled: u64,
 }
 
 #[derive(Debug)]
@@ -152,6 +647,14 @@ pub struct Game {
 
     pub(crate) deck: Deck,
     pub(crate) board: Board,
+    /// Second board of a double-board hand; `None` in single-board hands
+    pub(crate) second_board: Option<Board>,
+    /// Deal two boards from the next hand on
+    pub(crate) double_board: bool,
+    /// Game being played: deck, hole cards and hand rankings
+    pub(crate) variant: Arc<dyn Variant>,
+    /// Variant chosen mid-hand, applied when the next hand starts
+    pub(crate) next_variant: Option<Arc<dyn Variant>>,
     pub(crate) players: Vec<Player>,
     pub(crate) pot: u64,
     pub(crate) dealer: usize,
@@ -161,35 +664,120 @@ pub struct Game {
     pub(crate) current_bet: u64,
     pub(crate) min_raise: u64,
     pub(crate) last_raiser: Option<usize>,
-    pub(crate) round_starter: usize,
     pub(crate) sb_pos: Option<usize>,
     pub(crate) bb_pos: Option<usize>,
+    /// Seat the small blind fell on this hand, even when nobody could post it
+    pub(crate) sb_slot: Option<usize>,
+    /// Seat the big blind fell on this hand, even in a bomb pot where nobody
+    /// posts it
+    pub(crate) bb_slot: Option<usize>,
+    pub(crate) button_rule: ButtonRule,
+    pub(crate) bet_limit: BetLimit,
+    pub(crate) odd_chip_rule: OddChipRule,
+    /// Smallest chip in play; split pots are divided in multiples of it
+    pub(crate) chip_unit: u64,
     /// Winners of the last completed hand (seat indices in table order)
     pub(crate) winners: Vec<usize>,
     /// Showdown categories for each player in the last hand (None if folded/unknown)
     pub(crate) showdown_categories: Vec<Option<Category>>,
-    hand_history: Vec<HandHistoryEntry>,
+    /// Full result of the last completed hand
+    pub(crate) showdown: Option<ShowdownResult>,
+    pub(crate) hand_history: Vec<HandHistoryEntry>,
+    /// Master seed every hand's deck seed is derived from
+    pub(crate) seed: u64,
+    /// Number of hands dealt so far (1-based once the first hand starts)
+    pub(crate) hand_number: u64,
+    /// Seed used to shuffle the deck for the current hand
+    pub(crate) hand_seed: u64,
+    /// State before each action of the current hand, most recent last
+    undo_stack: Vec<Checkpoint>,
+    /// Chips at the table: every stack plus the pot
+    pub(crate) chips_in_play: u64,
+    /// Run [`check_invariants`](Self::check_invariants) after every action
+    pub(crate) invariant_checks: bool,
+    /// Problems repaired or swallowed during the current hand
+    pub(crate) violations: Vec<InvariantViolation>,
+    /// Buy-ins and per-hand results of everyone who has sat at the table
+    pub(crate) ledger: SessionLedger,
+    pub(crate) buy_in_rules: BuyInRules,
+}
+
+/// In-hand state captured before an action so it can be undone.
+#[derive(Debug, Clone)]
+struct Checkpoint {
+    deck: Deck,
+    board: Board,
+    second_board: Option<Board>,
+    players: Vec<Player>,
+    pot: u64,
+    current: usize,
+    street: Street,
+    current_bet: u64,
+    min_raise: u64,
+    last_raiser: Option<usize>,
+    winners: Vec<usize>,
+    showdown_categories: Vec<Option<Category>>,
+    showdown: Option<ShowdownResult>,
+    history_len: usize,
+    violations: Vec<InvariantViolation>,
+}
+
+/// Derive a per-hand deck seed from a master seed and a hand number.
+///
+/// Uses the SplitMix64 finalizer so consecutive hand numbers map to
+/// well-separated seeds.
+pub fn derive_hand_seed(master: u64, hand_number: u64) -> u64 {
+    let mut z = master.wrapping_add(hand_number.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
 }
 
 impl Game {
+    /// Create a game with a random master seed.
     pub fn new(num_players: usize, starting_stack: u64, small_blind: u64, big_blind: u64) -> Self {
-        let players = (1..=num_players)
-            .map(|i| Player {
-                name: format!("P{i}"),
-                stack: starting_stack,
-                bet: 0,
-                contributed: 0,
-                status: PlayerStatus::Active,
-                hole: None,
-                last_action: None,
-            })
-            .collect();
+        let seed: u64 = rand::rng().random();
+        Self::with_seed(num_players, starting_stack, small_blind, big_blind, seed)
+    }
+
+    /// Create a game whose deck order is fully determined by `seed`.
+    ///
+    /// Each hand's deck seed is derived from the master seed and the hand
+    /// number, so replaying the same actions reproduces the same hands.
+    ///
+    /// ```
+    /// use poker_rs::game::Game;
+    ///
+    /// let mut a = Game::with_seed(3, 1000, 5, 10, 42);
+    /// let mut b = Game::with_seed(3, 1000, 5, 10, 42);
+    /// a.new_hand().unwrap();
+    /// b.new_hand().unwrap();
+    /// assert_eq!(a.hand_seed(), b.hand_seed());
+    /// assert_eq!(a.players()[0].hole(), b.players()[0].hole());
+    /// ```
+    pub fn with_seed(
+        num_players: usize,
+        starting_stack: u64,
+        small_blind: u64,
+        big_blind: u64,
+        seed: u64,
+    ) -> Self {
+        let players: Vec<Player> =
+            (1..=num_players).map(|i| Player::seated(format!("P{i}"), starting_stack)).collect();
+        let mut ledger = SessionLedger::new(big_blind);
+        for p in &players {
+            ledger.record_buy_in(&p.name, starting_stack);
+        }
         Self {
             small_blind,
             big_blind,
             starting_stack,
             deck: Deck::standard(),
             board: Board::new(Vec::new()),
+            second_board: None,
+            double_board: false,
+            variant: Arc::new(Holdem),
+            next_variant: None,
             players,
             pot: 0,
             dealer: 0,
@@ -198,15 +786,58 @@ impl Game {
             current_bet: 0,
             min_raise: big_blind,
             last_raiser: None,
-            round_starter: 0,
             sb_pos: None,
             bb_pos: None,
+            sb_slot: None,
+            bb_slot: None,
+            button_rule: ButtonRule::default(),
+            bet_limit: BetLimit::default(),
+            odd_chip_rule: OddChipRule::default(),
+            chip_unit: 1,
             winners: Vec::new(),
             showdown_categories: vec![None; num_players],
+            showdown: None,
             hand_history: Vec::new(),
+            seed,
+            hand_number: 0,
+            hand_seed: 0,
+            undo_stack: Vec::new(),
+            chips_in_play: starting_stack * num_players as u64,
+            invariant_checks: false,
+            violations: Vec::new(),
+            ledger,
+            buy_in_rules: BuyInRules::default(),
         }
     }
 
+    /// Create a table of `num_seats` empty seats for players to [`join`](Self::join).
+    ///
+    /// `starting_stack` is kept as the table's default buy-in.
+    ///
+    /// ```
+    /// use poker_rs::game::{Game, Street};
+    ///
+    /// let mut game = Game::empty_table(6, 1000, 5, 10);
+    /// game.join(1, "Alice", 500).unwrap();
+    /// game.join(4, "Bob", 800).unwrap();
+    /// game.new_hand().unwrap();
+    /// assert_eq!(game.seated_count(), 2);
+    /// assert_eq!(game.street(), Street::Preflop);
+    /// assert!(game.players()[0].hole().is_none());
+    /// ```
+    pub fn empty_table(
+        num_seats: usize,
+        starting_stack: u64,
+        small_blind: u64,
+        big_blind: u64,
+    ) -> Self {
+        let mut game = Self::new(num_seats, starting_stack, small_blind, big_blind);
+        game.players.fill_with(Player::empty_seat);
+        game.chips_in_play = 0;
+        game.ledger = SessionLedger::new(big_blind);
+        game
+    }
+
     /// Returns the small blind amount
     pub fn small_blind(&self) -> u64 {
         self.small_blind
@@ -227,6 +858,83 @@ impl Game {
         &self.board
     }
 
+    /// Returns the second board of a double-board hand
+    pub fn second_board(&self) -> Option<&Board> {
+        self.second_board.as_ref()
+    }
+
+    /// Whether hands are dealt with two boards
+    pub fn double_board(&self) -> bool {
+        self.double_board
+    }
+
+    /// Deal two boards, splitting each pot between the best hand on each;
+    /// takes effect from the next hand.
+    ///
+    /// Fails if the deck could not deal both boards to a full table.
+    pub fn set_double_board(&mut self, enabled: bool) -> Result<(), DealError> {
+        let variant = self.next_hand_variant();
+        Self::check_deck_capacity(variant, self.players.len(), enabled, variant.deck().len())?;
+        self.double_board = enabled;
+        Ok(())
+    }
+
+    /// Returns the variant being played
+    pub fn variant(&self) -> &dyn Variant {
+        &*self.variant
+    }
+
+    /// Play `variant` from now on; chosen during a hand, it takes effect from
+    /// the next hand. Fails if the variant's deck is too small to deal a full
+    /// table.
+    ///
+    /// ```
+    /// use poker_rs::game::Game;
+    /// use poker_rs::variants::{Omaha, ShortDeck};
+    /// use std::sync::Arc;
+    ///
+    /// let mut game = Game::new(3, 1000, 5, 10);
+    /// game.set_variant(Arc::new(Omaha)).unwrap();
+    /// game.new_hand().unwrap();
+    /// assert_eq!(game.players()[0].hole().unwrap().as_slice().len(), 4);
+    ///
+    /// // 18 players need 41 cards; the short deck has 36.
+    /// let mut game = Game::new(18, 1000, 5, 10);
+    /// assert!(game.set_variant(Arc::new(ShortDeck)).is_err());
+    /// ```
+    pub fn set_variant(&mut self, variant: Arc<dyn Variant>) -> Result<(), DealError> {
+        let (seats, double_board) = (self.players.len(), self.double_board);
+        Self::check_deck_capacity(&*variant, seats, double_board, variant.deck().len())?;
+        if self.hand_in_progress() {
+            self.next_variant = Some(variant);
+        } else {
+            self.variant = variant;
+            self.next_variant = None;
+        }
+        Ok(())
+    }
+
+    /// Check a deck of `available` cards can deal `players` hands of
+    /// `variant` plus every board.
+    fn check_deck_capacity(
+        variant: &dyn Variant,
+        players: usize,
+        double_board: bool,
+        available: usize,
+    ) -> Result<(), DealError> {
+        let boards = if double_board { 2 } else { 1 };
+        let needed = players * variant.hole_cards() + boards * 5;
+        if needed > available {
+            return Err(DealError::DeckTooSmall { needed, available });
+        }
+        Ok(())
+    }
+
+    /// Variant the next hand will be dealt with.
+    fn next_hand_variant(&self) -> &dyn Variant {
+        self.next_variant.as_deref().unwrap_or(&*self.variant)
+    }
+
     /// Returns a reference to the players
     pub fn players(&self) -> &[Player] {
         &self.players
@@ -272,6 +980,58 @@ impl Game {
         self.bb_pos
     }
 
+    /// Returns how the button moves past empty and sitting-out seats
+    pub fn button_rule(&self) -> ButtonRule {
+        self.button_rule
+    }
+
+    /// Choose how the button moves; takes effect from the next hand.
+    pub fn set_button_rule(&mut self, rule: ButtonRule) {
+        self.button_rule = rule;
+    }
+
+    /// Returns the betting structure
+    pub fn bet_limit(&self) -> BetLimit {
+        self.bet_limit
+    }
+
+    /// Choose the betting structure, e.g. pot limit for Pot-Limit Omaha.
+    ///
+    /// ```
+    /// use poker_rs::game::{BetLimit, Game};
+    ///
+    /// let mut game = Game::new(3, 1000, 5, 10);
+    /// game.set_bet_limit(BetLimit::PotLimit);
+    /// game.new_hand().unwrap();
+    /// // Under the gun may raise to 35: the 10 to call plus the 25 pot after calling.
+    /// assert_eq!(game.legal_actions().unwrap().raise.unwrap().max, 35);
+    /// ```
+    pub fn set_bet_limit(&mut self, limit: BetLimit) {
+        self.bet_limit = limit;
+    }
+
+    /// Returns who receives odd chips in a split pot
+    pub fn odd_chip_rule(&self) -> OddChipRule {
+        self.odd_chip_rule
+    }
+
+    /// Choose who receives odd chips in a split pot.
+    pub fn set_odd_chip_rule(&mut self, rule: OddChipRule) {
+        self.odd_chip_rule = rule;
+    }
+
+    /// Returns the smallest chip denomination in play
+    pub fn chip_unit(&self) -> u64 {
+        self.chip_unit
+    }
+
+    /// Set the smallest chip denomination in play (at least 1). Split pots are
+    /// divided in whole chips of this size, and the odd chips left over are
+    /// awarded a chip at a time by the [`OddChipRule`].
+    pub fn set_chip_unit(&mut self, unit: u64) {
+        self.chip_unit = unit.max(1);
+    }
+
     /// Returns the winners of the last completed hand
     pub fn winners(&self) -> &[usize] {
         &self.winners
@@ -282,6 +1042,26 @@ impl Game {
         &self.showdown_categories
     }
 
+    /// Returns the pots, winners and hands of the last completed hand
+    pub fn showdown_result(&self) -> Option<&ShowdownResult> {
+        self.showdown.as_ref()
+    }
+
+    /// Returns the master seed hands are derived from
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the number of hands dealt so far
+    pub fn hand_number(&self) -> u64 {
+        self.hand_number
+    }
+
+    /// Returns the deck seed of the current hand
+    pub fn hand_seed(&self) -> u64 {
+        self.hand_seed
+    }
+
     pub fn history_recent(&self, n: usize) -> Vec<HandHistoryEntry> {
         if n == 0 {
             return Vec::new();
@@ -310,15 +1090,324 @@ impl Game {
         self.hand_history.len()
     }
 
-    pub fn new_hand(&mut self) {
+    /// Number of occupied seats, including players sitting out.
+    pub fn seated_count(&self) -> usize {
+        self.players.iter().filter(|p| p.is_seated()).count()
+    }
+
+    /// Whether a hand has been dealt and has not reached showdown yet.
+    pub fn hand_in_progress(&self) -> bool {
+        self.hand_number > 0 && !matches!(self.street, Street::Showdown)
+    }
+
+    /// Seat a new player with `buy_in` chips; they are dealt in from the next hand.
+    pub fn join(&mut self, seat: usize, name: &str, buy_in: u64) -> Result<(), SeatError> {
+        self.check_seat_change(seat)?;
+        if self.players[seat].is_seated() {
+            return Err(SeatError::SeatTaken(seat));
+        }
+        if buy_in == 0 {
+            return Err(SeatError::ZeroBuyIn);
+        }
+        // The ledger tracks players by name, so two seats cannot share one.
+        if self.players.iter().any(|p| p.is_seated() && p.name == name) {
+            return Err(SeatError::NameTaken(name.to_string()));
+        }
+        self.players[seat] = Player::seated(name.to_string(), buy_in);
+        self.chips_in_play += buy_in;
+        self.ledger.record_buy_in(name, buy_in);
+        self.undo_stack.clear();
+        Ok(())
+    }
+
+    /// Remove the player at `seat`, returning the stack they leave with.
+    pub fn leave(&mut self, seat: usize) -> Result<u64, SeatError> {
+        self.check_seat_change(seat)?;
+        if !self.players[seat].is_seated() {
+            return Err(SeatError::SeatEmpty(seat));
+        }
+        let stack = self.players[seat].stack;
+        let player = std::mem::replace(&mut self.players[seat], Player::empty_seat());
+        self.chips_in_play -= stack;
+        self.ledger.record_cash_out(&player.name, stack);
+        self.showdown_categories[seat] = None;
+        self.winners.retain(|&w| w != seat);
+        self.undo_stack.clear();
+        Ok(stack)
+    }
+
+    /// Keep the player at `seat` out of hands until [`sit_in`](Self::sit_in).
+    pub fn sit_out(&mut self, seat: usize) -> Result<(), SeatError> {
+        self.check_seat_change(seat)?;
+        if !self.players[seat].is_seated() {
+            return Err(SeatError::SeatEmpty(seat));
+        }
+        self.players[seat].status = PlayerStatus::SittingOut;
+        self.undo_stack.clear();
+        Ok(())
+    }
+
+    /// Deal a sitting-out player back in from the next hand.
+    pub fn sit_in(&mut self, seat: usize) -> Result<(), SeatError> {
+        self.check_seat_change(seat)?;
+        let p = &mut self.players[seat];
+        if !p.is_seated() {
+            return Err(SeatError::SeatEmpty(seat));
+        }
+        if matches!(p.status, PlayerStatus::SittingOut) {
+            p.status = if p.stack == 0 { PlayerStatus::Folded } else { PlayerStatus::Active };
+        }
+        self.undo_stack.clear();
+        Ok(())
+    }
+
+    /// Rules for rebuys, top-ups and add-ons
+    pub fn buy_in_rules(&self) -> BuyInRules {
+        self.buy_in_rules
+    }
+
+    pub fn set_buy_in_rules(&mut self, rules: BuyInRules) {
+        self.buy_in_rules = rules;
+    }
+
+    /// Buy `amount` chips for the busted player at `seat`, who is dealt back
+    /// in from the next hand.
+    pub fn rebuy(&mut self, seat: usize, amount: u64) -> Result<(), BuyInError> {
+        self.check_buy_in(seat, amount)?;
+        if !self.buy_in_rules.rebuys {
+            return Err(BuyInError::RebuysNotAllowed);
+        }
+        self.check_rebuy_period()?;
+        if self.players[seat].stack > 0 {
+            return Err(BuyInError::NotBusted(seat));
+        }
+        self.add_chips(seat, amount)
+    }
+
+    /// Add `amount` chips to the stack of the player at `seat`, who must
+    /// still have chips.
+    pub fn top_up(&mut self, seat: usize, amount: u64) -> Result<(), BuyInError> {
+        self.check_buy_in(seat, amount)?;
+        if !self.buy_in_rules.top_ups {
+            return Err(BuyInError::TopUpsNotAllowed);
+        }
+        if self.players[seat].stack == 0 {
+            return Err(BuyInError::Busted(seat));
+        }
+        self.add_chips(seat, amount)
+    }
+
+    /// Take the table's add-on for the player at `seat`, once per player
+    /// while rebuys are open. Returns the chips added.
+    pub fn add_on(&mut self, seat: usize) -> Result<u64, BuyInError> {
+        let amount = self.buy_in_rules.add_on.ok_or(BuyInError::NoAddOn)?;
+        self.check_buy_in(seat, amount)?;
+        self.check_rebuy_period()?;
+        let name = &self.players[seat].name;
+        if self.ledger.player(name).is_some_and(|p| p.add_ons > 0) {
+            return Err(BuyInError::AddOnTaken(seat));
+        }
+        self.players[seat].stack += amount;
+        self.chips_in_play += amount;
+        self.ledger.record_add_on(&self.players[seat].name, amount);
+        self.undo_stack.clear();
+        Ok(amount)
+    }
+
+    fn check_buy_in(&self, seat: usize, amount: u64) -> Result<(), SeatError> {
+        self.check_seat_change(seat)?;
+        if !self.players[seat].is_seated() {
+            return Err(SeatError::SeatEmpty(seat));
+        }
+        if amount == 0 {
+            return Err(SeatError::ZeroBuyIn);
+        }
+        Ok(())
+    }
+
+    fn check_rebuy_period(&self) -> Result<(), BuyInError> {
+        match self.buy_in_rules.rebuy_hands {
+            Some(hands) if self.hand_number >= hands => Err(BuyInError::RebuyPeriodOver(hands)),
+            _ => Ok(()),
+        }
+    }
+
+    fn add_chips(&mut self, seat: usize, amount: u64) -> Result<(), BuyInError> {
+        let stack = self.players[seat].stack + amount;
+        if let Some(max) = self.buy_in_rules.max_buy_in.filter(|&max| stack > max) {
+            return Err(BuyInError::AboveMaxBuyIn { max, stack });
+        }
+        self.players[seat].stack = stack;
+        self.chips_in_play += amount;
+        self.ledger.record_rebuy(&self.players[seat].name, amount);
+        self.undo_stack.clear();
+        Ok(())
+    }
+
+    /// Refill short stacks, busted ones included, to the maximum buy-in.
+    fn auto_top_up(&mut self) {
+        for seat in 0..self.players.len() {
+            let amount = self.auto_top_up_amount(seat);
+            if amount == 0 {
+                continue;
+            }
+            let p = &mut self.players[seat];
+            p.stack += amount;
+            self.chips_in_play += amount;
+            self.ledger.record_rebuy(&p.name, amount);
+        }
+    }
+
+    /// Chips the automatic top-up adds to `seat` before the next hand.
+    fn auto_top_up_amount(&self, seat: usize) -> u64 {
+        let BuyInRules { max_buy_in: Some(max), auto_top_up: true, .. } = self.buy_in_rules else {
+            return 0;
+        };
+        let p = &self.players[seat];
+        if !p.is_seated() || matches!(p.status, PlayerStatus::SittingOut) {
+            return 0;
+        }
+        max.saturating_sub(p.stack)
+    }
+
+    fn check_seat_change(&self, seat: usize) -> Result<(), SeatError> {
+        if seat >= self.players.len() {
+            return Err(SeatError::SeatOutOfRange { seat, num_seats: self.players.len() });
+        }
+        if self.hand_in_progress() {
+            return Err(SeatError::HandInProgress);
+        }
+        Ok(())
+    }
+
+    /// Start a new hand.
+    ///
+    /// Fails without touching the game if the deck is too small to deal
+    /// everyone in; the same holds for every `new_hand_*` method.
+    pub fn new_hand(&mut self) -> Result<(), DealError> {
+        let hand_seed = derive_hand_seed(self.seed, self.hand_number + 1);
+        self.new_hand_with_seed(hand_seed)
+    }
+
+    /// Start a new hand shuffling the deck with an explicit seed.
+    ///
+    /// Useful to replay a single reported hand from its `hand_seed`.
+    pub fn new_hand_with_seed(&mut self, hand_seed: u64) -> Result<(), DealError> {
+        let mut deck = self.next_hand_variant().deck();
+        deck.shuffle_seeded(hand_seed);
+        self.start_hand(hand_seed, deck, HandOptions::default())
+    }
+
+    /// Start a new hand with per-hand options such as a bomb pot.
+    pub fn new_hand_with(&mut self, options: HandOptions) -> Result<(), DealError> {
+        let hand_seed = derive_hand_seed(self.seed, self.hand_number + 1);
+        let mut deck = self.next_hand_variant().deck();
+        deck.shuffle_seeded(hand_seed);
+        self.start_hand(hand_seed, deck, options)
+    }
+
+    /// Start a new hand dealing from a caller-provided deck, top card first.
+    ///
+    /// Each seated player with chips receives the variant's hole cards in turn,
+    /// in seat order, followed by the flop, turn and river.
+    pub fn new_hand_with_deck(&mut self, deck: Deck) -> Result<(), DealError> {
+        let hand_seed = derive_hand_seed(self.seed, self.hand_number + 1);
+        self.start_hand(hand_seed, deck, HandOptions::default())
+    }
+
+    /// Start a new hand with predetermined hole cards and board.
+    ///
+    /// Fails without touching the game if the script repeats a card, names a
+    /// seat that does not exist, scripts cards for a seat that is empty,
+    /// sitting out or has no chips, or does not fit the variant's deck and
+    /// hole card count.
+    pub fn new_hand_scripted(&mut self, script: &DealScript) -> Result<(), DealError> {
+        script.validate(self.players.len())?;
+        if !self.double_board && !script.second_board_cards().is_empty() {
+            return Err(DealError::NoSecondBoard);
+        }
+        let variant = self.next_hand_variant();
+        let hole_cards = variant.hole_cards();
+        for (seat, hole) in script.hole.iter().enumerate() {
+            let got = hole.map_or(hole_cards, |h| h.as_slice().len());
+            if got != hole_cards {
+                return Err(DealError::HoleCount { seat, expected: hole_cards, got });
+            }
+        }
+        let hand_seed = derive_hand_seed(self.seed, self.hand_number + 1);
+        let mut pool = variant.deck();
+        let scripted = script.cards();
+        if let Some(&card) = scripted.iter().find(|&&c| !pool.contains(c)) {
+            return Err(DealError::CardNotInDeck(card));
+        }
+        pool.shuffle_seeded(hand_seed);
+        pool.remove_cards(&scripted);
+
+        let mut order: Vec<Card> = Vec::with_capacity(52);
+        for (seat, p) in self.players.iter().enumerate() {
+            let scripted = script.hole_for(seat);
+            if !p.will_be_dealt() && self.auto_top_up_amount(seat) == 0 {
+                if scripted.is_some() {
+                    return Err(DealError::SeatNotDealt(seat));
+                }
+                continue;
+            }
+            match scripted {
+                Some(h) => order.extend_from_slice(h.as_slice()),
+                None => order.extend(pool.draw_n(hole_cards)),
+            }
+        }
+        // Boards are dealt street by street, board one first on each street.
+        let mut boards = vec![script.board_cards()];
+        if self.double_board {
+            boards.push(script.second_board_cards());
+        }
+        for street in [0..3, 3..4, 4..5] {
+            for board in &boards {
+                for i in street.clone() {
+                    order.extend(board.get(i).copied().or_else(|| pool.draw()));
+                }
+            }
+        }
+        order.extend(pool.draw_order());
+
+        let deck = Deck::from_draw_order(order)?;
+        self.start_hand(hand_seed, deck, HandOptions::default())
+    }
+
+    fn start_hand(
+        &mut self,
+        hand_seed: u64,
+        deck: Deck,
+        options: HandOptions,
+    ) -> Result<(), DealError> {
+        let dealt = (0..self.players.len())
+            .filter(|&seat| self.players[seat].will_be_dealt() || self.auto_top_up_amount(seat) > 0)
+            .count();
+        Self::check_deck_capacity(self.next_hand_variant(), dealt, self.double_board, deck.len())?;
+        if let Some(variant) = self.next_variant.take() {
+            self.variant = variant;
+        }
+        self.hand_number += 1;
+        self.hand_seed = hand_seed;
+        self.undo_stack.clear();
+        self.violations.clear();
+        self.auto_top_up();
+        let prev_blinds = (self.sb_slot, self.bb_slot);
         self.advance_dealer();
         self.reset_hand_state();
+        self.deck = deck;
         self.reset_players_for_new_hand();
         self.align_dealer_to_eligible();
         self.winners.clear();
+        self.showdown = None;
         self.showdown_categories = vec![None; self.players.len()];
         self.deal_hole_cards();
-        self.setup_preflop();
+        match options.bomb_pot {
+            Some(ante) => self.setup_bomb_pot(ante, prev_blinds),
+            None => self.setup_preflop(prev_blinds),
+        }
+        Ok(())
     }
 
     fn advance_dealer(&mut self) {
@@ -328,28 +1417,32 @@ impl Game {
     }
 
     fn reset_hand_state(&mut self) {
-        self.deck = Deck::standard();
-        let seed: u64 = rand::rng().random();
-        self.deck.shuffle_seeded(seed);
         self.board = Board::new(Vec::new());
+        self.second_board = self.double_board.then(|| Board::new(Vec::new()));
         self.pot = 0;
         self.street = Street::Preflop;
         self.hand_history.clear();
         self.current_bet = 0;
         self.min_raise = self.big_blind;
         self.last_raiser = None;
-        self.round_starter = self.dealer;
         self.current = self.dealer;
         self.sb_pos = None;
         self.bb_pos = None;
+        self.sb_slot = None;
+        self.bb_slot = None;
     }
 
     fn reset_players_for_new_hand(&mut self) {
         for p in &mut self.players {
             p.bet = 0;
             p.contributed = 0;
+            p.dead = 0;
             p.hole = None;
             p.last_action = None;
+            p.acted_at = None;
+            if matches!(p.status, PlayerStatus::Empty | PlayerStatus::SittingOut) {
+                continue;
+            }
             if p.stack == 0 {
                 p.status = PlayerStatus::Folded;
             } else {
@@ -374,13 +1467,11 @@ impl Game {
     }
 
     fn deal_hole_cards(&mut self) {
+        let count = self.variant.hole_cards();
         for p in &mut self.players {
             if matches!(p.status, PlayerStatus::Active) {
-                if let (Some(a), Some(b)) = (self.deck.draw(), self.deck.draw()) {
-                    if let Ok(hole) = HoleCards::try_new(a, b) {
-                        p.hole = Some(hole);
-                    }
-                }
+                let hole = HoleCards::from_slice(&self.deck.draw_n(count));
+                p.hole = Some(hole.expect("deck size is checked before dealing"));
             }
         }
     }
@@ -411,29 +1502,150 @@ impl Game {
         }
     }
 
-    fn setup_preflop(&mut self) {
+    /// Place the button and blinds under the dead button rule: the big blind
+    /// moves to the next player dealt in after last hand's big blind, the small
+    /// blind falls on last hand's big blind seat and the button on last hand's
+    /// small blind seat, either of which may be dead.
+    /// Returns (dealer, sb_slot, sb_pos, bb_pos), or `None` when the moving
+    /// rule should be used instead.
+    fn dead_button_positions(
+        &self,
+        prev_blinds: (Option<usize>, Option<usize>),
+        eligible_count: usize,
+    ) -> Option<(usize, usize, Option<usize>, usize)> {
+        let (prev_sb_slot, prev_bb) = prev_blinds;
+        let prev_bb = prev_bb.filter(|&s| s < self.players.len())?;
+        let bb = self.next_eligible_from(prev_bb);
+        if eligible_count == 2 {
+            // Heads-up the button posts the small blind.
+            let sb = self.next_eligible_from(bb);
+            return Some((sb, sb, Some(sb), bb));
+        }
+        let dealer = prev_sb_slot.filter(|&s| s < self.players.len())?;
+        if dealer == bb || prev_bb == bb {
+            return None;
+        }
+        let sb = self.is_eligible(prev_bb).then_some(prev_bb);
+        Some((dealer, prev_bb, sb, bb))
+    }
+
+    /// Move the button and blind slots for this hand and mark the seats the
+    /// blinds passed over. Returns (sb_pos, bb_pos) for the players who post.
+    fn place_blinds(
+        &mut self,
+        prev_blinds: (Option<usize>, Option<usize>),
+        eligible_count: usize,
+    ) -> (Option<usize>, usize) {
+        let dead = match self.button_rule {
+            ButtonRule::Dead => self.dead_button_positions(prev_blinds, eligible_count),
+            ButtonRule::Moving => None,
+        };
+        let (sb_slot, sb_pos, bb_pos) = match dead {
+            Some((dealer, sb_slot, sb, bb)) => {
+                self.dealer = dealer;
+                (sb_slot, sb, bb)
+            }
+            None => {
+                let (sb, bb) = self.determine_blind_positions(eligible_count);
+                (sb, Some(sb), bb)
+            }
+        };
+        self.sb_slot = Some(sb_slot);
+        self.bb_slot = Some(bb_pos);
+        self.record_missed_blinds(prev_blinds, sb_slot, bb_pos);
+        (sb_pos, bb_pos)
+    }
+
+    fn setup_preflop(&mut self, prev_blinds: (Option<usize>, Option<usize>)) {
         let eligible_count = self.count_eligible();
         if eligible_count < 2 {
             self.street = Street::Showdown;
-            self.round_starter = self.dealer;
             self.current = self.dealer;
             self.sb_pos = None;
             self.bb_pos = None;
             return;
         }
 
-        let (sb_pos, bb_pos) = self.determine_blind_positions(eligible_count);
-        self.sb_pos = Some(sb_pos);
+        let (sb_pos, bb_pos) = self.place_blinds(prev_blinds, eligible_count);
+        self.sb_pos = sb_pos;
         self.bb_pos = Some(bb_pos);
 
         let bb_paid = self.post_blinds(sb_pos, bb_pos);
-        self.current_bet = bb_paid;
+        let live_paid = self.post_missed_blinds(sb_pos, bb_pos);
+        self.current_bet = bb_paid.max(live_paid);
         // Minimum raise is based on what the BB actually posted, not the nominal blind
-        self.min_raise = bb_paid;
+        self.min_raise = self.current_bet;
         // Note: last_raiser is NOT set for blinds, only for actual raises
 
         self.current = self.determine_first_actor(bb_pos, eligible_count);
-        self.round_starter = self.current;
+        // Posting may put the first to act, or everyone, all-in.
+        if !self.is_eligible(self.current) {
+            self.current = self.next_eligible_from(self.current);
+        }
+        self.maybe_force_showdown();
+    }
+
+    /// Collect the bomb-pot ante as dead money from everyone dealt in, then
+    /// deal the flop. Players who cannot cover the ante are all-in for what
+    /// they have and only contest the matching share of the pot. Nobody posts
+    /// a blind, but the blinds still move round as if they had.
+    fn setup_bomb_pot(&mut self, ante: u64, prev_blinds: (Option<usize>, Option<usize>)) {
+        let eligible_count = self.count_eligible();
+        if eligible_count < 2 {
+            self.street = Street::Showdown;
+            self.current = self.dealer;
+            return;
+        }
+        self.place_blinds(prev_blinds, eligible_count);
+        self.current = self.dealer;
+        for seat in 0..self.players.len() {
+            if !self.is_eligible(seat) {
+                continue;
+            }
+            let paid = self.pay_dead(seat, ante);
+            self.players[seat].last_action = Some(format!("Ante {paid}"));
+            self.record_history(seat, HandHistoryVerb::Ante, Some(paid));
+        }
+        self.deal_next_street();
+        self.maybe_force_showdown();
+    }
+
+    /// Mark sitting-out players the blinds passed over since last hand.
+    fn record_missed_blinds(
+        &mut self,
+        prev_blinds: (Option<usize>, Option<usize>),
+        sb_slot: usize,
+        bb_pos: usize,
+    ) {
+        let n = self.players.len();
+        let passed = |from: Option<usize>, to: usize| -> Vec<usize> {
+            let Some(from) = from.filter(|&s| s < n && s != to) else {
+                return Vec::new();
+            };
+            let mut seats = Vec::new();
+            let mut i = (from + 1) % n;
+            while i != from {
+                seats.push(i);
+                if i == to {
+                    break;
+                }
+                i = (i + 1) % n;
+            }
+            seats
+        };
+        let (prev_sb_slot, prev_bb) = prev_blinds;
+        for seat in passed(prev_sb_slot, sb_slot) {
+            let p = &mut self.players[seat];
+            if matches!(p.status, PlayerStatus::SittingOut) && p.missed_blind.is_none() {
+                p.missed_blind = Some(MissedBlind::Small);
+            }
+        }
+        for seat in passed(prev_bb, bb_pos) {
+            let p = &mut self.players[seat];
+            if matches!(p.status, PlayerStatus::SittingOut) {
+                p.missed_blind = Some(MissedBlind::Big);
+            }
+        }
     }
 
     /// Pay an amount from a player's stack, updating their bet, contributed, and pot.
@@ -452,32 +1664,90 @@ impl Game {
         paid
     }
 
-    fn post_blinds(&mut self, sb_pos: usize, bb_pos: usize) -> u64 {
-        let sb_paid = self.pay_amount(sb_pos, self.small_blind);
-        self.players[sb_pos].last_action = Some(format!("SB {sb_paid}"));
-        self.record_history(sb_pos, HandHistoryVerb::SmallBlind, Some(sb_paid));
+    /// Pay dead chips: they go into the pot but do not count toward the player's bet.
+    fn pay_dead(&mut self, idx: usize, amount: u64) -> u64 {
+        let p = &mut self.players[idx];
+        let paid = p.stack.min(amount);
+        p.stack -= paid;
+        p.contributed += paid;
+        if p.stack == 0 {
+            p.status = PlayerStatus::AllIn;
+        }
+        self.pot += paid;
+        paid
+    }
+
+    /// Post both blinds and return the larger one actually paid, which is the
+    /// small blind when a short big blind is all-in for less.
+    fn post_blinds(&mut self, sb_pos: Option<usize>, bb_pos: usize) -> u64 {
+        let mut sb_paid = 0;
+        if let Some(sb_pos) = sb_pos {
+            sb_paid = self.pay_amount(sb_pos, self.small_blind);
+            self.players[sb_pos].last_action = Some(format!("SB {sb_paid}"));
+            self.record_history(sb_pos, HandHistoryVerb::SmallBlind, Some(sb_paid));
+        }
 
         let bb_paid = self.pay_amount(bb_pos, self.big_blind);
         self.players[bb_pos].last_action = Some(format!("BB {bb_paid}"));
         self.record_history(bb_pos, HandHistoryVerb::BigBlind, Some(bb_paid));
 
-        bb_paid
+        bb_paid.max(sb_paid)
+    }
+
+    /// Collect blinds owed by players back from sitting out: a live big blind
+    /// plus a dead small blind, or just the dead small blind. Players in the
+    /// blinds this hand only post their blind. Returns the largest live post.
+    fn post_missed_blinds(&mut self, sb_pos: Option<usize>, bb_pos: usize) -> u64 {
+        let mut live_max = 0;
+        for seat in 0..self.players.len() {
+            let Some(missed) = self.players[seat].missed_blind else {
+                continue;
+            };
+            if !self.is_eligible(seat) {
+                continue;
+            }
+            self.players[seat].missed_blind = None;
+            if seat == bb_pos || sb_pos == Some(seat) {
+                continue;
+            }
+            let mut posted = Vec::new();
+            if matches!(missed, MissedBlind::Big) {
+                let live = self.pay_amount(seat, self.big_blind);
+                self.record_history(seat, HandHistoryVerb::BigBlind, Some(live));
+                posted.push(format!("BB {live}"));
+                live_max = live_max.max(live);
+            }
+            if self.players[seat].stack > 0 {
+                let dead = self.pay_dead(seat, self.small_blind);
+                self.players[seat].dead += dead;
+                self.record_history(seat, HandHistoryVerb::DeadBlind, Some(dead));
+                posted.push(format!("Dead {dead}"));
+            }
+            self.players[seat].last_action = Some(posted.join(" + "));
+        }
+        live_max
     }
 
     pub(crate) fn pot_breakdown(&self) -> PotBreakdown {
-        let mut levels: Vec<u64> =
-            self.players.iter().map(|p| p.contributed).filter(|&c| c > 0).collect();
+        let uncalled_bet = self.uncalled_bet();
+        let uncalled = uncalled_bet.map(|(_, amount)| amount).unwrap_or(0);
+        // The uncalled part belongs to one player only; it is not a pot.
+        let contributed: Vec<u64> = (0..self.players.len())
+            .map(|i| match uncalled_bet {
+                Some((seat, amount)) if seat == i => self.players[i].contributed - amount,
+                _ => self.players[i].contributed,
+            })
+            .collect();
+        let mut levels: Vec<u64> = contributed.iter().copied().filter(|&c| c > 0).collect();
         levels.sort_unstable();
         levels.dedup();
         if levels.is_empty() {
-            return PotBreakdown { main: 0, sides: Vec::new() };
+            return PotBreakdown { main: 0, sides: Vec::new(), uncalled };
         }
         let mut pots: Vec<u64> = Vec::new();
         let mut prev = 0u64;
         for lvl in levels {
-            let contributors =
-                self.players.iter().filter(|p| p.contributed >= lvl && p.contributed > 0).count()
-                    as u64;
+            let contributors = contributed.iter().filter(|&&c| c >= lvl && c > 0).count() as u64;
             if contributors == 0 {
                 prev = lvl;
                 continue;
@@ -490,34 +1760,62 @@ impl Game {
         }
         let main = pots.first().copied().unwrap_or(0);
         let sides = if pots.len() > 1 { pots[1..].to_vec() } else { Vec::new() };
-        PotBreakdown { main, sides }
+        PotBreakdown { main, sides, uncalled }
+    }
+
+    /// The seat whose live contribution exceeds everyone else's and by how
+    /// much. A dead blind is left out: it was never a bet anyone had to call.
+    fn uncalled_bet(&self) -> Option<(usize, u64)> {
+        let live = |p: &Player| p.contributed - p.dead;
+        let (top, top_amount) =
+            self.players.iter().enumerate().map(|(i, p)| (i, live(p))).max_by_key(|&(_, c)| c)?;
+        let second = self
+            .players
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| i != top)
+            .map(|(_, p)| live(p))
+            .max()
+            .unwrap_or(0);
+        let excess = top_amount - second.min(top_amount);
+        (excess > 0).then_some((top, excess))
+    }
+
+    /// Give back the part of the top bet nobody called, before any pot is awarded.
+    fn return_uncalled_bet(&mut self) {
+        let Some((seat, amount)) = self.uncalled_bet() else {
+            return;
+        };
+        let p = &mut self.players[seat];
+        p.contributed -= amount;
+        p.bet = p.bet.saturating_sub(amount);
+        p.stack += amount;
+        self.pot = self.pot.saturating_sub(amount);
+        self.record_history(seat, HandHistoryVerb::UncalledBet, Some(amount));
     }
 
     fn deal_next_street(&mut self) {
         match self.street {
             Street::Preflop => {
-                let drawn = self.deck.draw_n(3);
-                self.board.extend(drawn);
+                self.deal_board_cards(3);
                 self.street = Street::Flop;
                 self.reset_bets_set_current_postflop();
             }
             Street::Flop => {
-                if let Some(c) = self.deck.draw() {
-                    self.board.push(c);
+                if self.deal_board_cards(1) {
                     self.street = Street::Turn;
                     self.reset_bets_set_current_postflop();
                 }
             }
             Street::Turn => {
-                if let Some(c) = self.deck.draw() {
-                    self.board.push(c);
+                if self.deal_board_cards(1) {
                     self.street = Street::River;
                     self.reset_bets_set_current_postflop();
                 }
             }
             Street::River => {
                 self.street = Street::Showdown;
-                let _ = self.finish_showdown();
+                self.run_showdown();
             }
             Street::Showdown => {}
         }
@@ -527,6 +1825,7 @@ impl Game {
         for p in &mut self.players {
             p.bet = 0;
             p.last_action = None;
+            p.acted_at = None;
         }
         if !self.players.is_empty() {
             let n = self.players.len();
@@ -542,7 +1841,6 @@ impl Game {
             self.current_bet = 0;
             self.min_raise = self.big_blind;
             self.last_raiser = None;
-            self.round_starter = self.current;
         }
     }
 
@@ -575,6 +1873,79 @@ impl Game {
         self.current_bet.saturating_sub(self.players[idx].bet)
     }
 
+    /// Legal options for the current actor, or `None` when nobody can act.
+    ///
+    /// ```
+    /// use poker_rs::game::Game;
+    ///
+    /// let mut game = Game::new(3, 1000, 5, 10);
+    /// game.new_hand().unwrap();
+    /// let legal = game.legal_actions().unwrap();
+    /// assert!(legal.can_call && !legal.can_check);
+    /// assert_eq!(legal.call_amount, 10);
+    /// assert_eq!(legal.raise.unwrap().min, 20);
+    /// assert_eq!(legal.raise.unwrap().max, 1000);
+    /// ```
+    pub fn legal_actions(&self) -> Option<LegalActions> {
+        self.ensure_can_act().ok()?;
+        let seat = self.current;
+        let p = &self.players[seat];
+        let to_call = self.to_call(seat);
+        let reopened = self.can_raise(seat);
+        let all_in =
+            if reopened { p.bet + p.stack } else { (p.bet + p.stack).min(self.current_bet) };
+        let max = self.max_bet_total(seat).min(all_in);
+        let (bet, raise) = if self.current_bet == 0 {
+            let bet = (p.stack > 0).then(|| BetRange { min: self.big_blind.max(1).min(max), max });
+            (bet, None)
+        } else if all_in > self.current_bet {
+            let min = (self.current_bet + self.min_raise).min(max);
+            (None, Some(BetRange { min, max }))
+        } else {
+            (None, None)
+        };
+        Some(LegalActions {
+            seat,
+            can_fold: true,
+            can_check: to_call == 0,
+            can_call: to_call > 0,
+            call_amount: to_call.min(p.stack),
+            bet,
+            raise,
+            all_in,
+        })
+    }
+
+    /// Largest street total `idx` may bet or raise to: their whole stack, or
+    /// under pot limit the current bet plus the pot after calling (never less
+    /// than a minimum bet or raise).
+    fn max_bet_total(&self, idx: usize) -> u64 {
+        let Some(p) = self.players.get(idx) else {
+            return 0;
+        };
+        let all_in = p.bet + p.stack;
+        match self.bet_limit {
+            BetLimit::NoLimit => all_in,
+            BetLimit::PotLimit => {
+                let call = self.current_bet.saturating_sub(p.bet);
+                let min = if self.current_bet == 0 {
+                    self.big_blind.max(1)
+                } else {
+                    self.current_bet + self.min_raise
+                };
+                (self.current_bet + self.pot + call).max(min).min(all_in)
+            }
+        }
+    }
+
+    /// Whether `idx` may raise: true unless they already acted this round and
+    /// the bet has not grown by at least a full raise since.
+    fn can_raise(&self, idx: usize) -> bool {
+        self.players[idx]
+            .acted_at
+            .map_or(true, |level| self.current_bet.saturating_sub(level) >= self.min_raise)
+    }
+
     fn ensure_can_act(&self) -> Result<(), ActionError> {
         if matches!(self.street, Street::Showdown) {
             return Err(ActionError::Showdown);
@@ -585,21 +1956,170 @@ impl Game {
         Ok(())
     }
 
+    /// Perform `action` on behalf of `seat`, rejecting it if `seat` is not the current actor.
+    ///
+    /// ```
+    /// use poker_rs::agents::Action;
+    /// use poker_rs::game::{ActionError, Game};
+    ///
+    /// let mut game = Game::new(3, 1000, 5, 10);
+    /// game.new_hand().unwrap();
+    /// let seat = game.current();
+    /// let other = (seat + 1) % 3;
+    /// assert_eq!(
+    ///     game.act(other, Action::Fold),
+    ///     Err(ActionError::NotYourTurn { seat: other, current: seat })
+    /// );
+    /// game.act(seat, Action::CheckCall).unwrap();
+    /// ```
+    pub fn act(&mut self, seat: usize, action: Action) -> Result<(), ActionError> {
+        self.ensure_can_act()?;
+        if seat != self.current {
+            return Err(ActionError::NotYourTurn { seat, current: self.current });
+        }
+        match action {
+            Action::Fold => self.action_fold(),
+            Action::CheckCall => self.action_check_call(),
+            Action::BetMin => self.action_bet_min(),
+            Action::RaiseMin => self.action_raise_min(),
+            Action::Bet(amount) => self.action_bet(amount),
+            Action::RaiseTo(amount) => self.action_raise_to(amount),
+        }
+    }
+
     pub fn action_fold(&mut self) -> Result<(), ActionError> {
+        self.undoable(|g| g.apply_fold())
+    }
+
+    pub fn action_check_call(&mut self) -> Result<(), ActionError> {
+        self.undoable(|g| g.apply_check_call())
+    }
+
+    pub fn action_bet_min(&mut self) -> Result<(), ActionError> {
+        self.undoable(|g| g.apply_bet_min())
+    }
+
+    pub fn action_bet(&mut self, amount: u64) -> Result<(), ActionError> {
+        self.undoable(|g| g.apply_bet(amount))
+    }
+
+    pub fn action_raise_min(&mut self) -> Result<(), ActionError> {
+        self.undoable(|g| g.apply_raise_min())
+    }
+
+    pub fn action_raise_to(&mut self, amount: u64) -> Result<(), ActionError> {
+        self.undoable(|g| g.apply_raise_to(amount))
+    }
+
+    /// Run an action, recording a checkpoint so it can be undone if it succeeds.
+    fn undoable<F>(&mut self, f: F) -> Result<(), ActionError>
+    where
+        F: FnOnce(&mut Self) -> Result<(), ActionError>,
+    {
+        let checkpoint = self.checkpoint();
+        f(self)?;
+        self.undo_stack.push(checkpoint);
+        if self.invariant_checks {
+            self.check_invariants().map_err(ActionError::Invariant)?;
+        }
+        Ok(())
+    }
+
+    fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            deck: self.deck.clone(),
+            board: self.board.clone(),
+            second_board: self.second_board.clone(),
+            players: self.players.clone(),
+            pot: self.pot,
+            current: self.current,
+            street: self.street,
+            current_bet: self.current_bet,
+            min_raise: self.min_raise,
+            last_raiser: self.last_raiser,
+            winners: self.winners.clone(),
+            showdown_categories: self.showdown_categories.clone(),
+            showdown: self.showdown.clone(),
+            history_len: self.hand_history.len(),
+            violations: self.violations.clone(),
+        }
+    }
+
+    /// Number of actions in the current hand that can be undone.
+    pub fn undo_depth(&self) -> usize {
+        self.undo_stack.len()
+    }
+
+    /// Take back the most recent action of the current hand.
+    ///
+    /// Restores stacks, bets, pot, betting state and history exactly as they
+    /// were, returning any board cards dealt by the action to the deck.
+    /// Returns `false` when there is nothing to undo; blinds cannot be undone,
+    /// and a seat change after the hand ends it for that hand.
+    ///
+    /// ```
+    /// use poker_rs::game::{Game, Street};
+    ///
+    /// let mut game = Game::new(2, 1000, 5, 10);
+    /// game.new_hand().unwrap();
+    /// game.action_check_call().unwrap();
+    /// game.action_check_call().unwrap();
+    /// assert_eq!(game.street(), Street::Flop);
+    /// assert!(game.undo());
+    /// assert_eq!(game.street(), Street::Preflop);
+    /// assert!(game.board().is_empty());
+    /// ```
+    pub fn undo(&mut self) -> bool {
+        let Some(cp) = self.undo_stack.pop() else {
+            return false;
+        };
+        self.deck = cp.deck;
+        self.board = cp.board;
+        self.second_board = cp.second_board;
+        self.players = cp.players;
+        self.pot = cp.pot;
+        self.current = cp.current;
+        self.street = cp.street;
+        self.current_bet = cp.current_bet;
+        self.min_raise = cp.min_raise;
+        self.last_raiser = cp.last_raiser;
+        self.winners = cp.winners;
+        self.showdown_categories = cp.showdown_categories;
+        self.showdown = cp.showdown;
+        self.hand_history.truncate(cp.history_len);
+        self.violations = cp.violations;
+        if self.street != Street::Showdown {
+            self.ledger.retract_hand(self.hand_number);
+        }
+        true
+    }
+
+    /// Undo up to `n` actions; returns how many were undone.
+    pub fn undo_n(&mut self, n: usize) -> usize {
+        (0..n).take_while(|_| self.undo()).count()
+    }
+
+    fn apply_fold(&mut self) -> Result<(), ActionError> {
         self.ensure_can_act()?;
         self.players[self.current].status = PlayerStatus::Folded;
         self.players[self.current].last_action = Some("Fold".into());
         self.record_history(self.current, HandHistoryVerb::Fold, None);
-        if self.count_eligible() <= 1 {
+        // The last active player may still owe a call against an all-in
+        let live = self
+            .players
+            .iter()
+            .filter(|p| matches!(p.status, PlayerStatus::Active | PlayerStatus::AllIn))
+            .count();
+        if live <= 1 {
             self.street = Street::Showdown;
-            let _ = self.finish_showdown();
+            self.run_showdown();
             return Ok(());
         }
         self.advance_or_move();
         Ok(())
     }
 
-    pub fn action_check_call(&mut self) -> Result<(), ActionError> {
+    fn apply_check_call(&mut self) -> Result<(), ActionError> {
         self.ensure_can_act()?;
         let to_call = self.to_call(self.current);
         if to_call == 0 {
@@ -611,11 +2131,12 @@ impl Game {
             self.players[idx].last_action = Some(format!("Call {paid}"));
             self.record_history(idx, HandHistoryVerb::Call, Some(paid));
         }
+        self.players[self.current].acted_at = Some(self.current_bet);
         self.advance_or_move();
         Ok(())
     }
 
-    pub fn action_bet_min(&mut self) -> Result<(), ActionError> {
+    fn apply_bet_min(&mut self) -> Result<(), ActionError> {
         self.ensure_can_act()?;
         if self.current_bet > 0 {
             return Err(ActionError::BetNotAllowed);
@@ -624,40 +2145,46 @@ impl Game {
         self.place_to_amount(target, HandHistoryVerb::Bet, "Bet")
     }
 
-    pub fn action_bet(&mut self, amount: u64) -> Result<(), ActionError> {
+    fn apply_bet(&mut self, amount: u64) -> Result<(), ActionError> {
         self.ensure_can_act()?;
         if self.current_bet > 0 {
             return Err(ActionError::BetNotAllowed);
         }
         let min_bet = self.big_blind.max(1);
-        if amount < min_bet {
-            return Err(ActionError::AmountTooSmall { min: min_bet, got: amount });
-        }
-        let max_total = self.players.get(self.current).map(|p| p.bet + p.stack).unwrap_or(0);
+        let max_total = self.max_bet_total(self.current);
         if amount > max_total {
             return Err(ActionError::AmountTooLarge { max: max_total, got: amount });
         }
+        if amount < min_bet && amount < max_total {
+            return Err(ActionError::AmountTooSmall { min: min_bet, got: amount });
+        }
         self.place_to_amount(amount, HandHistoryVerb::Bet, "Bet")
     }
 
-    pub fn action_raise_min(&mut self) -> Result<(), ActionError> {
+    fn apply_raise_min(&mut self) -> Result<(), ActionError> {
         self.ensure_can_act()?;
         if self.current_bet == 0 {
             return Err(ActionError::RaiseNotAllowed);
         }
+        if !self.can_raise(self.current) {
+            return Err(ActionError::RaiseNotReopened);
+        }
         let target = self.current_bet + self.min_raise;
         self.place_to_amount(target, HandHistoryVerb::RaiseTo, "Raise to")
     }
 
-    pub fn action_raise_to(&mut self, amount: u64) -> Result<(), ActionError> {
+    fn apply_raise_to(&mut self, amount: u64) -> Result<(), ActionError> {
         self.ensure_can_act()?;
         if self.current_bet == 0 {
             return Err(ActionError::RaiseNotAllowed);
         }
-        let max_total = self.players.get(self.current).map(|p| p.bet + p.stack).unwrap_or(0);
+        let max_total = self.max_bet_total(self.current);
         if amount > max_total {
             return Err(ActionError::AmountTooLarge { max: max_total, got: amount });
         }
+        if amount > self.current_bet && !self.can_raise(self.current) {
+            return Err(ActionError::RaiseNotReopened);
+        }
         let min_target = self.current_bet + self.min_raise;
         if amount < min_target && amount < max_total {
             return Err(ActionError::AmountTooSmall { min: min_target, got: amount });
@@ -683,23 +2210,23 @@ impl Game {
         self.record_history(idx, verb, Some(new_bet));
 
         self.update_raise_state(idx, new_bet);
+        self.players[idx].acted_at = Some(self.current_bet);
         self.progress_round(idx, true);
         Ok(())
     }
 
     /// Update betting state after a bet or raise.
     /// Handles the "full raise vs short all-in" rule:
-    /// - Full raises (>= min_raise) reopen betting for all players
-    /// - Short all-in raises don't reopen action for players who already acted
+    /// - Full raises (>= min_raise) set the new minimum raise and reopen betting
+    /// - Short all-in raises still raise the amount to call, but players who
+    ///   already acted may only call or fold unless the total increase since
+    ///   they acted adds up to a full raise (see `can_raise`)
     fn update_raise_state(&mut self, raiser_idx: usize, new_bet: u64) {
         if new_bet > self.current_bet {
             let raise_amt = new_bet - self.current_bet;
-            // Only reopen betting if this is a full raise (>= min_raise)
-            // Short all-in raises don't reopen action for players who already acted
             if raise_amt >= self.min_raise {
-                self.min_raise = self.min_raise.max(raise_amt);
+                self.min_raise = raise_amt;
                 self.last_raiser = Some(raiser_idx);
-                self.round_starter = raiser_idx;
             }
             self.current_bet = new_bet;
         }
@@ -718,7 +2245,7 @@ impl Game {
                     Street::Preflop | Street::Flop | Street::Turn => self.deal_next_street(),
                     Street::River => {
                         self.street = Street::Showdown;
-                        let _ = self.finish_showdown();
+                        self.run_showdown();
                     }
                     Street::Showdown => {}
                 }
@@ -758,22 +2285,38 @@ impl Game {
             return false; // Can't end if someone hasn't matched
         }
 
-        // Case 2: All bets matched and action has returned to the round starter
-        // The round starter is the first player to act in this betting round
-        self.current == self.round_starter
+        // Case 2: All bets matched and every active player has acted this round
+        self.players
+            .iter()
+            .filter(|p| matches!(p.status, PlayerStatus::Active))
+            .all(|p| p.acted_at.is_some())
     }
 
-    /// Draw cards from the deck until the board has 5 cards.
-    /// Returns true if board reached 5 cards, false if deck was exhausted.
+    /// Deal `n` cards to the board, then `n` to the second board if there is
+    /// one. Returns false if the deck ran out.
+    fn deal_board_cards(&mut self, n: usize) -> bool {
+        let drawn = self.deck.draw_n(n);
+        let complete = drawn.len() == n;
+        self.board.extend(drawn);
+        if let Some(second) = self.second_board.as_mut() {
+            let drawn = self.deck.draw_n(n);
+            let complete = complete && drawn.len() == n;
+            second.extend(drawn);
+            return complete;
+        }
+        complete
+    }
+
+    /// Deal the remaining streets until every board has 5 cards.
+    /// Returns true if the boards reached 5 cards, false if deck was exhausted.
     fn complete_board(&mut self) -> bool {
         while self.board.len() < 5 {
-            if let Some(c) = self.deck.draw() {
-                self.board.push(c);
-            } else {
+            let n = if self.board.is_empty() { 3 } else { 1 };
+            if !self.deal_board_cards(n) {
                 break;
             }
         }
-        self.board.len() == 5
+        self.board.len() == 5 && self.second_board.as_ref().map_or(true, |b| b.len() == 5)
     }
 
     /// Calculate side pots based on player contributions.
@@ -784,7 +2327,7 @@ impl Game {
         levels.sort_unstable();
         levels.dedup();
 
-        let mut pots = Vec::new();
+        let mut pots: Vec<(u64, Vec<usize>)> = Vec::new();
         let mut prev = 0u64;
 
         for lvl in levels {
@@ -799,6 +2342,16 @@ impl Game {
             let amount = (lvl - prev) * contributors.len() as u64;
             prev = lvl;
 
+            // Dead money above every live contribution, such as the dead blind
+            // of a player who then folded, goes to the pot below it.
+            let live = contributors.iter().any(|&i| {
+                !matches!(self.players[i].status, PlayerStatus::Folded)
+                    && self.players[i].hole.is_some()
+            });
+            if let (false, Some(last)) = (live, pots.last_mut()) {
+                last.0 += amount;
+                continue;
+            }
             if amount > 0 {
                 pots.push((amount, contributors));
             }
@@ -810,10 +2363,11 @@ impl Game {
     /// Find the winners of a pot given eligible players and their hand evaluations.
     /// Returns indices of winning players.
     fn find_pot_winners(
+        variant: &dyn Variant,
         eligible: &[usize],
-        evals: &[Option<crate::evaluator::Evaluation>],
+        evals: &[Option<Evaluation>],
     ) -> Result<Vec<usize>, ShowdownError> {
-        let mut best: Option<crate::evaluator::Evaluation> = None;
+        let mut best: Option<Evaluation> = None;
         let mut pot_winners: Vec<usize> = Vec::new();
 
         for &i in eligible {
@@ -822,12 +2376,14 @@ impl Game {
             })?;
 
             if let Some(b) = best {
-                if ev > b {
-                    best = Some(ev);
-                    pot_winners.clear();
-                    pot_winners.push(i);
-                } else if ev == b {
-                    pot_winners.push(i);
+                match variant.compare(&ev, &b) {
+                    Ordering::Greater => {
+                        best = Some(ev);
+                        pot_winners.clear();
+                        pot_winners.push(i);
+                    }
+                    Ordering::Equal => pot_winners.push(i),
+                    Ordering::Less => {}
                 }
             } else {
                 best = Some(ev);
@@ -838,31 +2394,44 @@ impl Game {
         Ok(pot_winners)
     }
 
-    /// Distribute a pot amount among winners, handling odd chip distribution by seat order.
-    /// Returns (player_idx, amount, is_split) tuples for each winner.
-    fn distribute_pot(
-        pot_amount: u64,
-        winners: &[usize],
-        _start_seat: usize,
-        _num_players: usize,
-    ) -> Vec<(usize, u64, bool)> {
+    /// Order pot winners by who receives odd chips first under the odd-chip rule.
+    fn odd_chip_order(&self, winners: &mut [usize]) {
+        let n = self.players.len();
+        let start = if n == 0 { 0 } else { (self.dealer + 1) % n };
+        match self.odd_chip_rule {
+            OddChipRule::LeftOfButton => winners.sort_by_key(|&i| (i + n - start) % n),
+            OddChipRule::HighCardBySuit => winners.sort_by_key(|&i| {
+                let high = self.players[i].hole.and_then(|h| h.as_slice().iter().max().copied());
+                (std::cmp::Reverse(high), (i + n - start) % n)
+            }),
+        }
+    }
+
+    /// Distribute a pot amount among winners in whole chips of `unit`.
+    ///
+    /// `winners` must already be in odd-chip order: each leftover chip goes to
+    /// the next winner in turn, and any amount smaller than one chip goes to
+    /// the first. Returns (player_idx, amount) pairs for each winner.
+    fn distribute_pot(pot_amount: u64, winners: &[usize], unit: u64) -> Vec<(usize, u64)> {
         if winners.is_empty() {
             return Vec::new();
         }
 
-        let per = pot_amount / winners.len() as u64;
-        let mut rem = (pot_amount % winners.len() as u64) as usize;
-        let is_split = winners.len() > 1;
+        let unit = unit.max(1);
+        let chips = pot_amount / unit;
+        let per = chips / winners.len() as u64 * unit;
+        let mut rem = (chips % winners.len() as u64) as usize;
+        let mut small = pot_amount % unit;
 
         winners
             .iter()
             .map(|&i| {
-                let mut amt = per;
+                let mut amt = per + std::mem::take(&mut small);
                 if rem > 0 {
-                    amt += 1;
+                    amt += unit;
                     rem -= 1;
                 }
-                (i, amt, is_split)
+                (i, amt)
             })
             .collect()
     }
@@ -874,17 +2443,19 @@ impl Game {
             return Err(ShowdownError::InvalidState("empty pot at showdown".to_string()));
         }
         if self.pot != total_pot {
+            self.violations
+                .push(InvariantViolation::PotRepaired { pot: self.pot, contributed: total_pot });
             self.pot = total_pot;
         }
         Ok(())
     }
 
     /// Handle simple showdown cases (0-1 contenders, incomplete board).
-    /// Returns Some(()) if showdown was handled, None to continue with full showdown.
+    /// Returns the seat awarded the whole pot, or None to continue with full showdown.
     fn handle_simple_showdown(
         &mut self,
         contenders: &[usize],
-    ) -> Result<Option<()>, ShowdownError> {
+    ) -> Result<Option<usize>, ShowdownError> {
         // Clear per-street bets; pot already has all chips.
         for p in &mut self.players {
             p.bet = 0;
@@ -895,7 +2466,7 @@ impl Game {
             let i =
                 if self.players.is_empty() { 0 } else { (self.dealer + 1) % self.players.len() };
             self.award_pot_to_single_winner(i, None);
-            return Ok(Some(()));
+            return Ok(Some(i));
         }
 
         if contenders.len() == 1 {
@@ -904,20 +2475,20 @@ impl Game {
                 self.players[i]
                     .hole
                     .as_ref()
-                    .and_then(|h| evaluate_holdem(h, &self.board).ok())
+                    .and_then(|h| self.variant.evaluate(h, &self.board).ok())
                     .map(|ev| ev.category)
             } else {
                 None
             };
             self.award_pot_to_single_winner(i, category);
-            return Ok(Some(()));
+            return Ok(Some(i));
         }
 
         // Multiple contenders but incomplete board
         if self.board.len() < 5 && !self.complete_board() {
             let i = contenders[0];
             self.award_pot_to_single_winner(i, None);
-            return Ok(Some(()));
+            return Ok(Some(i));
         }
 
         Ok(None) // Continue with full showdown
@@ -938,30 +2509,49 @@ impl Game {
         }
     }
 
-    /// Evaluate hands for all contenders.
-    /// Returns (contender_indices, evaluations).
+    /// Evaluate hands for all contenders on every board.
+    /// Returns one list of evaluations per board, indexed by seat.
     fn evaluate_all_hands(
         &mut self,
         contenders: &[usize],
-    ) -> Result<Vec<Option<crate::evaluator::Evaluation>>, ShowdownError> {
+    ) -> Result<Vec<Vec<Option<Evaluation>>>, ShowdownError> {
         let n = self.players.len();
-        let mut evals: Vec<Option<crate::evaluator::Evaluation>> = vec![None; n];
+        let boards: Vec<&Board> = std::iter::once(&self.board).chain(&self.second_board).collect();
+        let mut evals: Vec<Vec<Option<Evaluation>>> = vec![vec![None; n]; boards.len()];
 
         for &i in contenders {
             let hole = self.players[i].hole.as_ref().ok_or_else(|| {
                 ShowdownError::InvalidState(format!("contender {i} missing hole cards"))
             })?;
-            let ev = evaluate_holdem(hole, &self.board)
-                .map_err(|e| ShowdownError::EvaluationFailed(format!("player {i}: {e}")))?;
+            for (board, evals) in boards.iter().zip(&mut evals) {
+                let ev = self
+                    .variant
+                    .evaluate(hole, board)
+                    .map_err(|e| ShowdownError::EvaluationFailed(format!("player {i}: {e}")))?;
+                evals[i] = Some(ev);
+            }
+        }
+        for &i in contenders {
             if i < self.showdown_categories.len() {
-                self.showdown_categories[i] = Some(ev.category);
+                self.showdown_categories[i] = evals[0][i].map(|ev| ev.category);
             }
-            evals[i] = Some(ev);
         }
 
         Ok(evals)
     }
 
+    /// Split a pot into one share per board in whole chips; any odd chip goes
+    /// to board one.
+    fn board_shares(&self, amount: u64, boards: usize) -> Vec<u64> {
+        if boards < 2 {
+            return vec![amount];
+        }
+        let unit = self.chip_unit.max(1);
+        let chips = amount / unit;
+        let first = (chips - chips / 2) * unit + amount % unit;
+        vec![first, amount - first]
+    }
+
     /// Finalize showdown by updating player stacks and resetting state.
     fn finalize_showdown(&mut self, winnings: &[u64], split: &[bool]) {
         let n = self.players.len();
@@ -987,20 +2577,44 @@ impl Game {
         self.current_bet = 0;
         self.min_raise = self.big_blind;
         self.last_raiser = None;
-        self.round_starter = self.current;
         self.winners = winners;
     }
 
     /// Showdown: determine winners and distribute the pot.
     ///
     /// Implements full side-pot logic to handle all-in situations correctly.
+    /// The returned result stays available from [`showdown_result`](Self::showdown_result)
+    /// until the next hand starts; a hand already settled is not paid again.
     /// Returns an error if hand evaluation fails or game state is inconsistent.
-    pub fn finish_showdown(&mut self) -> Result<(), ShowdownError> {
-        // 1. Validate and sync pot
+    pub(crate) fn finish_showdown(&mut self) -> Result<ShowdownResult, ShowdownError> {
+        if let Some(result) = &self.showdown {
+            return Ok(result.clone());
+        }
+        let start: Vec<u64> = self.players.iter().map(|p| p.stack + p.contributed).collect();
+        let result = self.resolve_showdown()?;
+        self.record_hand_result(&start, &result);
+        self.showdown = Some(result.clone());
+        Ok(result)
+    }
+
+    /// Settle the hand once betting is over, recording a failure instead of
+    /// aborting the action that ended the hand.
+    fn run_showdown(&mut self) {
+        if let Err(e) = self.finish_showdown() {
+            self.violations.push(InvariantViolation::Showdown(e));
+        }
+    }
+
+    fn resolve_showdown(&mut self) -> Result<ShowdownResult, ShowdownError> {
+        let mut result = ShowdownResult::default();
+
+        // 1. Validate and sync pot, then return any uncalled bet
         if self.sync_pot_with_contributions().is_err() {
             // Empty pot is OK, just skip showdown
-            return Ok(());
+            return Ok(result);
         }
+        result.uncalled = self.uncalled_bet();
+        self.return_uncalled_bet();
 
         // 2. Determine contenders (everyone not folded with hole cards)
         let contenders: Vec<usize> = self
@@ -1011,20 +2625,30 @@ impl Game {
             .map(|(i, _)| i)
             .collect();
 
-        // 3. Handle edge cases (0-1 contenders, incomplete board)
-        if let Some(()) = self.handle_simple_showdown(&contenders)? {
-            return Ok(());
+        // 3. Calculate side pots
+        let side_pots = self.calculate_side_pots();
+
+        // 4. Handle edge cases (0-1 contenders, incomplete board)
+        let contenders = self.showdown_order(&contenders);
+        if let Some(winner) = self.handle_simple_showdown(&contenders)? {
+            result.hands = self.showdown_hands(&contenders, false);
+            result.pots = side_pots
+                .into_iter()
+                .map(|(amount, contributors)| PotResult {
+                    amount,
+                    eligible: contributors.into_iter().filter(|i| contenders.contains(i)).collect(),
+                    winners: vec![PotShare { seat: winner, amount }],
+                })
+                .collect();
+            return Ok(result);
         }
 
-        // 4. Evaluate all hands
+        // 5. Evaluate all hands
         let evals = self.evaluate_all_hands(&contenders)?;
-
-        // 5. Calculate side pots
-        let side_pots = self.calculate_side_pots();
+        result.hands = self.showdown_hands(&contenders, true);
 
         // 6. Distribute each pot
         let n = self.players.len();
-        let start = if n == 0 { 0 } else { (self.dealer + 1) % n };
         let mut winnings = vec![0u64; n];
         let mut split = vec![false; n];
 
@@ -1035,31 +2659,175 @@ impl Game {
                 .filter(|&i| !matches!(self.players[i].status, PlayerStatus::Folded))
                 .filter(|&i| self.players[i].hole.is_some())
                 .collect();
+            let mut pot = PotResult { amount, eligible, winners: Vec::new() };
+
+            for (share, evals) in self.board_shares(amount, evals.len()).into_iter().zip(&evals) {
+                let mut pot_winners = if pot.eligible.is_empty() {
+                    Vec::new()
+                } else {
+                    Self::find_pot_winners(&*self.variant, &pot.eligible, evals)?
+                };
+                self.odd_chip_order(&mut pot_winners);
+
+                for (i, amt) in Self::distribute_pot(share, &pot_winners, self.chip_unit) {
+                    winnings[i] = winnings[i].saturating_add(amt);
+                    pot.winners.push(PotShare { seat: i, amount: amt });
+                }
+            }
+            for share in &pot.winners {
+                if pot.winners.iter().any(|other| other.seat != share.seat) {
+                    split[share.seat] = true;
+                }
+            }
+            result.pots.push(pot);
+        }
 
-            if eligible.is_empty() {
-                continue;
+        // 7. Table the hands that must be shown, then finalize. Once anyone
+        // is all-in every hand is tabled, so nobody may muck.
+        if !self.players.iter().any(|p| matches!(p.status, PlayerStatus::AllIn)) {
+            Self::mark_muckable(&*self.variant, &mut result);
+        }
+        for hand in result.hands.iter().filter(|h| !h.can_muck) {
+            if let Some(hole) = hand.hole {
+                self.record_show(hand.seat, hole);
             }
+        }
+        self.finalize_showdown(&winnings, &split);
+        Ok(result)
+    }
 
-            let mut pot_winners = Self::find_pot_winners(&eligible, &evals)?;
-            if pot_winners.is_empty() {
+    /// Contenders in the order they must show: the last aggressor on the river
+    /// first, or the first contender left of the button when nobody bet it.
+    fn showdown_order(&self, contenders: &[usize]) -> Vec<usize> {
+        let n = self.players.len();
+        if n == 0 {
+            return Vec::new();
+        }
+        let aggressor = self
+            .hand_history
+            .iter()
+            .rev()
+            .skip_while(|e| e.street == Street::Showdown)
+            .take_while(|e| e.street == Street::River)
+            .find(|e| matches!(e.verb, HandHistoryVerb::Bet | HandHistoryVerb::RaiseTo))
+            .map(|e| e.seat)
+            .filter(|s| contenders.contains(s));
+        let start = aggressor.unwrap_or((self.dealer + 1) % n);
+        let mut order = contenders.to_vec();
+        order.sort_by_key(|&i| (i + n - start) % n);
+        order
+    }
+
+    /// Let a hand muck when, in every pot it could win, a hand shown earlier
+    /// in showdown order already beats it. Those hands start out shown until
+    /// the player decides.
+    fn mark_muckable(variant: &dyn Variant, result: &mut ShowdownResult) {
+        for idx in 0..result.hands.len() {
+            let hand = result.hands[idx];
+            if hand.evaluation.is_none() {
                 continue;
             }
-            pot_winners.sort_by_key(|&i| (i + n - start) % n);
+            let boards = |h: &ShowdownHand| [h.evaluation, h.second_evaluation];
+            let earlier = &result.hands[..idx];
+            let mut pots =
+                result.pots.iter().filter(|p| p.eligible.contains(&hand.seat)).peekable();
+            let beaten_everywhere = pots.peek().is_some()
+                && pots.all(|pot| {
+                    // Beaten on every board this hand plays on.
+                    boards(&hand).iter().enumerate().all(|(b, mine)| {
+                        let Some(mine) = mine else {
+                            return true;
+                        };
+                        earlier.iter().any(|h| {
+                            pot.eligible.contains(&h.seat)
+                                && boards(h)[b].is_some_and(|e| variant.compare(&e, mine).is_gt())
+                        })
+                    })
+                });
+            result.hands[idx].can_muck = beaten_everywhere && result.won_by(hand.seat) == 0;
+        }
+    }
+
+    /// Muck `seat`'s losing hand so it is never revealed.
+    ///
+    /// Only hands flagged [`ShowdownHand::can_muck`] may be mucked.
+    pub fn muck(&mut self, seat: usize) -> Result<(), ShowdownError> {
+        let hand = self
+            .showdown
+            .as_mut()
+            .and_then(|r| r.hands.iter_mut().find(|h| h.seat == seat))
+            .filter(|h| h.can_muck)
+            .ok_or(ShowdownError::MuckNotAllowed(seat))?;
+        hand.shown = false;
+        hand.can_muck = false;
+        hand.hole = None;
+        hand.evaluation = None;
+        hand.second_evaluation = None;
+        if let Some(category) = self.showdown_categories.get_mut(seat) {
+            *category = None;
+        }
+        self.record_history(seat, HandHistoryVerb::Muck, None);
+        Ok(())
+    }
 
-            let distributions = Self::distribute_pot(amount, &pot_winners, start, n);
-            for (i, amt, is_split) in distributions {
-                winnings[i] = winnings[i].saturating_add(amt);
-                if is_split {
-                    split[i] = true;
-                }
-            }
+    /// Table `seat`'s losing hand, giving up the option to muck it.
+    pub fn show(&mut self, seat: usize) -> Result<(), ShowdownError> {
+        let hand = self
+            .showdown
+            .as_mut()
+            .and_then(|r| r.hands.iter_mut().find(|h| h.seat == seat))
+            .filter(|h| h.can_muck)
+            .ok_or(ShowdownError::MuckNotAllowed(seat))?;
+        hand.can_muck = false;
+        if let Some(hole) = hand.hole {
+            self.record_show(seat, hole);
         }
-
-        // 7. Finalize
-        self.finalize_showdown(&winnings, &split);
         Ok(())
     }
 
+    /// Seats still deciding whether to show or muck, in showdown order.
+    pub fn pending_mucks(&self) -> Vec<usize> {
+        self.showdown
+            .as_ref()
+            .map(|r| r.hands.iter().filter(|h| h.can_muck).map(|h| h.seat).collect())
+            .unwrap_or_default()
+    }
+
+    /// Hole cards of `seat` that everyone at the table has seen, if any.
+    ///
+    /// Hands awaiting a show/muck decision are not revealed yet.
+    pub fn revealed_hole(&self, seat: usize) -> Option<HoleCards> {
+        self.showdown.as_ref()?.hand(seat).filter(|h| h.shown && !h.can_muck).and_then(|h| h.hole)
+    }
+
+    fn record_show(&mut self, seat: usize, hole: HoleCards) {
+        self.record_history(seat, HandHistoryVerb::Show, None);
+        if let Some(entry) = self.hand_history.last_mut() {
+            entry.cards = Some(hole);
+        }
+    }
+
+    /// Cards and (when the board is complete) evaluations of every contender.
+    fn showdown_hands(&self, contenders: &[usize], shown: bool) -> Vec<ShowdownHand> {
+        contenders
+            .iter()
+            .filter_map(|&seat| {
+                let hole = self.players[seat].hole?;
+                let evaluate = |board: &Board| {
+                    (board.len() >= 5).then(|| self.variant.evaluate(&hole, board).ok()).flatten()
+                };
+                Some(ShowdownHand {
+                    seat,
+                    hole: Some(hole),
+                    evaluation: evaluate(&self.board),
+                    second_evaluation: self.second_board.as_ref().and_then(evaluate),
+                    shown,
+                    can_muck: false,
+                })
+            })
+            .collect()
+    }
+
     fn maybe_force_showdown(&mut self) {
         if matches!(self.street, Street::Showdown) {
             return;
@@ -1089,11 +2857,11 @@ impl Game {
             self.complete_board();
         }
         self.street = Street::Showdown;
-        let _ = self.finish_showdown();
+        self.run_showdown();
     }
 
     fn record_history(&mut self, seat: usize, verb: HandHistoryVerb, amount: Option<u64>) {
-        let entry = HandHistoryEntry { seat, verb, amount, street: self.street };
+        let entry = HandHistoryEntry { seat, verb, amount, street: self.street, cards: None };
         self.hand_history.push(entry);
     }
 }
@@ -1108,6 +2876,20 @@ mod tests {
         Game::new(n, 1000, 5, 10)
     }
 
+    #[test]
+    fn finishing_a_settled_showdown_pays_nothing_more() {
+        let mut g = mk_game(3);
+        g.new_hand().unwrap();
+        while g.street() != Street::Showdown {
+            g.action_check_call().unwrap();
+        }
+        let stacks: Vec<u64> = g.players.iter().map(|p| p.stack).collect();
+        let result = g.finish_showdown().unwrap();
+        assert_eq!(Some(&result), g.showdown_result());
+        assert_eq!(g.players.iter().map(|p| p.stack).collect::<Vec<_>>(), stacks);
+        assert_eq!(g.check_invariants(), Ok(()));
+    }
+
     fn hole(a: Card, b: Card) -> HoleCards {
         HoleCards::try_new(a, b).expect("valid hole cards")
     }
@@ -1187,6 +2969,19 @@ mod tests {
         assert_eq!(g.players[2].stack, 150, "side pot goes to lone contributor");
     }
 
+    #[test]
+    fn pot_breakdown_separates_uncalled_bet() {
+        let mut g = mk_game(3);
+        g.players[0].contributed = 50;
+        g.players[1].contributed = 100;
+        g.players[2].contributed = 300;
+        g.pot = 450;
+        let breakdown = g.pot_breakdown();
+        assert_eq!(breakdown.main, 150);
+        assert_eq!(breakdown.sides, vec![100]);
+        assert_eq!(breakdown.uncalled, 200);
+    }
+
     #[test]
     fn split_main_and_side_pots() {
         let mut g = mk_game(4);
@@ -1267,7 +3062,7 @@ mod tests {
     #[test]
     fn showdown_deals_remaining_board_cards() {
         let mut game = Game::new(3, 100, 5, 10);
-        game.new_hand();
+        game.new_hand().unwrap();
 
         for p in &mut game.players {
             p.status = PlayerStatus::Folded;
@@ -1292,7 +3087,7 @@ mod tests {
         let mut game = Game::new(3, 100, 5, 10);
         game.players[1].stack = 0;
 
-        game.new_hand();
+        game.new_hand().unwrap();
 
         let busted = &game.players[1];
         assert!(matches!(busted.status, PlayerStatus::Folded));
@@ -1305,7 +3100,7 @@ mod tests {
     #[test]
     fn postflop_bet_and_calls_advance() {
         let mut game = Game::new(3, 1000, 5, 10);
-        game.new_hand();
+        game.new_hand().unwrap();
         // Skip preflop
         for _ in 0..3 {
             game.action_check_call().unwrap();
@@ -1320,7 +3115,7 @@ mod tests {
     #[test]
     fn auto_showdown_when_all_players_all_in() {
         let mut game = Game::new(3, 100, 5, 10);
-        game.new_hand();
+        game.new_hand().unwrap();
         let utg = game.current;
         let sb = (game.dealer + 1) % game.players.len();
         let bb = (game.dealer + 2) % game.players.len();
@@ -1336,7 +3131,7 @@ mod tests {
     #[test]
     fn auto_showdown_when_one_active_and_others_all_in() {
         let mut game = Game::new(3, 200, 5, 10);
-        game.new_hand();
+        game.new_hand().unwrap();
 
         let sb = game.sb_pos.expect("sb set");
         let bb = game.bb_pos.expect("bb set");
@@ -1,4 +1,5 @@
 use crate::cards::{parse_cards, Card};
+use core::fmt;
 use std::collections::HashSet;
 use std::str::FromStr;
 
@@ -13,13 +14,16 @@ pub enum HandError {
     DuplicateBoardCards,
     #[error("hole cards overlap with board")]
     Overlap,
-    #[error("expected exactly two hole cards, got {0}")]
+    #[error("expected two to four hole cards, got {0}")]
     HoleCount(usize),
     #[error("card parse error: {0}")]
     CardParse(String),
 }
 
-/// A player's two private hole cards.
+/// Most hole cards any supported variant deals (four, in Omaha).
+pub const MAX_HOLE_CARDS: usize = 4;
+
+/// A player's private hole cards: two in Hold'em, four in Omaha.
 ///
 /// ```
 /// use poker_rs::cards::{Card, Rank, Suit};
@@ -29,39 +33,80 @@ pub enum HandError {
 ///     Card::new(Rank::Ace, Suit::Spades),
 ///     Card::new(Rank::King, Suit::Spades),
 /// ).unwrap();
-/// assert_eq!(hole.as_array().len(), 2);
+/// assert!(hole.as_array().is_some());
+///
+/// let omaha: HoleCards = "As Ks Qh Jh".parse().unwrap();
+/// assert_eq!(omaha.as_slice().len(), 4);
+/// assert_eq!(omaha.as_array(), None);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct HoleCards(Card, Card);
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HoleCards {
+    /// Unused slots repeat the first card so derived equality stays exact.
+    cards: [Card; MAX_HOLE_CARDS],
+    len: u8,
+}
 
 impl HoleCards {
     /// Return the first (left) hole card.
     pub fn first(&self) -> Card {
-        self.0
+        self.cards[0]
     }
 
-    /// Return the second (right) hole card.
+    /// Return the second hole card.
     pub fn second(&self) -> Card {
-        self.1
+        self.cards[1]
     }
 
-    /// Return both hole cards as a fixed array.
-    pub fn as_array(&self) -> [Card; 2] {
-        [self.0, self.1]
+    /// Return both cards of a two-card hand; `None` for larger hands.
+    pub fn as_array(&self) -> Option<[Card; 2]> {
+        (self.len == 2).then(|| [self.cards[0], self.cards[1]])
+    }
+
+    /// Return every hole card in dealing order.
+    pub fn as_slice(&self) -> &[Card] {
+        &self.cards[..self.len as usize]
     }
 
     pub fn try_new(a: Card, b: Card) -> Result<Self, HandError> {
-        if a == b {
-            return Err(HandError::DuplicateHoleCards);
-        }
-        Ok(Self(a, b))
+        Self::from_slice(&[a, b])
     }
 
+    /// Build hole cards from two to [`MAX_HOLE_CARDS`] distinct cards.
     pub fn from_slice(slice: &[Card]) -> Result<Self, HandError> {
-        if slice.len() != 2 {
+        if !(2..=MAX_HOLE_CARDS).contains(&slice.len()) {
             return Err(HandError::HoleCount(slice.len()));
         }
-        Self::try_new(slice[0], slice[1])
+        let mut cards = [slice[0]; MAX_HOLE_CARDS];
+        for (i, &card) in slice.iter().enumerate() {
+            if slice[..i].contains(&card) {
+                return Err(HandError::DuplicateHoleCards);
+            }
+            cards[i] = card;
+        }
+        Ok(Self { cards, len: slice.len() as u8 })
+    }
+}
+
+impl fmt::Debug for HoleCards {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut t = f.debug_tuple("HoleCards");
+        for card in self.as_slice() {
+            t.field(card);
+        }
+        t.finish()
+    }
+}
+
+impl serde::Serialize for HoleCards {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_slice().serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for HoleCards {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let cards = Vec::<Card>::deserialize(deserializer)?;
+        Self::from_slice(&cards).map_err(serde::de::Error::custom)
     }
 }
 
@@ -86,7 +131,7 @@ impl FromStr for HoleCards {
 /// ]).unwrap();
 /// assert_eq!(board.len(), 3);
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Board {
     cards: Vec<Card>,
 }
@@ -119,10 +164,6 @@ impl Board {
         &self.cards
     }
 
-    pub(crate) fn push(&mut self, card: Card) {
-        self.cards.push(card);
-    }
-
     pub(crate) fn extend<I>(&mut self, cards: I)
     where
         I: IntoIterator<Item = Card>,
@@ -158,6 +199,9 @@ impl FromStr for Board {
 /// validate_holdem(&hole, &board).unwrap();
 /// ```
 pub fn validate_holdem(hole: &HoleCards, board: &Board) -> Result<(), HandError> {
+    if hole.as_slice().len() != 2 {
+        return Err(HandError::HoleCount(hole.as_slice().len()));
+    }
     if board.len() > 5 {
         return Err(HandError::TooManyBoardCards(board.len()));
     }
@@ -0,0 +1,229 @@
+//! The 169 preflop hand classes of Hold'em.
+//!
+//! Before the flop only the ranks of two hole cards and whether they share a
+//! suit matter, so the 1326 starting hands fall into 13 pairs, 78 suited and
+//! 78 offsuit classes. Classes are numbered in the usual 13x13 chart grid:
+//! aces head the first row and column, pairs lie on the diagonal, suited
+//! hands above it and offsuit hands below.
+
+mod equity;
+
+use crate::cards::{Card, Rank, Suit};
+use crate::hand::HoleCards;
+use core::fmt;
+use std::str::FromStr;
+
+/// A starting hand up to suits, such as `AKs`, `T9o` or `77`.
+///
+/// ```
+/// use poker_rs::cards::Rank;
+/// use poker_rs::hand_class::HandClass;
+///
+/// let class: HandClass = "AKs".parse().unwrap();
+/// assert_eq!(class, HandClass::of(&"Kd Ad".parse().unwrap()));
+/// assert_eq!(class.combo_count(), 4);
+/// assert_eq!(class.combos().len(), 4);
+/// assert!(class.equity_vs_random() > 0.6);
+/// assert_eq!("t9O".parse::<HandClass>().unwrap().to_string(), "T9o");
+/// assert_eq!(HandClass::suited(Rank::King, Rank::Ace), Some(class));
+/// assert_eq!(HandClass::suited(Rank::Ace, Rank::Ace), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HandClass(Kind);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Kind {
+    Pair(Rank),
+    /// Higher rank first
+    Suited(Rank, Rank),
+    /// Higher rank first
+    Offsuit(Rank, Rank),
+}
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum HandClassParseError {
+    #[error("invalid hand class: '{0}'")]
+    Invalid(String),
+}
+
+impl HandClass {
+    /// Number of classes.
+    pub const COUNT: usize = 169;
+
+    /// The pair of `rank`.
+    pub fn pair(rank: Rank) -> Self {
+        Self(Kind::Pair(rank))
+    }
+
+    /// Two different ranks of one suit, given in either order; `None` for
+    /// matching ranks.
+    pub fn suited(high: Rank, low: Rank) -> Option<Self> {
+        Self::unpaired(high, low).map(|(high, low)| Self(Kind::Suited(high, low)))
+    }
+
+    /// Two different ranks of different suits, given in either order; `None`
+    /// for matching ranks.
+    pub fn offsuit(high: Rank, low: Rank) -> Option<Self> {
+        Self::unpaired(high, low).map(|(high, low)| Self(Kind::Offsuit(high, low)))
+    }
+
+    /// `a` and `b` higher first, unless they match.
+    fn unpaired(a: Rank, b: Rank) -> Option<(Rank, Rank)> {
+        match a.cmp(&b) {
+            std::cmp::Ordering::Equal => None,
+            std::cmp::Ordering::Greater => Some((a, b)),
+            std::cmp::Ordering::Less => Some((b, a)),
+        }
+    }
+
+    /// Whether the class is a pair
+    pub fn is_pair(self) -> bool {
+        matches!(self.0, Kind::Pair(_))
+    }
+
+    /// Whether the class is two cards of one suit
+    pub fn is_suited(self) -> bool {
+        matches!(self.0, Kind::Suited(..))
+    }
+
+    /// Class of the first two of `hole`.
+    pub fn of(hole: &HoleCards) -> Self {
+        Self::from_cards(hole.first(), hole.second())
+    }
+
+    fn from_cards(a: Card, b: Card) -> Self {
+        let (high, low) = if a.rank() >= b.rank() { (a, b) } else { (b, a) };
+        Self(if high.rank() == low.rank() {
+            Kind::Pair(high.rank())
+        } else if high.suit() == low.suit() {
+            Kind::Suited(high.rank(), low.rank())
+        } else {
+            Kind::Offsuit(high.rank(), low.rank())
+        })
+    }
+
+    /// Every class in grid order.
+    pub fn all() -> impl Iterator<Item = HandClass> {
+        (0..Self::COUNT).filter_map(Self::from_index)
+    }
+
+    /// Position in the 13x13 grid, numbered row by row from `AA`.
+    pub fn index(self) -> usize {
+        let row = |rank: Rank| 14 - rank.value() as usize;
+        match self.0 {
+            Kind::Pair(r) => row(r) * 14,
+            Kind::Suited(high, low) => row(high) * 13 + row(low),
+            Kind::Offsuit(high, low) => row(low) * 13 + row(high),
+        }
+    }
+
+    /// Class at a grid position; `None` past the last cell.
+    pub fn from_index(index: usize) -> Option<Self> {
+        if index >= Self::COUNT {
+            return None;
+        }
+        let rank = |cell: usize| Rank::from_value(14 - cell as u8);
+        let (row, col) = (index / 13, index % 13);
+        Some(Self(match row.cmp(&col) {
+            std::cmp::Ordering::Equal => Kind::Pair(rank(row)),
+            std::cmp::Ordering::Less => Kind::Suited(rank(row), rank(col)),
+            std::cmp::Ordering::Greater => Kind::Offsuit(rank(col), rank(row)),
+        }))
+    }
+
+    /// Higher rank, or the rank of a pair
+    pub fn high(self) -> Rank {
+        match self.0 {
+            Kind::Pair(r) | Kind::Suited(r, _) | Kind::Offsuit(r, _) => r,
+        }
+    }
+
+    /// Lower rank, or the rank of a pair
+    pub fn low(self) -> Rank {
+        match self.0 {
+            Kind::Pair(r) | Kind::Suited(_, r) | Kind::Offsuit(_, r) => r,
+        }
+    }
+
+    /// Ways to deal the class from a full deck: 6 for a pair, 4 suited and
+    /// 12 offsuit.
+    pub fn combo_count(self) -> usize {
+        match self.0 {
+            Kind::Pair(_) => 6,
+            Kind::Suited(..) => 4,
+            Kind::Offsuit(..) => 12,
+        }
+    }
+
+    /// Every concrete hand in the class.
+    pub fn combos(self) -> Vec<HoleCards> {
+        let mut combos = Vec::with_capacity(self.combo_count());
+        for (i, &s1) in Suit::ALL.iter().enumerate() {
+            for &s2 in &Suit::ALL {
+                let keep = match self.0 {
+                    Kind::Pair(_) => Suit::ALL[i + 1..].contains(&s2),
+                    Kind::Suited(..) => s1 == s2,
+                    Kind::Offsuit(..) => s1 != s2,
+                };
+                if keep {
+                    let hole =
+                        HoleCards::try_new(Card::new(self.high(), s1), Card::new(self.low(), s2))
+                            .expect("distinct cards");
+                    combos.push(hole);
+                }
+            }
+        }
+        combos
+    }
+
+    /// Whether the first two of `hole` belong to the class.
+    pub fn contains(self, hole: &HoleCards) -> bool {
+        Self::of(hole) == self
+    }
+
+    /// All-in equity against one random hand with the board to come, from a
+    /// precomputed table.
+    pub fn equity_vs_random(self) -> f64 {
+        f64::from(equity::VS_RANDOM[self.index()])
+    }
+}
+
+impl From<&HoleCards> for HandClass {
+    fn from(hole: &HoleCards) -> Self {
+        Self::of(hole)
+    }
+}
+
+impl fmt::Display for HandClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Kind::Pair(r) => write!(f, "{r}{r}"),
+            Kind::Suited(high, low) => write!(f, "{high}{low}s"),
+            Kind::Offsuit(high, low) => write!(f, "{high}{low}o"),
+        }
+    }
+}
+
+impl FromStr for HandClass {
+    type Err = HandClassParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || HandClassParseError::Invalid(s.to_string());
+        let chars: Vec<char> = s.trim().chars().collect();
+        let (a, b, suffix) = match chars.as_slice() {
+            [a, b] => (*a, *b, None),
+            [a, b, c] => (*a, *b, Some(c.to_ascii_lowercase())),
+            _ => return Err(invalid()),
+        };
+        let a: Rank = a.to_string().parse().map_err(|_| invalid())?;
+        let b: Rank = b.to_string().parse().map_err(|_| invalid())?;
+        let (high, low) = if a >= b { (a, b) } else { (b, a) };
+        match (high == low, suffix) {
+            (true, None) => Ok(Self::pair(high)),
+            (false, Some('s')) => Self::suited(high, low).ok_or_else(invalid),
+            (false, Some('o')) => Self::offsuit(high, low).ok_or_else(invalid),
+            _ => Err(invalid()),
+        }
+    }
+}
@@ -0,0 +1,361 @@
+//! Independent Chip Model: prize equity from tournament chip stacks.
+//!
+//! Under the Malmuth-Harville model a player finishes first with probability
+//! proportional to their stack, and each later place is awarded the same way
+//! among the players not yet placed. [`icm_equity`] sums this over every
+//! finishing order with a dynamic program over the set of players already
+//! placed, which takes `O(2^n * n)` time instead of enumerating `n!` orders.
+//!
+//! [`push_fold_ev`] compares moving all-in with folding in prize equity rather
+//! than chips, which is what makes a short stack fold hands near the bubble
+//! that it would happily shove in a cash game. [`call_fold_ev`] does the same
+//! for calling an all-in.
+
+use crate::equity::{equity_vs_range, EquityError};
+use crate::hand::HoleCards;
+use rand::Rng;
+
+/// Most players [`icm_equity`] accepts.
+pub const MAX_ICM_PLAYERS: usize = 16;
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IcmError {
+    #[error("ICM supports at most {max} players, got {got}")]
+    TooManyPlayers { max: usize, got: usize },
+    #[error("no player has any chips")]
+    NoChips,
+    #[error("payout for place {0} is negative or not finite")]
+    InvalidPayout(usize),
+    #[error("seat {seat} is out of range for {num_seats} seats")]
+    SeatOutOfRange { seat: usize, num_seats: usize },
+    #[error("{stacks} stacks but {posted} posted amounts")]
+    LengthMismatch { stacks: usize, posted: usize },
+    #[error(transparent)]
+    Equity(#[from] EquityError),
+}
+
+/// Each player's prize equity under the Malmuth-Harville model.
+///
+/// `payouts[k]` is the prize for finishing in place `k + 1`. Players without
+/// chips are already out and receive nothing; places beyond the number of
+/// players with chips are not awarded.
+///
+/// ```
+/// use poker_rs::icm::icm_equity;
+///
+/// let equity = icm_equity(&[5000, 3000, 2000], &[50.0, 30.0, 20.0]).unwrap();
+/// assert!((equity.iter().sum::<f64>() - 100.0).abs() < 1e-9);
+/// // The chip leader's share of the prizes is smaller than their share of the chips.
+/// assert!(equity[0] < 50.0 && equity[2] > 20.0);
+/// ```
+pub fn icm_equity(stacks: &[u64], payouts: &[f64]) -> Result<Vec<f64>, IcmError> {
+    let n = stacks.len();
+    if n > MAX_ICM_PLAYERS {
+        return Err(IcmError::TooManyPlayers { max: MAX_ICM_PLAYERS, got: n });
+    }
+    if let Some(place) = payouts.iter().position(|p| !p.is_finite() || *p < 0.0) {
+        return Err(IcmError::InvalidPayout(place + 1));
+    }
+    let total: u64 = stacks.iter().sum();
+    if total == 0 {
+        return Err(IcmError::NoChips);
+    }
+    let places = payouts.len().min(n);
+    let mut equity = vec![0.0; n];
+    // reach[mask]: probability that exactly the players in `mask` took the
+    // top `mask.count_ones()` places; placed[mask]: their chips.
+    let mut reach = vec![0.0; 1 << n];
+    let mut placed = vec![0u64; 1 << n];
+    reach[0] = 1.0;
+    for mask in 0usize..1 << n {
+        if mask > 0 {
+            let low = mask.trailing_zeros() as usize;
+            placed[mask] = placed[mask & (mask - 1)] + stacks[low];
+        }
+        let place = mask.count_ones() as usize;
+        let left = total - placed[mask];
+        if reach[mask] == 0.0 || place >= places || left == 0 {
+            continue;
+        }
+        for (j, &stack) in stacks.iter().enumerate() {
+            if mask & (1 << j) != 0 || stack == 0 {
+                continue;
+            }
+            let p = reach[mask] * stack as f64 / left as f64;
+            equity[j] += p * payouts[place];
+            reach[mask | (1 << j)] += p;
+        }
+    }
+    Ok(equity)
+}
+
+/// An opponent who may call the hero's all-in with any hand in `range`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Caller {
+    pub seat: usize,
+    pub range: Vec<HoleCards>,
+}
+
+impl Caller {
+    pub fn new(seat: usize, range: Vec<HoleCards>) -> Self {
+        Self { seat, range }
+    }
+}
+
+/// A decision between moving all-in and folding.
+///
+/// Callers act in order and the first to call ends the action, so at most
+/// one opponent is in the all-in with the hero. If the hero folds, the pot is
+/// awarded to `fold_winner`, usually the big blind.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct PushFoldSpot {
+    /// Chips behind for every seat, after blinds and antes are posted
+    pub stacks: Vec<u64>,
+    /// Chips each seat has already put into the pot
+    pub posted: Vec<u64>,
+    pub hero: usize,
+    /// Opponents still to act, in order
+    pub callers: Vec<Caller>,
+    pub fold_winner: usize,
+}
+
+impl PushFoldSpot {
+    pub fn new(
+        stacks: Vec<u64>,
+        posted: Vec<u64>,
+        hero: usize,
+        callers: Vec<Caller>,
+        fold_winner: usize,
+    ) -> Self {
+        Self { stacks, posted, hero, callers, fold_winner }
+    }
+
+    fn validate(&self) -> Result<(), IcmError> {
+        let num_seats = self.stacks.len();
+        if self.posted.len() != num_seats {
+            return Err(IcmError::LengthMismatch { stacks: num_seats, posted: self.posted.len() });
+        }
+        let seats = self.callers.iter().map(|c| c.seat);
+        match std::iter::once(self.hero)
+            .chain([self.fold_winner])
+            .chain(seats)
+            .find(|&s| s >= num_seats)
+        {
+            Some(seat) => Err(IcmError::SeatOutOfRange { seat, num_seats }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Prize equity of the hero's two options.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct PushFoldEv {
+    pub push: f64,
+    pub fold: f64,
+}
+
+impl PushFoldEv {
+    /// Whether moving all-in is worth more than folding.
+    pub fn should_push(&self) -> bool {
+        self.push > self.fold
+    }
+}
+
+/// Compare moving all-in with `hand` against folding, in prize equity.
+///
+/// Each caller's chance of calling is the share of the hands they could hold
+/// that are in their range; the hero's equity when called is sampled with
+/// `trials` run-outs against that range.
+///
+/// ```
+/// use poker_rs::equity::all_combos;
+/// use poker_rs::icm::{push_fold_ev, Caller, PushFoldSpot};
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// // Small blind with 2.5 big blinds behind; the big blind calls with anything.
+/// let spot = PushFoldSpot::new(
+///     vec![20, 990, 1000],
+///     vec![5, 10, 0],
+///     0,
+///     vec![Caller::new(1, all_combos())],
+///     1,
+/// );
+/// let mut rng = StdRng::seed_from_u64(1);
+/// let ev = push_fold_ev(&spot, &[100.0], &"Ks 9d".parse().unwrap(), 500, &mut rng).unwrap();
+/// assert!(ev.should_push());
+/// ```
+pub fn push_fold_ev<R: Rng + ?Sized>(
+    spot: &PushFoldSpot,
+    payouts: &[f64],
+    hand: &HoleCards,
+    trials: usize,
+    rng: &mut R,
+) -> Result<PushFoldEv, IcmError> {
+    spot.validate()?;
+    let hero = spot.hero;
+    let hero_total = spot.stacks[hero] + spot.posted[hero];
+    let pot: u64 = spot.posted.iter().sum();
+    let dead = pot - spot.posted[hero];
+    let value = |stacks: &[u64]| icm_equity(stacks, payouts).map(|eq| eq[hero]);
+
+    let mut stacks = spot.stacks.clone();
+    stacks[spot.fold_winner] += pot;
+    let fold = value(&stacks)?;
+
+    let blocked = hand.as_slice();
+    let dealable = dealable_combos(blocked);
+    let mut push = 0.0;
+    let mut reach = 1.0;
+    for caller in &spot.callers {
+        let range = unblocked(&caller.range, blocked);
+        let call = range.len() as f64 / dealable as f64;
+        if call == 0.0 {
+            continue;
+        }
+        let seat = caller.seat;
+        let caller_total = spot.stacks[seat] + spot.posted[seat];
+        let matched = hero_total.min(caller_total);
+        let side_dead = dead - spot.posted[seat];
+
+        let mut won = spot.stacks.clone();
+        won[hero] = hero_total + matched + side_dead;
+        won[seat] = caller_total - matched;
+        let mut lost = spot.stacks.clone();
+        lost[hero] = hero_total - matched;
+        lost[seat] = caller_total + matched + side_dead;
+
+        let equity = equity_vs_range(hand, &range, &[], trials, rng)?;
+        let called = equity * value(&won)? + (1.0 - equity) * value(&lost)?;
+        push += reach * call * called;
+        reach *= 1.0 - call;
+    }
+    let mut stacks = spot.stacks.clone();
+    stacks[hero] = hero_total + dead;
+    push += reach * value(&stacks)?;
+    Ok(PushFoldEv { push, fold })
+}
+
+/// A decision between calling one opponent's all-in and folding.
+///
+/// Folding leaves the pot to the `pusher`; nobody else is expected to call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CallFoldSpot {
+    /// Chips behind for every seat, after the all-in
+    pub stacks: Vec<u64>,
+    /// Chips each seat has already put into the pot
+    pub posted: Vec<u64>,
+    pub hero: usize,
+    pub pusher: usize,
+    /// Hands the pusher may hold
+    pub range: Vec<HoleCards>,
+}
+
+impl CallFoldSpot {
+    pub fn new(
+        stacks: Vec<u64>,
+        posted: Vec<u64>,
+        hero: usize,
+        pusher: usize,
+        range: Vec<HoleCards>,
+    ) -> Self {
+        Self { stacks, posted, hero, pusher, range }
+    }
+
+    fn validate(&self) -> Result<(), IcmError> {
+        let num_seats = self.stacks.len();
+        if self.posted.len() != num_seats {
+            return Err(IcmError::LengthMismatch { stacks: num_seats, posted: self.posted.len() });
+        }
+        match [self.hero, self.pusher].into_iter().find(|&s| s >= num_seats) {
+            Some(seat) => Err(IcmError::SeatOutOfRange { seat, num_seats }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Prize equity of calling an all-in and of folding to it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct CallFoldEv {
+    pub call: f64,
+    pub fold: f64,
+}
+
+impl CallFoldEv {
+    /// Whether calling is worth more than folding.
+    pub fn should_call(&self) -> bool {
+        self.call > self.fold
+    }
+}
+
+/// Compare calling an all-in with `hand` against folding, in prize equity.
+///
+/// The hero's equity against the pusher's range is sampled with `trials`
+/// run-outs; each outcome of the call is then valued by [`icm_equity`].
+///
+/// ```
+/// use poker_rs::icm::{call_fold_ev, CallFoldSpot};
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// // The big blind faces a 10 big blind shove from a player holding sevens.
+/// let spot = CallFoldSpot::new(
+///     vec![0, 990, 1000],
+///     vec![100, 10, 0],
+///     1,
+///     0,
+///     vec!["7s 7d".parse().unwrap()],
+/// );
+/// let mut rng = StdRng::seed_from_u64(1);
+/// let aces = call_fold_ev(&spot, &[100.0], &"As Ah".parse().unwrap(), 500, &mut rng).unwrap();
+/// assert!(aces.should_call());
+/// let trash = call_fold_ev(&spot, &[100.0], &"3c 2d".parse().unwrap(), 500, &mut rng).unwrap();
+/// assert!(!trash.should_call());
+/// ```
+pub fn call_fold_ev<R: Rng + ?Sized>(
+    spot: &CallFoldSpot,
+    payouts: &[f64],
+    hand: &HoleCards,
+    trials: usize,
+    rng: &mut R,
+) -> Result<CallFoldEv, IcmError> {
+    spot.validate()?;
+    let (hero, pusher) = (spot.hero, spot.pusher);
+    let pot: u64 = spot.posted.iter().sum();
+    let value = |stacks: &[u64]| icm_equity(stacks, payouts).map(|eq| eq[hero]);
+
+    let mut stacks = spot.stacks.clone();
+    stacks[pusher] += pot;
+    let fold = value(&stacks)?;
+
+    let hero_total = spot.stacks[hero] + spot.posted[hero];
+    let pusher_total = spot.stacks[pusher] + spot.posted[pusher];
+    let matched = hero_total.min(pusher_total);
+    let dead = pot - spot.posted[hero] - spot.posted[pusher];
+
+    let mut won = spot.stacks.clone();
+    won[hero] = hero_total + matched + dead;
+    won[pusher] = pusher_total - matched;
+    let mut lost = spot.stacks.clone();
+    lost[hero] = hero_total - matched;
+    lost[pusher] = pusher_total + matched + dead;
+
+    let equity = equity_vs_range(hand, &spot.range, &[], trials, rng)?;
+    let call = equity * value(&won)? + (1.0 - equity) * value(&lost)?;
+    Ok(CallFoldEv { call, fold })
+}
+
+/// Hands in `range` that share no card with `blocked`.
+fn unblocked(range: &[HoleCards], blocked: &[crate::cards::Card]) -> Vec<HoleCards> {
+    range.iter().filter(|h| !h.as_slice().iter().any(|c| blocked.contains(c))).copied().collect()
+}
+
+/// Number of two-card hands that avoid every card in `blocked`.
+fn dealable_combos(blocked: &[crate::cards::Card]) -> usize {
+    let left = 52 - blocked.len();
+    left * (left - 1) / 2
+}
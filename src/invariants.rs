@@ -0,0 +1,214 @@
+//! Engine state invariants.
+//!
+//! [`Game::check_invariants`] validates that chips are conserved and that
+//! statuses, the seat to act and the board agree with the street. With
+//! [`Game::set_invariant_checks`] the game runs the check after every action
+//! and returns violations as [`ActionError::Invariant`](crate::game::ActionError::Invariant),
+//! which is how stress tests catch bugs the engine would otherwise paper over.
+
+use crate::cards::Card;
+use crate::game::{Game, PlayerStatus, ShowdownError, Street};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
+
+/// A broken engine invariant.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum InvariantViolation {
+    #[error("chips not conserved: expected {expected} across stacks and pot, found {found}")]
+    ChipsNotConserved { expected: u64, found: u64 },
+    #[error("pot {pot} does not match the {contributed} chips contributed this hand")]
+    PotMismatch { pot: u64, contributed: u64 },
+    #[error("pot {pot} was overwritten with the {contributed} chips contributed at showdown")]
+    PotRepaired { pot: u64, contributed: u64 },
+    #[error("showdown failed: {0}")]
+    Showdown(ShowdownError),
+    #[error("seat {seat} bet {bet} but contributed only {contributed} this hand")]
+    BetExceedsContribution { seat: usize, bet: u64, contributed: u64 },
+    #[error("seat {seat} bet {bet} above the current bet {current_bet}")]
+    BetAboveCurrentBet { seat: usize, bet: u64, current_bet: u64 },
+    #[error("seat {seat} is {status:?} with a stack of {stack}")]
+    StatusMismatch { seat: usize, status: PlayerStatus, stack: u64 },
+    #[error("seat {seat} is {status:?} without hole cards")]
+    MissingHoleCards { seat: usize, status: PlayerStatus },
+    #[error("{role} seat {seat} is out of range for {num_seats} seats")]
+    SeatOutOfRange { role: SeatRole, seat: usize, num_seats: usize },
+    #[error("seat {0} is to act but cannot")]
+    CurrentCannotAct(usize),
+    #[error("{len} board cards on the {street:?}")]
+    BoardLength { street: Street, len: usize },
+    #[error("second board has {second} cards but the first has {first}")]
+    SecondBoardLength { first: usize, second: usize },
+    #[error("card {0} is in play more than once")]
+    DuplicateCard(Card),
+}
+
+/// Which seat pointer a [`InvariantViolation::SeatOutOfRange`] is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SeatRole {
+    Current,
+    Dealer,
+}
+
+impl fmt::Display for SeatRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            SeatRole::Current => "current",
+            SeatRole::Dealer => "dealer",
+        })
+    }
+}
+
+impl Game {
+    /// Whether invariants are checked after every action
+    pub fn invariant_checks(&self) -> bool {
+        self.invariant_checks
+    }
+
+    /// Check invariants after every action; a violation is returned from the
+    /// action as [`ActionError::Invariant`](crate::game::ActionError::Invariant)
+    /// after the action has been applied.
+    pub fn set_invariant_checks(&mut self, enabled: bool) {
+        self.invariant_checks = enabled;
+    }
+
+    /// Validate the engine state, reporting the first violation found.
+    ///
+    /// Problems the engine repaired or swallowed while playing the current
+    /// hand (a resynced pot, a failed showdown) are reported first.
+    ///
+    /// ```
+    /// use poker_rs::game::Game;
+    ///
+    /// let mut game = Game::new(3, 1000, 5, 10);
+    /// game.new_hand().unwrap();
+    /// game.action_raise_to(40).unwrap();
+    /// assert_eq!(game.check_invariants(), Ok(()));
+    /// ```
+    pub fn check_invariants(&self) -> Result<(), InvariantViolation> {
+        if let Some(v) = self.violations.first() {
+            return Err(v.clone());
+        }
+        self.check_chips()?;
+        self.check_players()?;
+        self.check_seats()?;
+        self.check_board()?;
+        self.check_cards()
+    }
+
+    fn check_chips(&self) -> Result<(), InvariantViolation> {
+        let found = self.players.iter().map(|p| p.stack).sum::<u64>() + self.pot;
+        if found != self.chips_in_play {
+            return Err(InvariantViolation::ChipsNotConserved {
+                expected: self.chips_in_play,
+                found,
+            });
+        }
+        if self.hand_in_progress() {
+            let contributed = self.players.iter().map(|p| p.contributed).sum();
+            if self.pot != contributed {
+                return Err(InvariantViolation::PotMismatch { pot: self.pot, contributed });
+            }
+        }
+        Ok(())
+    }
+
+    fn check_players(&self) -> Result<(), InvariantViolation> {
+        let in_hand = self.hand_in_progress();
+        for (seat, p) in self.players.iter().enumerate() {
+            if p.bet > p.contributed {
+                return Err(InvariantViolation::BetExceedsContribution {
+                    seat,
+                    bet: p.bet,
+                    contributed: p.contributed,
+                });
+            }
+            let status_ok = match p.status {
+                PlayerStatus::Empty => p.stack == 0 && p.contributed == 0,
+                PlayerStatus::Active => !in_hand || p.stack > 0,
+                PlayerStatus::AllIn => !in_hand || p.stack == 0,
+                PlayerStatus::Folded | PlayerStatus::SittingOut => true,
+            };
+            if !status_ok {
+                return Err(InvariantViolation::StatusMismatch {
+                    seat,
+                    status: p.status,
+                    stack: p.stack,
+                });
+            }
+            if !in_hand {
+                continue;
+            }
+            if matches!(p.status, PlayerStatus::Active | PlayerStatus::AllIn) && p.hole.is_none() {
+                return Err(InvariantViolation::MissingHoleCards { seat, status: p.status });
+            }
+            if p.bet > self.current_bet {
+                return Err(InvariantViolation::BetAboveCurrentBet {
+                    seat,
+                    bet: p.bet,
+                    current_bet: self.current_bet,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn check_seats(&self) -> Result<(), InvariantViolation> {
+        let num_seats = self.players.len();
+        if num_seats == 0 {
+            return Ok(());
+        }
+        for (role, seat) in [(SeatRole::Current, self.current), (SeatRole::Dealer, self.dealer)] {
+            if seat >= num_seats {
+                return Err(InvariantViolation::SeatOutOfRange { role, seat, num_seats });
+            }
+        }
+        if self.hand_in_progress()
+            && !matches!(self.players[self.current].status, PlayerStatus::Active)
+        {
+            return Err(InvariantViolation::CurrentCannotAct(self.current));
+        }
+        Ok(())
+    }
+
+    fn check_board(&self) -> Result<(), InvariantViolation> {
+        let len = self.board.len();
+        let ok = match self.street {
+            Street::Preflop => len == 0,
+            Street::Flop => len == 3,
+            Street::Turn => len == 4,
+            Street::River => len == 5,
+            Street::Showdown => len <= 5,
+        };
+        if !ok {
+            return Err(InvariantViolation::BoardLength { street: self.street, len });
+        }
+        if let Some(second) = &self.second_board {
+            if second.len() != len {
+                return Err(InvariantViolation::SecondBoardLength {
+                    first: len,
+                    second: second.len(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn check_cards(&self) -> Result<(), InvariantViolation> {
+        let mut seen = HashSet::new();
+        let deck = self.deck.draw_order();
+        let boards = std::iter::once(&self.board).chain(&self.second_board);
+        let holes = self.players.iter().filter_map(|p| p.hole.as_ref());
+        let cards = deck
+            .iter()
+            .chain(boards.flat_map(|b| b.as_slice()))
+            .chain(holes.flat_map(|h| h.as_slice()));
+        for &card in cards {
+            if !seen.insert(card) {
+                return Err(InvariantViolation::DuplicateCard(card));
+            }
+        }
+        Ok(())
+    }
+}
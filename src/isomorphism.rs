@@ -0,0 +1,156 @@
+//! Suit isomorphism: situations that differ only by a renaming of suits.
+//!
+//! Suits carry no value of their own, so `Ah Kh` on `2c 7d 9s` plays exactly
+//! like `As Ks` on `2h 7c 9d`. [`canonicalize`] picks one representative of
+//! every such family, which lets equity caches and solver tables store each
+//! situation once. The hole cards are an unordered set, as is the flop, while
+//! the turn and river keep their place; renaming is applied to all of them at
+//! once.
+
+use crate::cards::{Card, Rank, Suit};
+use crate::hand::{Board, HoleCards};
+use std::collections::HashMap;
+
+/// Number of flops that are distinct up to suits.
+pub const DISTINCT_FLOPS: usize = 1755;
+
+/// A renaming of the four suits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SuitMap([Suit; 4]);
+
+impl SuitMap {
+    /// The renaming that changes nothing.
+    pub fn identity() -> Self {
+        Self(Suit::ALL)
+    }
+
+    /// All 24 renamings.
+    pub fn all() -> impl Iterator<Item = SuitMap> {
+        let suits = Suit::ALL;
+        (0..4).flat_map(move |a| {
+            (0..4).flat_map(move |b| {
+                (0..4).filter_map(move |c| {
+                    let d = (0..4).find(|d| ![a, b, c].contains(d))?;
+                    let distinct = a != b && a != c && b != c;
+                    distinct.then(|| Self([suits[a], suits[b], suits[c], suits[d]]))
+                })
+            })
+        })
+    }
+
+    /// Suit that `suit` is renamed to.
+    pub fn suit(&self, suit: Suit) -> Suit {
+        self.0[suit as usize]
+    }
+
+    /// `card` with its suit renamed.
+    pub fn apply(&self, card: Card) -> Card {
+        Card::new(card.rank(), self.suit(card.suit()))
+    }
+
+    /// The renaming that undoes this one.
+    pub fn inverse(&self) -> Self {
+        let mut inverse = Suit::ALL;
+        for suit in Suit::ALL {
+            inverse[self.suit(suit) as usize] = suit;
+        }
+        Self(inverse)
+    }
+}
+
+/// Canonical form of hole cards and a board, with the renaming that maps the
+/// original onto it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct Canonical {
+    pub hole: HoleCards,
+    pub board: Board,
+    /// Renaming from the original suits to the canonical ones
+    pub map: SuitMap,
+}
+
+/// Canonical representative of `hole` on `board` under suit renaming.
+///
+/// Two situations have the same canonical form exactly when one becomes the
+/// other by renaming suits. Hole cards and flop cards come back sorted.
+///
+/// ```
+/// use poker_rs::isomorphism::canonicalize;
+///
+/// let a = canonicalize(&"Ah Kh".parse().unwrap(), &"2c 7d 9s".parse().unwrap());
+/// let b = canonicalize(&"Ks As".parse().unwrap(), &"9d 2h 7c".parse().unwrap());
+/// assert_eq!((&a.hole, &a.board), (&b.hole, &b.board));
+///
+/// let offsuit = canonicalize(&"Ah Kd".parse().unwrap(), &"2c 7d 9s".parse().unwrap());
+/// assert_ne!(offsuit.hole, a.hole);
+/// ```
+pub fn canonicalize(hole: &HoleCards, board: &Board) -> Canonical {
+    let (map, mut cards) = canonical_groups(hole.as_slice(), board.as_slice());
+    let board = Board::new(cards.split_off(hole.as_slice().len()));
+    let hole = HoleCards::from_slice(&cards).expect("renaming keeps hole cards valid");
+    Canonical { hole, board, map }
+}
+
+/// Canonical representative of `board` alone, with the renaming onto it.
+pub fn canonical_board(board: &Board) -> (Board, SuitMap) {
+    let (map, cards) = canonical_groups(&[], board.as_slice());
+    (Board::new(cards), map)
+}
+
+/// Try every renaming and keep the smallest result, comparing the sorted
+/// hole cards, then the sorted flop, then the turn and river in order.
+fn canonical_groups(hole: &[Card], board: &[Card]) -> (SuitMap, Vec<Card>) {
+    let flop = board.len().min(3);
+    let rename = |map: &SuitMap| {
+        let mut cards: Vec<Card> = hole.iter().chain(board).map(|&c| map.apply(c)).collect();
+        cards[..hole.len()].sort();
+        cards[hole.len()..hole.len() + flop].sort();
+        cards
+    };
+    SuitMap::all()
+        .map(|map| (rename(&map), map))
+        .min_by(|(a, _), (b, _)| a.cmp(b))
+        .map(|(cards, map)| (map, cards))
+        .expect("24 renamings")
+}
+
+/// A flop that stands for every flop with the same canonical form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct WeightedFlop {
+    /// Canonical cards, sorted
+    pub cards: [Card; 3],
+    /// Number of the 22100 flops with this canonical form
+    pub weight: u32,
+}
+
+/// The 1755 flops that are distinct up to suits, with how many actual flops
+/// each one stands for.
+///
+/// ```
+/// use poker_rs::isomorphism::{distinct_flops, DISTINCT_FLOPS};
+///
+/// let flops = distinct_flops();
+/// assert_eq!(flops.len(), DISTINCT_FLOPS);
+/// assert_eq!(flops.iter().map(|f| f.weight).sum::<u32>(), 22100);
+/// ```
+pub fn distinct_flops() -> Vec<WeightedFlop> {
+    let deck: Vec<Card> =
+        Rank::ALL.iter().flat_map(|&r| Suit::ALL.iter().map(move |&s| Card::new(r, s))).collect();
+    let mut weights: HashMap<[Card; 3], u32> = HashMap::new();
+    let mut order = Vec::with_capacity(DISTINCT_FLOPS);
+    for (i, &a) in deck.iter().enumerate() {
+        for (j, &b) in deck.iter().enumerate().skip(i + 1) {
+            for &c in &deck[j + 1..] {
+                let (_, cards) = canonical_groups(&[], &[a, b, c]);
+                let key = [cards[0], cards[1], cards[2]];
+                let weight = weights.entry(key).or_insert_with(|| {
+                    order.push(key);
+                    0
+                });
+                *weight += 1;
+            }
+        }
+    }
+    order.into_iter().map(|cards| WeightedFlop { cards, weight: weights[&cards] }).collect()
+}
@@ -0,0 +1,272 @@
+//! Session results per player.
+//!
+//! A [`SessionLedger`] follows every player through a session: the chips they
+//! bought in with, each hand's net result split by whether the hand reached a
+//! showdown, and rake. [`Game`] keeps one up to date as players join and leave
+//! and as hands finish; read it with [`Game::ledger`].
+
+use crate::game::{Game, ShowdownResult};
+use serde::{Deserialize, Serialize};
+
+/// One player's result in a finished hand.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct SeatResult {
+    pub seat: usize,
+    pub name: String,
+    /// Chips won minus chips put into the pot
+    pub net: i64,
+    /// Whether the hand was compared against another at showdown
+    pub showdown: bool,
+}
+
+/// Net results of every player dealt into a hand.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct HandRecord {
+    pub hand_number: u64,
+    pub results: Vec<SeatResult>,
+}
+
+impl HandRecord {
+    pub fn new(hand_number: u64, results: Vec<SeatResult>) -> Self {
+        Self { hand_number, results }
+    }
+}
+
+impl SeatResult {
+    pub fn new(seat: usize, name: impl Into<String>, net: i64, showdown: bool) -> Self {
+        Self { seat, name: name.into(), net, showdown }
+    }
+}
+
+/// A player's running totals for the session.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct PlayerTotals {
+    pub name: String,
+    /// Chips brought to the table when sitting down, over every sitting
+    pub bought_in: u64,
+    /// Chips added while already seated: rebuys and top-ups
+    pub rebuys: u64,
+    /// Chips added by a tournament add-on
+    pub add_ons: u64,
+    /// Chips taken away when leaving the table
+    pub cashed_out: u64,
+    /// Hands dealt in
+    pub hands: u64,
+    /// Sum of the player's per-hand results
+    pub net: i64,
+    /// Part of `net` from hands that reached a showdown against another player
+    pub showdown_net: i64,
+    /// Part of `net` from hands won or lost without a showdown
+    pub non_showdown_net: i64,
+    /// Rake charged to the player
+    pub rake: u64,
+}
+
+impl PlayerTotals {
+    fn named(name: &str) -> Self {
+        Self { name: name.to_string(), ..Self::default() }
+    }
+
+    /// All chips the player has put on the table: buy-ins, rebuys and add-ons.
+    pub fn invested(&self) -> u64 {
+        self.bought_in + self.rebuys + self.add_ons
+    }
+
+    /// Net result after rake.
+    pub fn net_after_rake(&self) -> i64 {
+        self.net - self.rake as i64
+    }
+
+    /// Win rate in big blinds per 100 hands; `None` before the first hand.
+    pub fn bb_per_100(&self, big_blind: u64) -> Option<f64> {
+        if self.hands == 0 || big_blind == 0 {
+            return None;
+        }
+        Some(self.net as f64 / big_blind as f64 * 100.0 / self.hands as f64)
+    }
+
+    fn apply(&mut self, result: &SeatResult, sign: i64) {
+        let net = result.net * sign;
+        self.hands = self.hands.wrapping_add_signed(sign);
+        self.net += net;
+        if result.showdown {
+            self.showdown_net += net;
+        } else {
+            self.non_showdown_net += net;
+        }
+    }
+
+    fn add(&mut self, other: &PlayerTotals) {
+        self.bought_in += other.bought_in;
+        self.rebuys += other.rebuys;
+        self.add_ons += other.add_ons;
+        self.cashed_out += other.cashed_out;
+        self.hands += other.hands;
+        self.net += other.net;
+        self.showdown_net += other.showdown_net;
+        self.non_showdown_net += other.non_showdown_net;
+        self.rake += other.rake;
+    }
+}
+
+/// Per-player results over a session, keyed by player name so a player who
+/// leaves and sits back down keeps one record.
+///
+/// ```
+/// use poker_rs::game::{Game, Street};
+///
+/// let mut game = Game::new(2, 1000, 5, 10);
+/// game.new_hand().unwrap();
+/// game.action_fold().unwrap();
+/// assert_eq!(game.street(), Street::Showdown);
+///
+/// let ledger = game.ledger();
+/// let winner = ledger.player(game.players()[game.winners()[0]].name()).unwrap();
+/// assert_eq!((winner.bought_in, winner.net, winner.non_showdown_net), (1000, 5, 5));
+/// assert_eq!(ledger.bb_per_100(winner.name.as_str()), Some(50.0));
+/// assert_eq!(ledger.totals().net, 0);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct SessionLedger {
+    big_blind: u64,
+    players: Vec<PlayerTotals>,
+    hands: Vec<HandRecord>,
+}
+
+impl SessionLedger {
+    /// An empty ledger for a game played at `big_blind`.
+    pub fn new(big_blind: u64) -> Self {
+        Self { big_blind, ..Self::default() }
+    }
+
+    /// Big blind that [`bb_per_100`](Self::bb_per_100) measures against
+    pub fn big_blind(&self) -> u64 {
+        self.big_blind
+    }
+
+    /// Every player seen this session, in order of first buy-in
+    pub fn players(&self) -> &[PlayerTotals] {
+        &self.players
+    }
+
+    /// Totals for the player called `name`.
+    pub fn player(&self, name: &str) -> Option<&PlayerTotals> {
+        self.players.iter().find(|p| p.name == name)
+    }
+
+    /// Finished hands, oldest first
+    pub fn hands(&self) -> &[HandRecord] {
+        &self.hands
+    }
+
+    /// Totals summed over every player. Without rake the net is zero.
+    pub fn totals(&self) -> PlayerTotals {
+        let mut totals = PlayerTotals::default();
+        for p in &self.players {
+            totals.add(p);
+        }
+        totals
+    }
+
+    /// Win rate of the player called `name` in big blinds per 100 hands.
+    pub fn bb_per_100(&self, name: &str) -> Option<f64> {
+        self.player(name)?.bb_per_100(self.big_blind)
+    }
+
+    /// Record chips brought to the table by a player sitting down.
+    pub fn record_buy_in(&mut self, name: &str, amount: u64) {
+        self.entry(name).bought_in += amount;
+    }
+
+    /// Record chips added by a player who is already seated.
+    pub fn record_rebuy(&mut self, name: &str, amount: u64) {
+        self.entry(name).rebuys += amount;
+    }
+
+    /// Record chips from a tournament add-on.
+    pub fn record_add_on(&mut self, name: &str, amount: u64) {
+        self.entry(name).add_ons += amount;
+    }
+
+    /// Record the stack a player leaves the table with.
+    pub fn record_cash_out(&mut self, name: &str, amount: u64) {
+        self.entry(name).cashed_out += amount;
+    }
+
+    /// Charge rake to a player. The engine takes no rake itself; hosts that
+    /// rake pots record it here.
+    pub fn record_rake(&mut self, name: &str, amount: u64) {
+        self.entry(name).rake += amount;
+    }
+
+    /// Add a finished hand to every dealt-in player's totals.
+    pub fn record_hand(&mut self, hand: HandRecord) {
+        for result in &hand.results {
+            self.entry(&result.name).apply(result, 1);
+        }
+        self.hands.push(hand);
+    }
+
+    /// Take back the most recent hand if it is `hand_number`, as when the
+    /// action that finished it is undone.
+    pub(crate) fn retract_hand(&mut self, hand_number: u64) -> bool {
+        if self.hands.last().map(|h| h.hand_number) != Some(hand_number) {
+            return false;
+        }
+        let hand = self.hands.pop().expect("last hand checked above");
+        for result in &hand.results {
+            self.entry(&result.name).apply(result, -1);
+        }
+        true
+    }
+
+    fn entry(&mut self, name: &str) -> &mut PlayerTotals {
+        let idx = match self.players.iter().position(|p| p.name == name) {
+            Some(idx) => idx,
+            None => {
+                self.players.push(PlayerTotals::named(name));
+                self.players.len() - 1
+            }
+        };
+        &mut self.players[idx]
+    }
+}
+
+impl Game {
+    /// Per-player results for the session so far
+    pub fn ledger(&self) -> &SessionLedger {
+        &self.ledger
+    }
+
+    /// Mutable access to the ledger, for recording rake taken by the host.
+    pub fn ledger_mut(&mut self) -> &mut SessionLedger {
+        &mut self.ledger
+    }
+
+    /// Record the finished hand, given each seat's stack before it started.
+    pub(crate) fn record_hand_result(&mut self, start: &[u64], result: &ShowdownResult) {
+        if self.hand_number == 0
+            || self.ledger.hands.last().is_some_and(|h| h.hand_number == self.hand_number)
+        {
+            return;
+        }
+        let contested = result.hands.len() > 1;
+        let results = self
+            .players
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| p.is_seated() && (p.hole.is_some() || p.contributed > 0))
+            .map(|(seat, p)| SeatResult {
+                seat,
+                name: p.name.clone(),
+                net: p.stack as i64 - start[seat] as i64,
+                showdown: contested && result.hand(seat).is_some(),
+            })
+            .collect();
+        self.ledger.record_hand(HandRecord::new(self.hand_number, results));
+    }
+}
@@ -37,11 +37,20 @@ pub mod agents;
 pub mod cards;
 pub mod deck;
 pub mod engine;
+pub mod equity;
 pub mod evaluator;
 pub mod game;
 pub mod hand;
+pub mod hand_class;
+pub mod icm;
+pub mod invariants;
+pub mod isomorphism;
+pub mod ledger;
+pub mod push_fold;
+pub mod snapshot;
 pub mod tui;
 pub mod variants;
+pub mod view;
 
 /// Library version
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
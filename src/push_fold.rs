@@ -0,0 +1,266 @@
+//! Push/fold equilibrium charts for short-stacked Hold'em.
+//!
+//! With only a few big blinds behind, preflop play reduces to moving all-in
+//! or folding, and calling an all-in or folding. [`solve`] finds the
+//! equilibrium of that game for a player who is first in with some number of
+//! opponents still to act, by fictitious play over the 169 [`HandClass`]es.
+//! Opponents act in turn and the first to call ends the action, as in
+//! [`icm::push_fold_ev`](crate::icm::push_fold_ev). Strategies and matrices
+//! are indexed by [`HandClass::index`].
+//!
+//! Solving needs an [`EquityMatrix`] of class-against-class all-in equity,
+//! which is slow to sample, so the charts bots use are solved offline by
+//! `cargo run --release --example push_fold_tables` and compiled in. A chart
+//! gives each class the largest effective stack, in big blinds, at which it is
+//! still in the range; look one up with [`push_chart`] or [`call_chart`].
+
+mod tables;
+
+use crate::equity::{equity_vs_range, EquityError};
+use crate::hand::HoleCards;
+use crate::hand_class::HandClass;
+use rand::Rng;
+
+/// Number of hand classes in a chart.
+pub const CLASSES: usize = HandClass::COUNT;
+
+/// Most opponents left to act that the charts cover.
+pub const MAX_BEHIND: usize = 8;
+
+/// Deepest effective stack, in big blinds, that the charts are solved for.
+pub const MAX_CHART_BB: f64 = 20.0;
+
+/// Effective stacks the compiled charts are solved at: 1 to 20 big blinds in
+/// half big blind steps.
+pub fn chart_stacks() -> impl Iterator<Item = f64> {
+    (2..=(MAX_CHART_BB as usize * 2)).map(|half| half as f64 / 2.0)
+}
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum PushFoldError {
+    #[error("{0} players left to act; charts cover 1 to {MAX_BEHIND}")]
+    Behind(usize),
+    #[error("effective stack of {0} big blinds is below the big blind")]
+    Stack(f64),
+    #[error(transparent)]
+    Equity(#[from] EquityError),
+}
+
+/// All-in equity of every hand class against every other.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EquityMatrix {
+    /// `equity[i * CLASSES + j]`: equity of class `i` against class `j`
+    equity: Vec<f64>,
+    /// `weight[i * CLASSES + j]`: pairs of combos of the two classes that
+    /// share no card
+    weight: Vec<f64>,
+}
+
+impl EquityMatrix {
+    /// Sample the equity of every pair of classes with `trials` run-outs each.
+    pub fn compute<R: Rng + ?Sized>(trials: usize, rng: &mut R) -> Result<Self, PushFoldError> {
+        let classes: Vec<Vec<HoleCards>> = HandClass::all().map(HandClass::combos).collect();
+        let mut equity = vec![0.0; CLASSES * CLASSES];
+        let mut weight = vec![0.0; CLASSES * CLASSES];
+        for i in 0..CLASSES {
+            for j in i..CLASSES {
+                let pairs = classes[i]
+                    .iter()
+                    .flat_map(|a| classes[j].iter().map(move |b| (a, b)))
+                    .filter(|(a, b)| !a.as_slice().iter().any(|c| b.as_slice().contains(c)))
+                    .count() as f64;
+                // Every combo of a class is the same hand up to suits, so one
+                // stands in for all of them.
+                let eq = equity_vs_range(&classes[i][0], &classes[j], &[], trials, rng)?;
+                equity[i * CLASSES + j] = eq;
+                equity[j * CLASSES + i] = 1.0 - eq;
+                weight[i * CLASSES + j] = pairs;
+                weight[j * CLASSES + i] = pairs;
+            }
+        }
+        Ok(Self { equity, weight })
+    }
+
+    /// Equity of class `i` all-in against class `j`.
+    pub fn equity(&self, i: usize, j: usize) -> f64 {
+        self.equity[i * CLASSES + j]
+    }
+
+    /// How many ways class `i` and class `j` can be dealt together.
+    pub fn weight(&self, i: usize, j: usize) -> f64 {
+        self.weight[i * CLASSES + j]
+    }
+}
+
+/// Equilibrium strategies of one push/fold spot, as the frequency each class
+/// takes the aggressive option.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct Equilibrium {
+    /// Pushing frequency of the first player in
+    pub push: Vec<f64>,
+    /// Calling frequency of each opponent, in the order they act; the last is
+    /// the big blind
+    pub call: Vec<Vec<f64>>,
+}
+
+/// Solve the push/fold game for a player first in with `behind` opponents
+/// left to act, everyone holding `stack_bb` big blinds including blinds.
+///
+/// The blinds are half a big blind and one big blind with no antes. With one
+/// opponent behind the pusher is the small blind, as heads-up.
+pub fn solve(
+    matrix: &EquityMatrix,
+    behind: usize,
+    stack_bb: f64,
+    iterations: usize,
+) -> Result<Equilibrium, PushFoldError> {
+    if !(1..=MAX_BEHIND).contains(&behind) {
+        return Err(PushFoldError::Behind(behind));
+    }
+    if stack_bb.is_nan() || stack_bb < 1.0 {
+        return Err(PushFoldError::Stack(stack_bb));
+    }
+    let stack = stack_bb;
+    let hero_posted = if behind == 1 { 0.5 } else { 0.0 };
+    let posted: Vec<f64> = (0..behind)
+        .map(|c| match behind - c {
+            1 => 1.0,
+            2 => 0.5,
+            _ => 0.0,
+        })
+        .collect();
+    // Blinds of players who fold, won by whoever takes the pot.
+    let dead: Vec<f64> = posted.iter().map(|p| 1.5 - hero_posted - p).collect();
+
+    let mut push = vec![1.0; CLASSES];
+    let mut call = vec![vec![0.0; CLASSES]; behind];
+    for t in 1..=iterations {
+        let step = 1.0 / (t + 1) as f64;
+        for (c, freq) in call.iter_mut().enumerate() {
+            for (j, f) in freq.iter_mut().enumerate() {
+                let (mut w, mut won) = (0.0, 0.0);
+                for (i, &p) in push.iter().enumerate() {
+                    let x = p * matrix.weight(i, j);
+                    w += x;
+                    won += x * matrix.equity(j, i);
+                }
+                let best = w > 0.0 && won / w * (2.0 * stack + dead[c]) - stack > -posted[c];
+                *f += (f64::from(u8::from(best)) - *f) * step;
+            }
+        }
+        for (i, f) in push.iter_mut().enumerate() {
+            let total: f64 = (0..CLASSES).map(|j| matrix.weight(i, j)).sum();
+            let (mut reach, mut ev) = (1.0, 0.0);
+            for (c, freq) in call.iter().enumerate() {
+                let (mut w, mut won) = (0.0, 0.0);
+                for (j, &q) in freq.iter().enumerate() {
+                    let x = q * matrix.weight(i, j);
+                    w += x;
+                    won += x * matrix.equity(i, j);
+                }
+                if w > 0.0 {
+                    ev += reach * w / total * (won / w * (2.0 * stack + dead[c]) - stack);
+                }
+                reach *= 1.0 - w / total;
+            }
+            ev += reach * (1.5 - hero_posted);
+            let best = ev > -hero_posted;
+            *f += (f64::from(u8::from(best)) - *f) * step;
+        }
+    }
+    Ok(Equilibrium { push, call })
+}
+
+/// Whether a chart tells the player to push or to call a push.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ChartKind {
+    Push,
+    Call,
+}
+
+/// Equilibrium range of one push/fold spot across stack depths.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PushFoldChart {
+    kind: ChartKind,
+    behind: usize,
+    max_bb: &'static [f32; CLASSES],
+}
+
+/// Chart for moving all-in first in with `behind` opponents left to act.
+pub fn push_chart(behind: usize) -> Option<PushFoldChart> {
+    let max_bb = tables::PUSH.get(behind.checked_sub(1)?)?;
+    Some(PushFoldChart { kind: ChartKind::Push, behind, max_bb })
+}
+
+/// Chart for the big blind calling an all-in from a player who had `behind`
+/// opponents left to act. Other callers, who have less invested, should be
+/// somewhat tighter.
+pub fn call_chart(behind: usize) -> Option<PushFoldChart> {
+    let max_bb = tables::CALL.get(behind.checked_sub(1)?)?;
+    Some(PushFoldChart { kind: ChartKind::Call, behind, max_bb })
+}
+
+impl PushFoldChart {
+    pub fn kind(&self) -> ChartKind {
+        self.kind
+    }
+
+    /// Opponents left to act behind the pusher
+    pub fn behind(&self) -> usize {
+        self.behind
+    }
+
+    /// Largest effective stack, in big blinds, at which `class` is in the
+    /// range; `0.0` if it never is and [`MAX_CHART_BB`] if it is at every
+    /// depth charted.
+    pub fn max_bb(&self, class: HandClass) -> f64 {
+        f64::from(self.max_bb[class.index()])
+    }
+
+    /// Whether `hole` is in the range with `stack_bb` big blinds effective.
+    ///
+    /// ```
+    /// use poker_rs::push_fold::push_chart;
+    ///
+    /// let heads_up = push_chart(1).unwrap();
+    /// assert!(heads_up.contains(&"Ah Kd".parse().unwrap(), 15.0));
+    /// assert!(!heads_up.contains(&"7c 2d".parse().unwrap(), 10.0));
+    /// ```
+    pub fn contains(&self, hole: &HoleCards, stack_bb: f64) -> bool {
+        stack_bb <= self.max_bb(HandClass::of(hole))
+    }
+
+    /// Every hand in the range with `stack_bb` big blinds effective.
+    pub fn range(&self, stack_bb: f64) -> Vec<HoleCards> {
+        HandClass::all().filter(|&c| stack_bb <= self.max_bb(c)).flat_map(|c| c.combos()).collect()
+    }
+}
+
+/// Chart values of an equilibrium solved at each of `stacks`: the largest
+/// stack at which each class pushes, and at which the big blind calls, at
+/// least half the time.
+pub fn chart_values(
+    matrix: &EquityMatrix,
+    behind: usize,
+    stacks: impl IntoIterator<Item = f64>,
+    iterations: usize,
+) -> Result<(Vec<f64>, Vec<f64>), PushFoldError> {
+    let mut push = vec![0.0; CLASSES];
+    let mut call = vec![0.0; CLASSES];
+    for stack in stacks {
+        let eq = solve(matrix, behind, stack, iterations)?;
+        let big_blind = eq.call.last().expect("at least one caller");
+        for i in 0..CLASSES {
+            if eq.push[i] >= 0.5 {
+                push[i] = f64::max(push[i], stack);
+            }
+            if big_blind[i] >= 0.5 {
+                call[i] = f64::max(call[i], stack);
+            }
+        }
+    }
+    Ok((push, call))
+}
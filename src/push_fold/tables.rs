@@ -0,0 +1,254 @@
+//! Generated by `cargo run --release --example push_fold_tables`; do not edit.
+//!
+//! One table per number of opponents behind the pusher, in chart grid order.
+//! Solved from 1000 run-outs per pair of classes and 300 iterations per stack.
+
+use super::{CLASSES, MAX_BEHIND};
+
+#[rustfmt::skip]
+pub(super) const PUSH: [[f32; CLASSES]; MAX_BEHIND] = [
+    [
+        20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0,
+        20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 16.5,
+        20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 19.0, 16.0, 13.5, 12.0,
+        20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 19.5, 14.5, 13.0, 11.0, 8.0,
+        20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 13.0, 11.5, 8.0, 6.5,
+        20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 16.5, 5.5, 5.0, 3.0,
+        20.0, 18.0, 14.5, 13.5, 19.5, 19.0, 20.0, 20.0, 20.0, 19.0, 10.0, 2.5, 2.5,
+        20.0, 16.0, 10.0, 9.0, 8.5, 10.0, 15.5, 20.0, 20.0, 20.0, 11.0, 2.5, 2.0,
+        20.0, 15.0, 10.0, 6.5, 5.5, 5.0, 6.5, 10.5, 20.0, 20.0, 15.5, 6.5, 2.0,
+        20.0, 13.5, 8.5, 5.5, 4.0, 3.0, 2.5, 2.5, 2.5, 20.0, 20.0, 13.0, 2.0,
+        20.0, 13.0, 8.0, 5.5, 3.5, 2.5, 2.0, 2.0, 2.0, 2.0, 20.0, 8.5, 1.5,
+        20.0, 12.0, 7.5, 5.0, 3.0, 2.5, 2.0, 1.5, 1.5, 1.5, 1.5, 20.0, 1.5,
+        20.0, 11.5, 7.0, 4.5, 3.0, 2.0, 1.5, 1.5, 1.5, 1.5, 1.5, 1.0, 20.0,
+    ],
+    [
+        20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0,
+        20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 19.0, 19.5, 10.0, 7.5, 7.0, 6.0,
+        20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 18.5, 6.5, 6.5, 4.5, 4.0, 3.5, 3.5,
+        20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 19.0, 4.5, 3.5, 3.0, 3.0, 3.0, 2.5,
+        20.0, 20.0, 17.0, 20.0, 20.0, 20.0, 19.5, 10.5, 3.0, 2.5, 2.5, 2.5, 2.5,
+        20.0, 9.0, 6.5, 4.5, 4.0, 20.0, 20.0, 10.5, 3.0, 2.5, 2.5, 2.0, 2.0,
+        20.0, 7.0, 4.5, 3.5, 3.0, 3.0, 20.0, 11.5, 2.5, 2.5, 2.0, 2.0, 2.0,
+        17.0, 6.5, 3.5, 3.0, 3.0, 2.5, 2.5, 20.0, 11.5, 2.5, 2.0, 2.0, 1.5,
+        15.5, 6.0, 3.5, 3.0, 2.5, 2.5, 2.0, 2.0, 20.0, 2.5, 2.0, 1.5, 1.5,
+        16.5, 5.5, 3.0, 2.5, 2.5, 2.0, 2.0, 2.0, 1.5, 20.0, 2.0, 1.5, 1.5,
+        14.0, 5.0, 3.0, 2.5, 2.0, 2.0, 1.5, 1.5, 1.5, 1.5, 20.0, 1.5, 1.0,
+        13.0, 4.5, 3.0, 2.5, 2.0, 1.5, 1.0, 1.0, 1.0, 1.0, 1.0, 20.0, 1.0,
+        12.0, 4.0, 3.0, 2.5, 2.0, 1.5, 1.0, 1.0, 1.0, 1.0, 0.0, 0.0, 20.0,
+    ],
+    [
+        20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 18.5, 17.0, 15.5, 17.5, 13.5, 13.0, 12.5,
+        20.0, 20.0, 20.0, 20.0, 20.0, 17.5, 13.0, 6.5, 7.0, 5.5, 5.0, 4.5, 4.0,
+        20.0, 20.0, 20.0, 20.0, 20.0, 13.5, 5.5, 4.0, 3.5, 3.5, 3.0, 3.0, 3.0,
+        20.0, 17.5, 14.0, 20.0, 20.0, 13.0, 4.0, 3.5, 3.0, 3.0, 2.5, 2.5, 2.5,
+        20.0, 13.0, 6.5, 6.5, 20.0, 14.0, 6.5, 3.0, 2.5, 2.5, 2.5, 2.5, 2.5,
+        15.0, 6.0, 4.0, 3.5, 3.0, 20.0, 10.5, 3.0, 2.5, 2.5, 2.0, 2.0, 2.0,
+        13.5, 5.0, 3.5, 3.0, 3.0, 2.5, 20.0, 3.0, 2.5, 2.5, 2.0, 2.0, 2.0,
+        11.0, 4.5, 3.0, 2.5, 2.5, 2.5, 2.5, 20.0, 2.5, 2.5, 2.0, 2.0, 1.5,
+        10.0, 4.0, 3.0, 2.5, 2.5, 2.0, 2.0, 2.0, 20.0, 2.5, 2.0, 2.0, 1.5,
+        10.0, 3.5, 3.0, 2.5, 2.0, 2.0, 2.0, 2.0, 2.0, 20.0, 2.0, 2.0, 1.5,
+        9.0, 3.5, 2.5, 2.5, 2.0, 2.0, 1.5, 1.5, 1.5, 1.5, 19.0, 2.0, 1.5,
+        8.0, 3.5, 2.5, 2.0, 2.0, 1.5, 1.5, 1.5, 1.5, 1.5, 1.5, 16.0, 1.5,
+        7.5, 3.0, 2.5, 2.0, 2.0, 1.5, 1.5, 1.5, 1.5, 1.5, 1.0, 1.0, 13.0,
+    ],
+    [
+        20.0, 20.0, 20.0, 20.0, 20.0, 18.5, 13.5, 12.0, 11.0, 12.5, 9.5, 9.0, 8.5,
+        20.0, 20.0, 20.0, 20.0, 17.5, 11.5, 5.5, 5.0, 5.0, 4.5, 4.0, 3.5, 3.5,
+        20.0, 15.0, 20.0, 15.5, 13.5, 5.5, 4.0, 3.5, 3.0, 3.0, 3.0, 3.0, 3.0,
+        20.0, 12.0, 6.5, 20.0, 14.0, 5.0, 3.5, 3.0, 2.5, 2.5, 2.5, 2.5, 2.5,
+        16.5, 7.5, 4.5, 4.0, 20.0, 7.5, 3.5, 3.0, 2.5, 2.5, 2.5, 2.5, 2.0,
+        11.0, 5.0, 3.5, 3.0, 3.0, 20.0, 3.5, 3.0, 2.5, 2.5, 2.0, 2.0, 2.0,
+        10.0, 4.0, 3.0, 3.0, 2.5, 2.5, 20.0, 3.0, 2.5, 2.5, 2.0, 2.0, 2.0,
+        8.0, 3.5, 3.0, 2.5, 2.5, 2.5, 2.5, 20.0, 2.5, 2.5, 2.0, 2.0, 1.5,
+        7.5, 3.5, 2.5, 2.5, 2.0, 2.0, 2.0, 2.0, 20.0, 2.5, 2.0, 2.0, 1.5,
+        7.5, 3.5, 2.5, 2.5, 2.0, 2.0, 2.0, 2.0, 2.0, 16.5, 2.5, 2.0, 2.0,
+        6.5, 3.0, 2.5, 2.0, 2.0, 2.0, 1.5, 1.5, 1.5, 2.0, 13.5, 2.0, 1.5,
+        6.0, 3.0, 2.5, 2.0, 2.0, 1.5, 1.5, 1.5, 1.5, 1.5, 1.5, 11.5, 1.5,
+        5.5, 3.0, 2.5, 2.0, 2.0, 1.5, 1.5, 1.5, 1.5, 1.5, 1.0, 1.0, 9.0,
+    ],
+    [
+        20.0, 20.0, 20.0, 20.0, 20.0, 14.5, 10.5, 9.5, 8.5, 9.5, 7.5, 7.0, 6.5,
+        20.0, 20.0, 20.0, 15.5, 13.0, 6.0, 4.5, 4.5, 4.0, 4.0, 3.5, 3.5, 3.5,
+        20.0, 11.5, 20.0, 11.5, 9.5, 4.5, 3.5, 3.0, 3.0, 3.0, 3.0, 3.0, 2.5,
+        20.0, 8.5, 5.0, 20.0, 9.5, 4.0, 3.0, 3.0, 2.5, 2.5, 2.5, 2.5, 2.5,
+        13.5, 5.5, 4.0, 3.5, 20.0, 4.5, 3.0, 3.0, 2.5, 2.5, 2.5, 2.0, 2.0,
+        9.0, 4.5, 3.5, 3.0, 3.0, 20.0, 3.0, 2.5, 2.5, 2.5, 2.0, 2.0, 2.0,
+        8.0, 3.5, 3.0, 2.5, 2.5, 2.5, 20.0, 3.0, 2.5, 2.5, 2.0, 2.0, 2.0,
+        6.5, 3.5, 2.5, 2.5, 2.5, 2.5, 2.5, 19.0, 2.5, 2.5, 2.0, 2.0, 1.5,
+        6.0, 3.5, 2.5, 2.5, 2.0, 2.0, 2.0, 2.0, 17.5, 2.5, 2.0, 2.0, 1.5,
+        6.0, 3.0, 2.5, 2.5, 2.0, 2.0, 2.0, 2.0, 2.0, 13.0, 2.5, 2.0, 2.0,
+        5.5, 3.0, 2.5, 2.0, 2.0, 2.0, 1.5, 1.5, 1.5, 2.0, 10.5, 2.0, 1.5,
+        5.0, 3.0, 2.5, 2.0, 2.0, 1.5, 1.5, 1.5, 1.5, 1.5, 1.5, 8.5, 1.5,
+        4.5, 3.0, 2.5, 2.0, 2.0, 1.5, 1.5, 1.5, 1.5, 1.5, 1.0, 1.0, 7.0,
+    ],
+    [
+        20.0, 20.0, 20.0, 20.0, 18.0, 11.5, 9.0, 8.0, 7.0, 8.0, 6.5, 6.0, 6.0,
+        20.0, 20.0, 16.0, 12.0, 10.0, 5.0, 4.5, 4.0, 4.0, 3.5, 3.5, 3.5, 3.0,
+        20.0, 9.0, 20.0, 9.0, 7.0, 4.0, 3.5, 3.0, 3.0, 3.0, 3.0, 3.0, 2.5,
+        17.5, 6.5, 4.5, 20.0, 7.0, 3.5, 3.0, 3.0, 2.5, 2.5, 2.5, 2.5, 2.5,
+        11.5, 5.0, 3.5, 3.5, 20.0, 4.0, 3.0, 3.0, 2.5, 2.5, 2.5, 2.0, 2.0,
+        8.0, 4.0, 3.0, 3.0, 3.0, 20.0, 3.0, 2.5, 2.5, 2.5, 2.0, 2.0, 2.0,
+        7.0, 3.5, 3.0, 2.5, 2.5, 2.5, 20.0, 3.0, 2.5, 2.5, 2.0, 2.0, 2.0,
+        6.0, 3.5, 2.5, 2.5, 2.5, 2.0, 2.5, 16.5, 2.5, 2.5, 2.0, 2.0, 1.5,
+        5.5, 3.0, 2.5, 2.5, 2.0, 2.0, 2.0, 2.0, 15.0, 2.5, 2.0, 2.0, 1.5,
+        5.5, 3.0, 2.5, 2.5, 2.0, 2.0, 2.0, 2.0, 2.0, 11.0, 2.5, 2.0, 2.0,
+        5.0, 3.0, 2.5, 2.0, 2.0, 2.0, 1.5, 1.5, 1.5, 2.0, 9.0, 2.0, 1.5,
+        4.5, 3.0, 2.5, 2.0, 2.0, 1.5, 1.5, 1.5, 1.5, 1.5, 1.5, 7.0, 1.5,
+        4.5, 3.0, 2.5, 2.0, 2.0, 1.5, 1.5, 1.5, 1.5, 1.5, 1.0, 1.0, 6.0,
+    ],
+    [
+        20.0, 20.0, 20.0, 20.0, 15.5, 10.0, 8.0, 7.0, 6.5, 7.0, 6.0, 5.5, 5.5,
+        20.0, 20.0, 13.5, 10.0, 7.5, 4.5, 4.0, 4.0, 4.0, 3.5, 3.5, 3.0, 3.0,
+        20.0, 7.5, 20.0, 7.0, 5.5, 3.5, 3.5, 3.0, 3.0, 3.0, 3.0, 3.0, 2.5,
+        15.5, 5.5, 4.0, 20.0, 5.0, 3.5, 3.0, 3.0, 2.5, 2.5, 2.5, 2.5, 2.5,
+        10.0, 4.5, 3.5, 3.5, 20.0, 3.5, 3.0, 3.0, 2.5, 2.5, 2.5, 2.0, 2.0,
+        7.5, 4.0, 3.0, 3.0, 3.0, 20.0, 3.0, 2.5, 2.5, 2.5, 2.0, 2.0, 2.0,
+        6.5, 3.5, 3.0, 2.5, 2.5, 2.5, 20.0, 3.0, 2.5, 2.5, 2.0, 2.0, 2.0,
+        5.5, 3.5, 2.5, 2.5, 2.5, 2.0, 2.0, 14.5, 2.5, 2.5, 2.0, 2.0, 1.5,
+        5.0, 3.0, 2.5, 2.5, 2.0, 2.0, 2.0, 2.0, 13.0, 2.5, 2.0, 2.0, 1.5,
+        5.0, 3.0, 2.5, 2.5, 2.0, 2.0, 2.0, 2.0, 2.0, 10.0, 2.5, 2.0, 2.0,
+        4.5, 3.0, 2.5, 2.0, 2.0, 2.0, 1.5, 1.5, 1.5, 2.0, 8.0, 2.0, 1.5,
+        4.5, 3.0, 2.5, 2.0, 2.0, 1.5, 1.5, 1.5, 1.5, 1.5, 1.5, 6.5, 1.5,
+        4.0, 3.0, 2.5, 2.0, 2.0, 1.5, 1.5, 1.5, 1.5, 1.5, 1.0, 1.0, 5.5,
+    ],
+    [
+        20.0, 20.0, 20.0, 20.0, 14.0, 9.0, 7.5, 6.5, 6.0, 6.5, 5.5, 5.0, 5.0,
+        20.0, 20.0, 11.5, 8.5, 6.5, 4.5, 4.0, 3.5, 3.5, 3.5, 3.5, 3.0, 3.0,
+        20.0, 6.5, 20.0, 6.0, 5.0, 3.5, 3.0, 3.0, 3.0, 3.0, 3.0, 3.0, 2.5,
+        14.0, 5.5, 4.0, 20.0, 4.5, 3.5, 3.0, 3.0, 2.5, 2.5, 2.5, 2.5, 2.5,
+        9.5, 4.5, 3.5, 3.5, 20.0, 3.5, 3.0, 3.0, 2.5, 2.5, 2.5, 2.0, 2.0,
+        7.0, 4.0, 3.0, 3.0, 3.0, 20.0, 3.0, 2.5, 2.5, 2.5, 2.0, 2.0, 2.0,
+        6.0, 3.5, 3.0, 2.5, 2.5, 2.5, 20.0, 3.0, 2.5, 2.5, 2.0, 2.0, 2.0,
+        5.5, 3.0, 2.5, 2.5, 2.5, 2.0, 2.0, 13.5, 2.5, 2.5, 2.0, 2.0, 1.5,
+        5.0, 3.0, 2.5, 2.5, 2.0, 2.0, 2.0, 2.0, 12.0, 2.5, 2.0, 2.0, 1.5,
+        5.0, 3.0, 2.5, 2.5, 2.0, 2.0, 2.0, 2.0, 2.0, 9.0, 2.5, 2.0, 2.0,
+        4.5, 3.0, 2.5, 2.0, 2.0, 2.0, 1.5, 1.5, 1.5, 2.0, 7.5, 2.0, 1.5,
+        4.0, 3.0, 2.5, 2.0, 2.0, 1.5, 1.5, 1.5, 1.5, 1.5, 1.5, 6.0, 1.5,
+        4.0, 3.0, 2.5, 2.0, 2.0, 1.5, 1.5, 1.5, 1.5, 1.5, 1.0, 1.0, 5.0,
+    ],
+];
+
+#[rustfmt::skip]
+pub(super) const CALL: [[f32; CLASSES]; MAX_BEHIND] = [
+    [
+        20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 20.0,
+        20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 18.0, 15.5, 15.0, 13.0, 12.5, 11.0, 10.0,
+        20.0, 20.0, 20.0, 20.0, 20.0, 15.0, 12.5, 10.0, 9.5, 8.5, 8.0, 7.5, 7.0,
+        20.0, 20.0, 18.5, 20.0, 18.0, 12.5, 10.0, 8.0, 6.5, 6.5, 6.0, 5.5, 5.0,
+        20.0, 20.0, 15.0, 12.5, 20.0, 11.0, 8.5, 7.0, 5.5, 5.0, 5.0, 4.5, 4.5,
+        20.0, 17.0, 12.0, 9.0, 8.0, 20.0, 8.0, 6.5, 5.5, 5.0, 4.0, 4.0, 3.5,
+        20.0, 14.0, 9.5, 7.5, 6.5, 5.5, 20.0, 6.0, 5.0, 4.5, 4.0, 3.5, 3.5,
+        20.0, 12.5, 7.5, 6.0, 5.0, 4.5, 4.5, 20.0, 5.5, 4.5, 4.0, 3.5, 3.0,
+        20.0, 11.0, 7.0, 5.0, 4.5, 4.0, 4.0, 4.0, 20.0, 5.0, 4.0, 3.5, 3.0,
+        20.0, 10.0, 6.5, 5.0, 4.0, 3.5, 3.5, 3.5, 3.5, 20.0, 4.5, 4.0, 3.5,
+        18.0, 9.0, 6.0, 4.5, 3.5, 3.0, 3.0, 3.0, 3.0, 3.5, 20.0, 3.5, 3.0,
+        16.5, 8.5, 5.5, 4.5, 3.5, 3.0, 2.5, 2.5, 3.0, 3.0, 3.0, 20.0, 3.0,
+        16.0, 8.0, 5.5, 4.0, 3.5, 3.0, 2.5, 2.5, 2.5, 2.5, 2.5, 2.5, 15.0,
+    ],
+    [
+        20.0, 20.0, 20.0, 20.0, 20.0, 20.0, 19.5, 17.0, 15.5, 16.5, 13.5, 12.5, 12.0,
+        20.0, 20.0, 20.0, 18.5, 15.0, 10.5, 8.5, 8.0, 7.5, 7.0, 6.5, 6.0, 5.5,
+        20.0, 18.5, 20.0, 13.0, 10.5, 7.5, 6.5, 5.5, 5.5, 5.0, 5.0, 4.5, 4.5,
+        20.0, 14.0, 9.5, 20.0, 9.5, 7.0, 6.0, 5.0, 4.5, 4.5, 4.5, 4.0, 4.0,
+        20.0, 10.5, 7.5, 6.5, 20.0, 7.0, 6.0, 5.5, 4.5, 4.5, 4.0, 4.0, 4.0,
+        18.5, 8.0, 6.0, 5.0, 5.0, 20.0, 6.0, 5.5, 5.0, 4.5, 4.0, 3.5, 3.5,
+        16.5, 6.5, 5.0, 4.5, 4.5, 4.5, 20.0, 5.5, 5.0, 4.5, 4.0, 3.5, 3.5,
+        13.5, 6.0, 4.5, 4.0, 4.0, 4.0, 4.5, 20.0, 5.5, 5.0, 4.0, 4.0, 3.5,
+        11.5, 5.5, 4.5, 3.5, 3.5, 3.5, 4.0, 4.0, 20.0, 5.0, 4.5, 4.0, 3.5,
+        11.0, 5.0, 4.0, 3.5, 3.0, 3.5, 3.5, 3.5, 4.0, 20.0, 4.5, 4.5, 4.0,
+        10.0, 5.0, 4.0, 3.5, 3.0, 3.0, 3.0, 3.5, 3.5, 3.5, 19.0, 4.0, 3.5,
+        9.0, 5.0, 4.0, 3.5, 3.0, 3.0, 3.0, 3.0, 3.0, 3.5, 3.0, 14.5, 3.5,
+        8.5, 4.5, 3.5, 3.0, 3.0, 3.0, 3.0, 2.5, 3.0, 3.0, 3.0, 2.5, 12.0,
+    ],
+    [
+        20.0, 20.0, 20.0, 20.0, 19.5, 15.0, 13.0, 12.0, 10.5, 12.0, 10.0, 9.5, 9.0,
+        20.0, 20.0, 16.0, 12.5, 10.5, 7.5, 7.0, 6.5, 6.5, 6.0, 5.5, 5.0, 5.0,
+        20.0, 12.5, 20.0, 9.5, 8.0, 6.5, 5.5, 5.0, 5.0, 4.5, 4.5, 4.5, 4.0,
+        20.0, 10.0, 7.0, 20.0, 8.0, 6.0, 5.5, 5.0, 4.5, 4.5, 4.0, 4.0, 3.5,
+        16.0, 7.5, 6.0, 5.5, 20.0, 6.5, 5.5, 5.0, 4.5, 4.0, 4.0, 4.0, 3.5,
+        13.0, 6.0, 5.0, 4.5, 4.5, 20.0, 6.0, 5.0, 5.0, 4.0, 3.5, 3.5, 3.5,
+        11.0, 5.5, 4.5, 4.0, 4.5, 4.0, 20.0, 5.5, 5.0, 4.5, 4.0, 3.5, 3.5,
+        9.5, 5.0, 4.0, 4.0, 4.0, 4.0, 4.0, 20.0, 5.5, 4.5, 4.0, 3.5, 3.5,
+        8.5, 5.0, 4.0, 3.5, 3.5, 3.5, 3.5, 4.0, 18.5, 5.0, 4.5, 4.0, 3.5,
+        8.0, 4.5, 3.5, 3.5, 3.0, 3.5, 3.5, 3.5, 3.5, 15.5, 4.5, 4.0, 4.0,
+        7.5, 4.5, 3.5, 3.5, 3.0, 3.0, 3.0, 3.5, 3.5, 3.5, 13.5, 4.0, 3.5,
+        7.0, 4.0, 3.5, 3.5, 3.0, 3.0, 3.0, 3.0, 3.0, 3.5, 3.0, 11.0, 3.5,
+        6.5, 4.0, 3.5, 3.0, 3.0, 3.0, 2.5, 2.5, 2.5, 3.0, 3.0, 2.5, 9.5,
+    ],
+    [
+        20.0, 20.0, 20.0, 20.0, 15.0, 12.0, 10.5, 9.5, 8.5, 9.0, 8.0, 7.5, 7.5,
+        20.0, 20.0, 12.5, 9.5, 8.5, 6.5, 6.0, 5.5, 6.0, 5.5, 5.0, 5.0, 4.5,
+        20.0, 10.0, 20.0, 8.0, 7.5, 6.0, 5.5, 4.5, 4.5, 4.5, 4.5, 4.0, 4.0,
+        17.0, 8.0, 6.5, 20.0, 7.5, 6.0, 5.5, 5.0, 4.5, 4.0, 4.0, 4.0, 3.5,
+        13.0, 6.5, 5.5, 5.5, 20.0, 6.5, 5.5, 5.0, 4.5, 4.0, 4.0, 3.5, 3.5,
+        10.0, 5.5, 4.5, 4.5, 4.5, 20.0, 6.0, 5.0, 5.0, 4.0, 3.5, 3.5, 3.5,
+        9.0, 5.0, 4.5, 4.0, 4.0, 4.0, 20.0, 5.5, 5.0, 4.5, 4.0, 3.5, 3.5,
+        7.5, 4.5, 3.5, 3.5, 3.5, 4.0, 4.0, 18.0, 5.5, 4.5, 4.0, 3.5, 3.5,
+        7.0, 4.5, 4.0, 3.5, 3.5, 3.5, 3.5, 4.0, 15.5, 5.0, 4.5, 4.0, 3.5,
+        7.0, 4.5, 3.5, 3.5, 3.0, 3.0, 3.5, 3.5, 3.5, 12.5, 4.5, 4.0, 4.0,
+        6.5, 4.0, 3.5, 3.5, 3.0, 3.0, 3.0, 3.0, 3.5, 3.5, 11.0, 4.0, 3.5,
+        6.0, 4.0, 3.5, 3.0, 3.0, 3.0, 3.0, 3.0, 3.0, 3.5, 3.0, 9.0, 3.5,
+        6.0, 4.0, 3.5, 3.0, 3.0, 3.0, 2.5, 2.5, 2.5, 3.0, 3.0, 2.5, 8.0,
+    ],
+    [
+        20.0, 20.0, 20.0, 18.0, 13.5, 10.5, 9.0, 8.5, 8.0, 8.0, 7.5, 7.0, 7.0,
+        20.0, 20.0, 11.0, 9.0, 7.5, 6.5, 5.5, 5.5, 5.5, 5.0, 5.0, 4.5, 4.5,
+        20.0, 8.5, 20.0, 7.5, 7.0, 6.0, 5.5, 4.5, 4.5, 4.5, 4.0, 4.0, 4.0,
+        14.5, 7.0, 6.0, 20.0, 7.0, 6.0, 5.0, 5.0, 4.5, 4.0, 4.0, 4.0, 3.5,
+        11.0, 6.0, 5.5, 5.5, 20.0, 6.5, 5.5, 5.0, 4.5, 4.0, 4.0, 3.5, 3.5,
+        8.5, 5.0, 4.5, 4.5, 4.5, 20.0, 6.0, 5.0, 5.0, 4.0, 3.5, 3.5, 3.5,
+        8.0, 4.5, 4.0, 4.0, 4.5, 4.0, 20.0, 5.5, 5.0, 4.5, 4.0, 3.5, 3.5,
+        7.0, 4.5, 3.5, 3.5, 3.5, 4.0, 4.0, 16.0, 5.0, 4.5, 4.0, 3.5, 3.5,
+        6.5, 4.5, 3.5, 3.5, 3.5, 3.5, 3.5, 4.0, 13.5, 5.0, 4.0, 4.0, 3.5,
+        6.5, 4.0, 3.5, 3.5, 3.0, 3.0, 3.5, 3.5, 3.5, 11.0, 4.5, 4.0, 3.5,
+        6.0, 4.0, 3.5, 3.5, 3.0, 3.0, 3.0, 3.0, 3.5, 3.5, 9.5, 4.0, 3.5,
+        6.0, 4.0, 3.5, 3.0, 3.0, 3.0, 3.0, 3.0, 3.0, 3.5, 3.0, 8.5, 3.5,
+        5.5, 4.0, 3.5, 3.0, 3.0, 3.0, 2.5, 2.5, 2.5, 3.0, 3.0, 2.5, 7.5,
+    ],
+    [
+        20.0, 20.0, 20.0, 16.5, 12.0, 9.5, 8.5, 7.5, 7.0, 7.5, 6.5, 6.5, 6.5,
+        20.0, 20.0, 10.0, 8.5, 7.5, 6.0, 5.5, 5.5, 5.5, 5.0, 4.5, 4.5, 4.5,
+        20.0, 8.0, 20.0, 7.0, 6.5, 5.5, 5.0, 4.5, 4.5, 4.5, 4.0, 4.0, 4.0,
+        13.0, 6.5, 6.0, 20.0, 7.0, 6.0, 5.0, 4.5, 4.5, 4.0, 4.0, 4.0, 3.5,
+        10.0, 6.0, 5.0, 5.5, 20.0, 6.0, 5.5, 5.0, 4.5, 4.0, 4.0, 3.5, 3.5,
+        8.0, 5.0, 4.5, 4.5, 4.5, 20.0, 5.5, 5.0, 4.5, 4.0, 3.5, 3.5, 3.5,
+        7.0, 4.5, 4.0, 4.0, 4.5, 4.5, 20.0, 5.5, 5.0, 4.5, 4.0, 3.5, 3.5,
+        6.5, 4.5, 3.5, 3.5, 3.5, 4.0, 4.0, 15.0, 5.0, 4.5, 4.0, 3.5, 3.5,
+        6.0, 4.0, 3.5, 3.5, 3.5, 3.5, 3.5, 4.0, 12.5, 5.0, 4.0, 4.0, 3.5,
+        6.0, 4.0, 3.5, 3.5, 3.0, 3.0, 3.5, 3.5, 3.5, 10.0, 4.5, 4.0, 3.5,
+        6.0, 4.0, 3.5, 3.0, 3.0, 3.0, 3.0, 3.0, 3.5, 3.5, 9.0, 4.0, 3.5,
+        5.5, 4.0, 3.5, 3.0, 3.0, 3.0, 3.0, 3.0, 3.0, 3.0, 3.0, 8.0, 3.5,
+        5.5, 3.5, 3.0, 3.0, 3.0, 3.0, 2.5, 2.5, 2.5, 3.0, 3.0, 2.5, 7.0,
+    ],
+    [
+        20.0, 20.0, 20.0, 15.5, 11.5, 9.0, 7.5, 7.5, 7.0, 7.5, 6.5, 6.5, 6.0,
+        20.0, 20.0, 10.0, 8.0, 7.0, 6.0, 5.5, 5.0, 5.0, 5.0, 4.5, 4.5, 4.0,
+        20.0, 7.5, 20.0, 7.0, 6.5, 5.5, 5.0, 4.5, 4.5, 4.5, 4.0, 4.0, 4.0,
+        12.5, 6.5, 5.5, 20.0, 7.0, 5.5, 5.0, 4.5, 4.5, 4.0, 4.0, 4.0, 3.5,
+        9.0, 5.5, 5.0, 5.0, 20.0, 6.0, 5.5, 5.0, 4.5, 4.0, 4.0, 3.5, 3.5,
+        7.5, 5.0, 4.5, 4.5, 4.5, 20.0, 5.5, 5.0, 4.5, 4.0, 3.5, 3.5, 3.5,
+        7.0, 4.5, 4.0, 4.0, 4.0, 4.5, 18.5, 5.0, 5.0, 4.5, 4.0, 3.5, 3.5,
+        6.0, 4.5, 3.5, 3.5, 3.5, 4.0, 4.0, 13.5, 5.0, 4.5, 4.0, 3.5, 3.5,
+        6.0, 4.0, 3.5, 3.5, 3.5, 3.5, 3.5, 4.0, 11.5, 5.0, 4.0, 4.0, 3.5,
+        6.0, 4.0, 3.5, 3.5, 3.0, 3.0, 3.5, 3.5, 3.5, 9.5, 4.5, 4.0, 3.5,
+        5.5, 4.0, 3.5, 3.0, 3.0, 3.0, 3.0, 3.0, 3.5, 3.5, 8.5, 4.0, 3.5,
+        5.5, 3.5, 3.5, 3.0, 3.0, 3.0, 3.0, 3.0, 3.0, 3.0, 3.0, 7.5, 3.5,
+        5.0, 3.5, 3.0, 3.0, 3.0, 3.0, 2.5, 2.5, 2.5, 3.0, 3.0, 2.5, 7.0,
+    ],
+    [
+        20.0, 20.0, 20.0, 15.0, 11.0, 8.5, 7.5, 7.0, 6.5, 7.0, 6.5, 6.0, 6.0,
+        20.0, 20.0, 9.5, 8.0, 7.0, 6.0, 5.5, 5.0, 5.0, 5.0, 4.5, 4.5, 4.0,
+        20.0, 7.5, 20.0, 7.0, 6.5, 5.5, 5.0, 4.5, 4.5, 4.5, 4.0, 4.0, 4.0,
+        11.5, 6.5, 5.5, 20.0, 6.5, 5.5, 5.0, 4.5, 4.5, 4.0, 4.0, 4.0, 3.5,
+        9.0, 5.5, 5.0, 5.0, 20.0, 6.0, 5.5, 5.0, 4.5, 4.0, 4.0, 3.5, 3.5,
+        7.0, 5.0, 4.5, 4.5, 4.5, 20.0, 5.5, 5.0, 4.5, 4.0, 3.5, 3.5, 3.5,
+        6.5, 4.5, 4.0, 4.0, 4.0, 4.0, 17.5, 5.0, 4.5, 4.5, 4.0, 3.5, 3.5,
+        6.0, 4.0, 3.5, 3.5, 3.5, 4.0, 4.0, 13.0, 5.0, 4.5, 4.0, 3.5, 3.5,
+        5.5, 4.0, 3.5, 3.5, 3.5, 3.5, 3.5, 4.0, 11.0, 4.5, 4.0, 4.0, 3.5,
+        5.5, 4.0, 3.5, 3.0, 3.0, 3.0, 3.0, 3.5, 3.5, 9.0, 4.5, 4.0, 3.5,
+        5.5, 4.0, 3.5, 3.0, 3.0, 3.0, 3.0, 3.0, 3.5, 3.5, 8.0, 4.0, 3.5,
+        5.5, 3.5, 3.5, 3.0, 3.0, 3.0, 3.0, 3.0, 3.0, 3.0, 3.0, 7.0, 3.0,
+        5.0, 3.5, 3.0, 3.0, 3.0, 3.0, 2.5, 2.5, 2.5, 3.0, 3.0, 2.5, 6.5,
+    ],
+];
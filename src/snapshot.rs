@@ -0,0 +1,378 @@
+//! Game snapshots: a serializable copy of a `Game` in progress.
+//!
+//! A `GameSnapshot` captures everything needed to resume a hand exactly:
+//! players, stacks, deck order, board, pot, betting state and history. It is
+//! written to disk as versioned JSON so saved sessions and attached bug
+//! reports keep loading as the engine evolves.
+
+use crate::cards::Card;
+use crate::deck::{Deck, DeckError};
+use crate::evaluator::Category;
+use crate::game::{
+    BetLimit, ButtonRule, BuyInRules, Game, HandHistoryEntry, MissedBlind, OddChipRule, Player,
+    PlayerStatus, ShowdownResult, Street,
+};
+use crate::hand::{Board, HoleCards};
+use crate::invariants::InvariantViolation;
+use crate::ledger::SessionLedger;
+use crate::variants::{self, Holdem, Variant};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Current on-disk snapshot format version.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum SnapshotError {
+    #[error("unsupported snapshot version {found} (supported: {supported})")]
+    UnsupportedVersion { found: u32, supported: u32 },
+    #[error("invalid snapshot: {0}")]
+    Invalid(String),
+    #[error("duplicate card in snapshot: {0}")]
+    DuplicateCard(Card),
+    #[error(transparent)]
+    Deck(#[from] DeckError),
+    #[error("snapshot encoding failed: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("snapshot io failed: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// Serializable state of a single seat.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct PlayerSnapshot {
+    pub name: String,
+    pub stack: u64,
+    pub bet: u64,
+    pub contributed: u64,
+    #[serde(default)]
+    pub dead: u64,
+    pub status: PlayerStatus,
+    pub hole: Option<HoleCards>,
+    pub last_action: Option<String>,
+    #[serde(default)]
+    pub missed_blind: Option<MissedBlind>,
+    #[serde(default)]
+    pub acted_at: Option<u64>,
+}
+
+/// Serializable state of a whole `Game`.
+///
+/// Undo history is not saved: a restored game plays on identically but
+/// starts with nothing to [`undo`](Game::undo).
+///
+/// ```
+/// use poker_rs::game::Game;
+/// use poker_rs::snapshot::GameSnapshot;
+///
+/// let mut game = Game::with_seed(3, 1000, 5, 10, 1);
+/// game.new_hand().unwrap();
+/// game.action_check_call().unwrap();
+///
+/// let json = game.snapshot().to_json().unwrap();
+/// let restored = Game::restore(GameSnapshot::from_json(&json).unwrap()).unwrap();
+/// assert_eq!(restored.snapshot(), game.snapshot());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct GameSnapshot {
+    pub version: u32,
+    pub small_blind: u64,
+    pub big_blind: u64,
+    pub starting_stack: u64,
+    pub seed: u64,
+    pub hand_number: u64,
+    pub hand_seed: u64,
+    /// Remaining deck, next card to be drawn first
+    pub deck: Vec<Card>,
+    pub board: Vec<Card>,
+    #[serde(default)]
+    pub second_board: Option<Vec<Card>>,
+    #[serde(default)]
+    pub double_board: bool,
+    /// [`Variant::name`] of the game being played; empty in snapshots
+    /// written before variants existed, meaning Hold'em
+    #[serde(default)]
+    pub variant: String,
+    /// Variant chosen mid-hand for the next hand, if any
+    #[serde(default)]
+    pub next_variant: Option<String>,
+    pub players: Vec<PlayerSnapshot>,
+    pub pot: u64,
+    pub dealer: usize,
+    pub current: usize,
+    pub street: Street,
+    pub current_bet: u64,
+    pub min_raise: u64,
+    pub last_raiser: Option<usize>,
+    pub sb_pos: Option<usize>,
+    pub bb_pos: Option<usize>,
+    #[serde(default)]
+    pub sb_slot: Option<usize>,
+    #[serde(default)]
+    pub bb_slot: Option<usize>,
+    #[serde(default)]
+    pub button_rule: ButtonRule,
+    #[serde(default)]
+    pub bet_limit: BetLimit,
+    #[serde(default)]
+    pub buy_in_rules: BuyInRules,
+    #[serde(default)]
+    pub odd_chip_rule: OddChipRule,
+    /// Zero in snapshots written before chip denominations existed; read as 1
+    #[serde(default)]
+    pub chip_unit: u64,
+    pub winners: Vec<usize>,
+    pub showdown_categories: Vec<Option<Category>>,
+    /// Outcome of the hand once it is over; absent in snapshots written
+    /// before it was saved
+    #[serde(default)]
+    pub showdown: Option<ShowdownResult>,
+    pub history: Vec<HandHistoryEntry>,
+    /// Session results; absent in snapshots written before the ledger existed
+    #[serde(default)]
+    pub ledger: Option<SessionLedger>,
+    /// Chips at the table across stacks and pot; absent in snapshots written
+    /// before it was saved, when it is taken from the restored stacks and pot
+    #[serde(default)]
+    pub chips_in_play: Option<u64>,
+    #[serde(default)]
+    pub invariant_checks: bool,
+    /// Problems the engine repaired or swallowed this hand
+    #[serde(default)]
+    pub violations: Vec<InvariantViolation>,
+}
+
+impl GameSnapshot {
+    /// Encode as pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String, SnapshotError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Decode from JSON, rejecting snapshots written by a newer format.
+    pub fn from_json(json: &str) -> Result<Self, SnapshotError> {
+        #[derive(Deserialize)]
+        struct Header {
+            version: u32,
+        }
+        let header: Header = serde_json::from_str(json)?;
+        if header.version != SNAPSHOT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion {
+                found: header.version,
+                supported: SNAPSHOT_VERSION,
+            });
+        }
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Write the snapshot to `path` as JSON.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), SnapshotError> {
+        std::fs::write(path, self.to_json()?)?;
+        Ok(())
+    }
+
+    /// Read a snapshot previously written with [`GameSnapshot::save`].
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, SnapshotError> {
+        let json = std::fs::read_to_string(path)?;
+        Self::from_json(&json)
+    }
+
+    fn validate(&self) -> Result<(), SnapshotError> {
+        if self.version != SNAPSHOT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion {
+                found: self.version,
+                supported: SNAPSHOT_VERSION,
+            });
+        }
+        let n = self.players.len();
+        let seat_ok = |seat: usize| n == 0 || seat < n;
+        if !seat_ok(self.dealer) || !seat_ok(self.current) {
+            return Err(SnapshotError::Invalid("seat index out of range".to_string()));
+        }
+        let optional_seats =
+            [self.last_raiser, self.sb_pos, self.bb_pos, self.sb_slot, self.bb_slot];
+        if optional_seats.iter().flatten().chain(&self.winners).any(|&s| s >= n) {
+            return Err(SnapshotError::Invalid("seat index out of range".to_string()));
+        }
+        if self.history.iter().any(|e| e.seat >= n) {
+            return Err(SnapshotError::Invalid("history seat out of range".to_string()));
+        }
+        if self.showdown_categories.len() != n {
+            return Err(SnapshotError::Invalid(format!(
+                "expected {n} showdown categories, got {}",
+                self.showdown_categories.len()
+            )));
+        }
+        let second = self.second_board.as_deref().unwrap_or_default();
+        if let Some(len) = [self.board.len(), second.len()].into_iter().find(|&l| l > 5) {
+            return Err(SnapshotError::Invalid(format!("{len} board cards")));
+        }
+        let mut seen = HashSet::new();
+        let holes = self.players.iter().filter_map(|p| p.hole.as_ref()).flat_map(|h| h.as_slice());
+        for &c in self.deck.iter().chain(&self.board).chain(second).chain(holes) {
+            if !seen.insert(c) {
+                return Err(SnapshotError::DuplicateCard(c));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Game {
+    /// Capture the full game state as a serializable snapshot.
+    pub fn snapshot(&self) -> GameSnapshot {
+        GameSnapshot {
+            version: SNAPSHOT_VERSION,
+            small_blind: self.small_blind,
+            big_blind: self.big_blind,
+            starting_stack: self.starting_stack,
+            seed: self.seed,
+            hand_number: self.hand_number,
+            hand_seed: self.hand_seed,
+            deck: self.deck.draw_order(),
+            board: self.board.as_slice().to_vec(),
+            second_board: self.second_board.as_ref().map(|b| b.as_slice().to_vec()),
+            double_board: self.double_board,
+            variant: self.variant.name().to_string(),
+            next_variant: self.next_variant.as_ref().map(|v| v.name().to_string()),
+            players: self
+                .players
+                .iter()
+                .map(|p| PlayerSnapshot {
+                    name: p.name.clone(),
+                    stack: p.stack,
+                    bet: p.bet,
+                    contributed: p.contributed,
+                    dead: p.dead,
+                    status: p.status,
+                    hole: p.hole,
+                    last_action: p.last_action.clone(),
+                    missed_blind: p.missed_blind,
+                    acted_at: p.acted_at,
+                })
+                .collect(),
+            pot: self.pot,
+            dealer: self.dealer,
+            current: self.current,
+            street: self.street,
+            current_bet: self.current_bet,
+            min_raise: self.min_raise,
+            last_raiser: self.last_raiser,
+            sb_pos: self.sb_pos,
+            bb_pos: self.bb_pos,
+            sb_slot: self.sb_slot,
+            bb_slot: self.bb_slot,
+            button_rule: self.button_rule,
+            bet_limit: self.bet_limit,
+            buy_in_rules: self.buy_in_rules,
+            odd_chip_rule: self.odd_chip_rule,
+            chip_unit: self.chip_unit,
+            winners: self.winners.clone(),
+            showdown_categories: self.showdown_categories.clone(),
+            showdown: self.showdown.clone(),
+            history: self.hand_history.clone(),
+            ledger: Some(self.ledger.clone()),
+            chips_in_play: Some(self.chips_in_play),
+            invariant_checks: self.invariant_checks,
+            violations: self.violations.clone(),
+        }
+    }
+
+    /// Rebuild a game from a snapshot; the result continues exactly where the
+    /// snapshot was taken.
+    pub fn restore(snapshot: GameSnapshot) -> Result<Game, SnapshotError> {
+        snapshot.validate()?;
+        let variant = match snapshot.variant.as_str() {
+            "" => Arc::new(Holdem),
+            name => variant_by_name(name)?,
+        };
+        let next_variant = snapshot.next_variant.as_deref().map(variant_by_name).transpose()?;
+        if let Some(hole) = snapshot
+            .players
+            .iter()
+            .filter_map(|p| p.hole)
+            .find(|h| h.as_slice().len() != variant.hole_cards())
+        {
+            return Err(SnapshotError::Invalid(format!(
+                "{} deals {} hole cards, got {}",
+                variant.name(),
+                variant.hole_cards(),
+                hole.as_slice().len()
+            )));
+        }
+        let players: Vec<Player> = snapshot
+            .players
+            .into_iter()
+            .map(|p| Player {
+                name: p.name,
+                stack: p.stack,
+                bet: p.bet,
+                contributed: p.contributed,
+                dead: p.dead,
+                status: p.status,
+                hole: p.hole,
+                last_action: p.last_action,
+                missed_blind: p.missed_blind,
+                acted_at: p.acted_at,
+            })
+            .collect();
+
+        let mut game = Game::with_seed(
+            players.len(),
+            snapshot.starting_stack,
+            snapshot.small_blind,
+            snapshot.big_blind,
+            snapshot.seed,
+        );
+        game.hand_number = snapshot.hand_number;
+        game.hand_seed = snapshot.hand_seed;
+        game.deck = Deck::from_draw_order(snapshot.deck)?;
+        game.board = Board::new(snapshot.board);
+        game.second_board = snapshot.second_board.map(Board::new);
+        game.double_board = snapshot.double_board;
+        game.variant = variant;
+        game.next_variant = next_variant;
+        game.players = players;
+        game.pot = snapshot.pot;
+        game.dealer = snapshot.dealer;
+        game.current = snapshot.current;
+        game.street = snapshot.street;
+        game.current_bet = snapshot.current_bet;
+        game.min_raise = snapshot.min_raise;
+        game.last_raiser = snapshot.last_raiser;
+        game.sb_pos = snapshot.sb_pos;
+        game.bb_pos = snapshot.bb_pos;
+        game.sb_slot = snapshot.sb_slot;
+        // Snapshots from before bomb pots tracked the big blind only by bb_pos.
+        game.bb_slot = snapshot.bb_slot.or(snapshot.bb_pos);
+        game.button_rule = snapshot.button_rule;
+        game.bet_limit = snapshot.bet_limit;
+        game.buy_in_rules = snapshot.buy_in_rules;
+        game.odd_chip_rule = snapshot.odd_chip_rule;
+        game.set_chip_unit(snapshot.chip_unit);
+        game.winners = snapshot.winners;
+        game.showdown_categories = snapshot.showdown_categories;
+        game.showdown = snapshot.showdown;
+        game.hand_history = snapshot.history;
+        let found = game.players.iter().map(|p| p.stack).sum::<u64>() + game.pot;
+        if let Some(expected) = snapshot.chips_in_play.filter(|&e| e != found) {
+            return Err(SnapshotError::Invalid(format!(
+                "{found} chips across stacks and pot, expected {expected}"
+            )));
+        }
+        game.chips_in_play = found;
+        game.invariant_checks = snapshot.invariant_checks;
+        game.violations = snapshot.violations;
+        game.ledger = snapshot.ledger.unwrap_or_else(|| SessionLedger::new(snapshot.big_blind));
+        Ok(game)
+    }
+}
+
+fn variant_by_name(name: &str) -> Result<Arc<dyn Variant>, SnapshotError> {
+    variants::by_name(name)
+        .ok_or_else(|| SnapshotError::Invalid(format!("unknown variant {name:?}")))
+}
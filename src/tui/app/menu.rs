@@ -1,23 +1,88 @@
 use crate::agents::{AgentTable, BotAgent, BotProfile, Difficulty, HumanAgent};
-use crate::game::Game;
+use crate::game::{BetLimit, BuyInRules, Game};
+use crate::variants::{Holdem, Omaha, ShortDeck, Variant, VariantId};
+use std::sync::Arc;
 
 use super::AppState;
 
+/// Game picked in the menu: a variant together with its betting structure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameMode {
+    NoLimitHoldem,
+    PotLimitOmaha,
+    ShortDeck,
+}
+
+impl GameMode {
+    const ALL: [GameMode; 3] =
+        [GameMode::NoLimitHoldem, GameMode::PotLimitOmaha, GameMode::ShortDeck];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            GameMode::NoLimitHoldem => "No-Limit Hold'em",
+            GameMode::PotLimitOmaha => "Pot-Limit Omaha",
+            GameMode::ShortDeck => "Short Deck",
+        }
+    }
+
+    /// The mode a game is being played in, if the menu offers it.
+    pub fn of(game: &Game) -> Option<Self> {
+        match (game.variant().id(), game.bet_limit()) {
+            (VariantId::Holdem, BetLimit::NoLimit) => Some(GameMode::NoLimitHoldem),
+            (VariantId::Omaha, BetLimit::PotLimit) => Some(GameMode::PotLimitOmaha),
+            (VariantId::ShortDeck, BetLimit::NoLimit) => Some(GameMode::ShortDeck),
+            _ => None,
+        }
+    }
+
+    /// Name of the game being played: its mode's label, or the betting
+    /// structure and variant name for games the menu does not offer.
+    pub fn title(game: &Game) -> String {
+        if let Some(mode) = Self::of(game) {
+            return mode.label().to_string();
+        }
+        let limit = match game.bet_limit() {
+            BetLimit::NoLimit => "No-Limit",
+            BetLimit::PotLimit => "Pot-Limit",
+        };
+        format!("{limit} {}", game.variant().name())
+    }
+
+    fn apply(self, game: &mut Game) {
+        let (variant, limit): (Arc<dyn Variant>, _) = match self {
+            GameMode::NoLimitHoldem => (Arc::new(Holdem), BetLimit::NoLimit),
+            GameMode::PotLimitOmaha => (Arc::new(Omaha), BetLimit::PotLimit),
+            GameMode::ShortDeck => (Arc::new(ShortDeck), BetLimit::NoLimit),
+        };
+        game.set_variant(variant).expect("the menu's tables are small enough for every deck");
+        game.set_bet_limit(limit);
+    }
+
+    fn step(self, delta: usize) -> Self {
+        let i = Self::ALL.iter().position(|&m| m == self).unwrap_or(0);
+        Self::ALL[(i + delta) % Self::ALL.len()]
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 enum MenuItem {
+    Game,
     Players,
     StartingStack,
     SmallBlind,
     BigBlind,
+    DoubleBoard,
     BotDifficulty,
     BotDelayMs,
 }
 
-const MENU_ITEMS: [MenuItem; 6] = [
+const MENU_ITEMS: [MenuItem; 8] = [
+    MenuItem::Game,
     MenuItem::Players,
     MenuItem::StartingStack,
     MenuItem::SmallBlind,
     MenuItem::BigBlind,
+    MenuItem::DoubleBoard,
     MenuItem::BotDifficulty,
     MenuItem::BotDelayMs,
 ];
@@ -25,10 +90,14 @@ const MENU_ITEMS: [MenuItem; 6] = [
 impl MenuItem {
     fn display(self, app: &AppState) -> String {
         match self {
+            MenuItem::Game => format!("Game: {}", app.cfg_game.label()),
             MenuItem::Players => format!("Players: {}", app.cfg_num_players),
             MenuItem::StartingStack => format!("Starting Stack: ${}", app.cfg_starting_stack),
             MenuItem::SmallBlind => format!("Small Blind: {}", app.cfg_small_blind),
             MenuItem::BigBlind => format!("Big Blind: {}", app.cfg_big_blind),
+            MenuItem::DoubleBoard => {
+                format!("Double Board: {}", if app.cfg_double_board { "On" } else { "Off" })
+            }
             MenuItem::BotDifficulty => {
                 format!("Bot Difficulty: {}", AppState::difficulty_label(app.cfg_bot_difficulty))
             }
@@ -38,6 +107,7 @@ impl MenuItem {
 
     fn inc(self, app: &mut AppState) {
         match self {
+            MenuItem::Game => app.cfg_game = app.cfg_game.step(1),
             MenuItem::Players => {
                 if app.cfg_num_players < 9 {
                     app.cfg_num_players += 1;
@@ -55,6 +125,7 @@ impl MenuItem {
             MenuItem::BigBlind => {
                 app.cfg_big_blind = app.cfg_big_blind.saturating_add(1);
             }
+            MenuItem::DoubleBoard => app.cfg_double_board = !app.cfg_double_board,
             MenuItem::BotDelayMs => {
                 app.cfg_bot_delay_ms = app.cfg_bot_delay_ms.saturating_add(100);
             }
@@ -71,6 +142,7 @@ impl MenuItem {
 
     fn dec(self, app: &mut AppState) {
         match self {
+            MenuItem::Game => app.cfg_game = app.cfg_game.step(GameMode::ALL.len() - 1),
             MenuItem::Players => {
                 if app.cfg_num_players > 2 {
                     app.cfg_num_players -= 1;
@@ -92,6 +164,7 @@ impl MenuItem {
                     }
                 }
             }
+            MenuItem::DoubleBoard => app.cfg_double_board = !app.cfg_double_board,
             MenuItem::BotDelayMs => {
                 app.cfg_bot_delay_ms = app.cfg_bot_delay_ms.saturating_sub(100);
             }
@@ -115,6 +188,7 @@ impl AppState {
     pub fn toggle_menu(&mut self) {
         self.close_help();
         self.close_history();
+        self.close_chart();
         self.scene = match self.scene {
             super::Scene::Menu => super::Scene::Table,
             _ => {
@@ -128,11 +202,14 @@ impl AppState {
     pub fn open_menu(&mut self) {
         self.close_help();
         self.close_history();
+        self.close_chart();
         self.menu_index = 0;
+        self.cfg_game = GameMode::of(&self.game).unwrap_or(self.cfg_game);
         self.cfg_num_players = self.game.players.len();
         self.cfg_starting_stack = self.game.starting_stack;
         self.cfg_small_blind = self.game.small_blind;
         self.cfg_big_blind = self.game.big_blind;
+        self.cfg_double_board = self.game.double_board;
         self.cfg_bot_delay_ms = self.bot_delay_ms;
         self.cfg_bot_difficulty = self.bot_default_difficulty;
         self.scene = super::Scene::Menu;
@@ -161,6 +238,11 @@ impl AppState {
             self.cfg_small_blind,
             self.cfg_big_blind,
         );
+        self.cfg_game.apply(&mut self.game);
+        self.game
+            .set_double_board(self.cfg_double_board)
+            .expect("the menu's tables are small enough for every deck");
+        self.game.set_buy_in_rules(BuyInRules::cash(self.cfg_starting_stack));
         self.focus = 0;
         self.agents = AgentTable::for_seats(self.cfg_num_players);
         self.agents.set_min_action_delay_ms(150);
@@ -1,4 +1,5 @@
 mod menu;
 mod state;
 
+pub use menu::GameMode;
 pub use state::{AppState, InputAction, Scene};
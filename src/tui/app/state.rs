@@ -1,7 +1,10 @@
 use crate::agents::{Action, AgentKind, AgentTable, BotAgent, BotProfile, Difficulty};
-use crate::game::Game;
+use crate::game::{Game, Street};
+use crate::push_fold::{ChartKind, MAX_BEHIND};
 use std::time::{Duration, Instant};
 
+use super::menu::GameMode;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum Scene {
@@ -40,6 +43,11 @@ pub enum InputAction {
     FocusNext,
     FocusPrev,
     FocusSeat(usize),
+    Rebuy,
+    ToggleChart,
+    ChartMoreBehind,
+    ChartFewerBehind,
+    ChartSwitchKind,
 }
 
 #[derive(Debug)]
@@ -54,10 +62,12 @@ pub struct AppState {
     pub agents: AgentTable,
     // Menu config being edited
     pub menu_index: usize,
+    pub cfg_game: GameMode,
     pub cfg_num_players: usize,
     pub cfg_starting_stack: u64,
     pub cfg_small_blind: u64,
     pub cfg_big_blind: u64,
+    pub cfg_double_board: bool,
     pub cfg_bot_delay_ms: u64,
     pub bot_delay_ms: u64,
     pub cfg_bot_difficulty: Difficulty,
@@ -67,6 +77,9 @@ pub struct AppState {
     help_open: bool,
     history_open: bool,
     history_offset: usize,
+    chart_open: bool,
+    chart_behind: usize,
+    chart_kind: ChartKind,
     amount_entry: Option<String>,
     amount_entry_error: Option<String>,
     action_error: Option<String>,
@@ -86,10 +99,12 @@ impl Default for AppState {
             focus: 0,
             agents: AgentTable::for_seats(5),
             menu_index: 0,
+            cfg_game: GameMode::NoLimitHoldem,
             cfg_num_players: 5,
             cfg_starting_stack: 1000,
             cfg_small_blind: 5,
             cfg_big_blind: 10,
+            cfg_double_board: false,
             cfg_bot_delay_ms: default_delay,
             bot_delay_ms: default_delay,
             cfg_bot_difficulty: default_difficulty,
@@ -99,6 +114,9 @@ impl Default for AppState {
             help_open: false,
             history_open: false,
             history_offset: 0,
+            chart_open: false,
+            chart_behind: 1,
+            chart_kind: ChartKind::Push,
             amount_entry: None,
             amount_entry_error: None,
             action_error: None,
@@ -166,6 +184,16 @@ impl AppState {
         self.history_offset
     }
 
+    pub fn chart_open(&self) -> bool {
+        self.chart_open
+    }
+
+    /// Push/fold chart shown in the chart overlay: its kind and the number
+    /// of opponents behind the pusher.
+    pub fn chart_selection(&self) -> (ChartKind, usize) {
+        (self.chart_kind, self.chart_behind)
+    }
+
     pub(crate) fn close_help(&mut self) {
         self.help_open = false;
     }
@@ -174,6 +202,10 @@ impl AppState {
         self.history_open = false;
     }
 
+    pub(crate) fn close_chart(&mut self) {
+        self.chart_open = false;
+    }
+
     pub fn bot_profile_label(&self, seat: usize) -> Option<&'static str> {
         if !matches!(self.agents.agent_kind(seat), Some(AgentKind::Bot)) {
             return None;
@@ -195,12 +227,10 @@ impl AppState {
         if !self.can_act_for_focus() {
             return false;
         }
-        let buf = if self.game.current_bet == 0 {
-            self.game.big_blind.max(1).to_string()
-        } else {
-            (self.game.current_bet + self.game.min_raise).to_string()
+        let Some(range) = self.game.legal_actions().and_then(|l| l.bet.or(l.raise)) else {
+            return false;
         };
-        self.amount_entry = Some(buf);
+        self.amount_entry = Some(range.min.to_string());
         self.amount_entry_error = None;
         true
     }
@@ -243,30 +273,30 @@ impl AppState {
                 return false;
             }
         };
-        let max_total =
-            self.game.players.get(self.game.current).map(|p| p.bet + p.stack).unwrap_or(0);
-        if self.game.current_bet == 0 {
-            let min_bet = self.game.big_blind.max(1);
-            if amount < min_bet && amount < max_total {
-                self.amount_entry_error = Some(format!("Min bet is {min_bet}"));
-                return false;
-            }
-            if self.queue_action(Action::Bet(amount)) {
-                self.amount_entry = None;
-                self.amount_entry_error = None;
-                return true;
-            }
-        } else {
-            let min_target = self.game.current_bet + self.game.min_raise;
-            if amount < min_target && amount < max_total {
-                self.amount_entry_error = Some(format!("Min raise is {min_target}"));
+        let Some(legal) = self.game.legal_actions() else {
+            self.amount_entry_error = Some("Action not allowed".to_string());
+            return false;
+        };
+        let (range, action, label) = match (legal.bet, legal.raise) {
+            (Some(range), _) => (range, Action::Bet(amount), "bet"),
+            (None, Some(range)) => (range, Action::RaiseTo(amount), "raise"),
+            (None, None) => {
+                self.amount_entry_error = Some("Action not allowed".to_string());
                 return false;
             }
-            if self.queue_action(Action::RaiseTo(amount)) {
-                self.amount_entry = None;
-                self.amount_entry_error = None;
-                return true;
-            }
+        };
+        if amount < range.min {
+            self.amount_entry_error = Some(format!("Min {label} is {}", range.min));
+            return false;
+        }
+        if amount > range.max {
+            self.amount_entry_error = Some(format!("Max {label} is {}", range.max));
+            return false;
+        }
+        if self.queue_action(action) {
+            self.amount_entry = None;
+            self.amount_entry_error = None;
+            return true;
         }
         self.amount_entry_error = Some("Action not allowed".to_string());
         false
@@ -286,6 +316,7 @@ impl AppState {
             InputAction::ToggleHelp => {
                 if self.scene == Scene::Table {
                     self.history_open = false;
+                    self.chart_open = false;
                     self.help_open = !self.help_open;
                 }
                 false
@@ -293,6 +324,7 @@ impl AppState {
             InputAction::ToggleHistory => {
                 if self.scene == Scene::Table {
                     self.help_open = false;
+                    self.chart_open = false;
                     if !self.history_open {
                         self.history_offset = 0;
                     }
@@ -300,6 +332,35 @@ impl AppState {
                 }
                 false
             }
+            InputAction::ToggleChart => {
+                if self.scene == Scene::Table {
+                    self.help_open = false;
+                    self.history_open = false;
+                    self.chart_open = !self.chart_open;
+                }
+                false
+            }
+            InputAction::ChartMoreBehind => {
+                if self.chart_open {
+                    self.chart_behind = (self.chart_behind + 1).min(MAX_BEHIND);
+                }
+                false
+            }
+            InputAction::ChartFewerBehind => {
+                if self.chart_open {
+                    self.chart_behind = self.chart_behind.saturating_sub(1).max(1);
+                }
+                false
+            }
+            InputAction::ChartSwitchKind => {
+                if self.chart_open {
+                    self.chart_kind = match self.chart_kind {
+                        ChartKind::Push => ChartKind::Call,
+                        _ => ChartKind::Push,
+                    };
+                }
+                false
+            }
             InputAction::HistoryUp => {
                 if self.scene == Scene::Table && self.history_open {
                     let max_offset =
@@ -406,6 +467,7 @@ impl AppState {
                 }
                 false
             }
+            InputAction::Rebuy => self.scene == Scene::Table && self.rebuy_focus(),
         }
     }
 
@@ -413,12 +475,42 @@ impl AppState {
         if self.hand_started && !matches!(self.game.street, crate::game::Street::Showdown) {
             return;
         }
-        self.game.new_hand();
+        if let Err(err) = self.game.new_hand() {
+            self.action_error = Some(err.to_string());
+            self.action_error_at = Some(Instant::now());
+            return;
+        }
         self.hand_started = true;
         self.history_offset = 0;
         self.clear_action_error();
     }
 
+    /// Rebuy a busted focus seat, or top it up, to the table's maximum buy-in.
+    pub fn rebuy_focus(&mut self) -> bool {
+        let seat = self.focus;
+        let Some(player) = self.game.players.get(seat) else {
+            return false;
+        };
+        let max = self.game.buy_in_rules().max_buy_in.unwrap_or(self.game.starting_stack());
+        let stack = player.stack();
+        let result = if stack == 0 {
+            self.game.rebuy(seat, max)
+        } else {
+            self.game.top_up(seat, max.saturating_sub(stack))
+        };
+        match result {
+            Ok(()) => {
+                self.clear_action_error();
+                true
+            }
+            Err(err) => {
+                self.action_error = Some(err.to_string());
+                self.action_error_at = Some(Instant::now());
+                false
+            }
+        }
+    }
+
     pub fn focus_next(&mut self) {
         if self.game.players.is_empty() {
             return;
@@ -477,6 +569,12 @@ impl AppState {
             }
         }
         self.agents.ensure_len(self.game.players.len());
+        if self.game.street() == Street::Showdown {
+            if let Err(err) = self.agents.on_showdown(&mut self.game) {
+                self.action_error = Some(err.to_string());
+                self.action_error_at = Some(Instant::now());
+            }
+        }
         match self.agents.on_turn(&mut self.game) {
             Ok(true) => self.clear_action_error(),
             Ok(false) => {}
@@ -742,4 +840,43 @@ mod tests {
         app.handle_input(InputAction::HistoryUp);
         // Offset should decrease or stay at 0
     }
+
+    #[test]
+    fn rebuy_refills_the_focus_seat_between_hands() {
+        let mut app = AppState::default();
+        app.apply_menu();
+        app.game.players[0].stack = 0;
+        app.game.chips_in_play -= 1000;
+        assert!(app.handle_input(InputAction::Rebuy));
+        assert_eq!(app.game.players[0].stack(), 1000);
+        assert!(!app.handle_input(InputAction::Rebuy), "already at the maximum buy-in");
+        assert!(app.action_error().is_some());
+
+        app.game.players[0].stack = 400;
+        app.game.chips_in_play -= 600;
+        assert!(app.handle_input(InputAction::Rebuy));
+        assert_eq!(app.game.players[0].stack(), 1000);
+        assert_eq!(app.game.check_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn game_mode_matches_variant_and_bet_limit() {
+        use crate::game::BetLimit;
+        use crate::variants::{Omaha, ShortDeck};
+        use std::sync::Arc;
+
+        let mut game = Game::new(3, 1000, 5, 10);
+        assert_eq!(GameMode::of(&game), Some(GameMode::NoLimitHoldem));
+        game.set_variant(Arc::new(ShortDeck)).unwrap();
+        assert_eq!(GameMode::of(&game), Some(GameMode::ShortDeck));
+
+        game.set_bet_limit(BetLimit::PotLimit);
+        assert_eq!(GameMode::of(&game), None);
+        assert_eq!(GameMode::title(&game), "Pot-Limit Short Deck");
+
+        game.set_variant(Arc::new(Omaha)).unwrap();
+        assert_eq!(GameMode::title(&game), "Pot-Limit Omaha");
+        game.set_bet_limit(BetLimit::NoLimit);
+        assert_eq!(GameMode::title(&game), "No-Limit Omaha");
+    }
 }
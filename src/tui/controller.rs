@@ -31,9 +31,12 @@ pub fn run(
     Ok(())
 }
 
+// Key handlers keep side-effecting calls out of match guards.
+#[allow(clippy::collapsible_match)]
 fn handle_key(app: &mut AppState, code: KeyCode) -> bool {
     let help_toggle = matches!(code, KeyCode::Char('?'));
     let history_toggle = matches!(code, KeyCode::Char('h') | KeyCode::Char('H'));
+    let chart_toggle = matches!(code, KeyCode::Char('n') | KeyCode::Char('N'));
     if help_toggle {
         let _ = app.handle_input(InputAction::ToggleHelp);
         return false;
@@ -42,6 +45,10 @@ fn handle_key(app: &mut AppState, code: KeyCode) -> bool {
         let _ = app.handle_input(InputAction::ToggleHistory);
         return false;
     }
+    if chart_toggle {
+        let _ = app.handle_input(InputAction::ToggleChart);
+        return false;
+    }
     if app.help_open() {
         if matches!(code, KeyCode::Esc) {
             let _ = app.handle_input(InputAction::ToggleHelp);
@@ -63,6 +70,19 @@ fn handle_key(app: &mut AppState, code: KeyCode) -> bool {
         }
         return false;
     }
+    if app.chart_open() {
+        let action = match code {
+            KeyCode::Right => Some(InputAction::ChartMoreBehind),
+            KeyCode::Left => Some(InputAction::ChartFewerBehind),
+            KeyCode::Tab => Some(InputAction::ChartSwitchKind),
+            KeyCode::Esc => Some(InputAction::ToggleChart),
+            _ => None,
+        };
+        if let Some(action) = action {
+            let _ = app.handle_input(action);
+        }
+        return false;
+    }
     if app.amount_entry_active() {
         match code {
             KeyCode::Esc => {
@@ -129,6 +149,9 @@ fn handle_key(app: &mut AppState, code: KeyCode) -> bool {
             KeyCode::Char('d') | KeyCode::Char('D') => {
                 let _ = app.handle_input(InputAction::BotDifficultyNext);
             }
+            KeyCode::Char('u') | KeyCode::Char('U') => {
+                let _ = app.handle_input(InputAction::Rebuy);
+            }
             KeyCode::Char('f') | KeyCode::Char('F') => {
                 if app.handle_input(InputAction::Fold) {
                     app.agents_on_turn();
@@ -168,6 +191,7 @@ fn handle_key(app: &mut AppState, code: KeyCode) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::push_fold::ChartKind;
     use crate::tui::app::AppState;
 
     #[test]
@@ -214,6 +238,25 @@ mod tests {
         assert!(app.history_open());
     }
 
+    #[test]
+    fn chart_toggle_selects_and_closes() {
+        let mut app = AppState::default();
+        app.scene = Scene::Table;
+        assert!(!handle_key(&mut app, KeyCode::Char('n')));
+        assert!(app.chart_open());
+        assert_eq!(app.chart_selection(), (ChartKind::Push, 1));
+
+        handle_key(&mut app, KeyCode::Right);
+        handle_key(&mut app, KeyCode::Right);
+        handle_key(&mut app, KeyCode::Tab);
+        assert_eq!(app.chart_selection(), (ChartKind::Call, 3));
+        // Chart keys do not act at the table
+        assert!(!handle_key(&mut app, KeyCode::Char('f')));
+
+        handle_key(&mut app, KeyCode::Esc);
+        assert!(!app.chart_open());
+    }
+
     #[test]
     fn help_blocks_other_input() {
         let mut app = AppState::default();
@@ -1,6 +1,8 @@
 use crate::cards::Card;
 use crate::game::{PlayerStatus, Street};
-use crate::tui::app::AppState;
+use crate::hand_class::HandClass;
+use crate::push_fold::{call_chart, push_chart, ChartKind, MAX_CHART_BB};
+use crate::tui::app::{AppState, GameMode};
 use ratatui::prelude::*;
 use ratatui::widgets::*;
 
@@ -13,12 +15,18 @@ pub(super) fn draw_table(f: &mut Frame, app: &AppState) {
     let header_height = header_lines_count + 2;
     let status_lines: u16 = 2;
     let status_height: u16 = status_lines + 2; // content + borders
+    let mut boards = vec![app.game.board().as_slice()];
+    if let Some(second) = app.game.second_board() {
+        boards.push(second.as_slice());
+    }
+    // One 3-row card strip per board plus borders
+    let board_height = boards.len() as u16 * 3 + 2;
 
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(header_height), // header
-            Constraint::Length(5),             // board
+            Constraint::Length(board_height),  // board(s)
             Constraint::Min(3),                // seats
             Constraint::Length(status_height), // status bar
         ])
@@ -34,43 +42,45 @@ pub(super) fn draw_table(f: &mut Frame, app: &AppState) {
         pot_line(&app.game).unwrap_or_default(),
     )));
     header_lines.push(Line::from(format!(
-        "Bet: {}   MinRaise: {}   ToCall: {}",
+        "Bet: {}   MinRaise: {}   ToCall: {}   Hand #{} Seed {:016x}",
         app.game.current_bet(),
         app.game.min_raise(),
-        app.game.to_call(app.focus)
+        app.game.to_call(app.focus),
+        app.game.hand_number(),
+        app.game.hand_seed()
     )));
-    let header = Paragraph::new(header_lines)
-        .block(Block::default().title("poker-rs").borders(Borders::ALL));
+    let title = format!("poker-rs — {}", GameMode::title(&app.game));
+    let header =
+        Paragraph::new(header_lines).block(Block::default().title(title).borders(Borders::ALL));
     f.render_widget(header, chunks[0]);
 
-    // Board (5 slots)
+    // Board (5 slots per board, one row each)
     let board_block =
         Block::default().title(format!("Board — {:?}", app.game.street())).borders(Borders::ALL);
     let board_area = chunks[1];
     let board_inner = inner(board_area);
-    let board_cards = app.game.board().as_slice();
-    let card_width = board_inner.width.saturating_sub(2) / 5;
-    let board_chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Length(card_width),
-            Constraint::Length(card_width),
-            Constraint::Length(card_width),
-            Constraint::Length(card_width),
-            Constraint::Length(card_width),
-        ])
+    let board_rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Length(3); boards.len()])
         .split(board_inner);
+    let card_width = board_inner.width.saturating_sub(2) / 5;
     f.render_widget(board_block, board_area);
-    for i in 0..5 {
-        let highlight = (matches!(app.game.street(), Street::Flop) && i < 3)
-            || (matches!(app.game.street(), Street::Turn) && i == 3)
-            || (matches!(app.game.street(), Street::River) && i == 4);
-        render_card_widget(
-            f,
-            board_chunks[i],
-            board_cards.get(i).copied(),
-            if highlight { Some(Color::Yellow) } else { None },
-        );
+    for (board_cards, row) in boards.iter().zip(board_rows.iter()) {
+        let board_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(card_width); 5])
+            .split(*row);
+        for i in 0..5 {
+            let highlight = (matches!(app.game.street(), Street::Flop) && i < 3)
+                || (matches!(app.game.street(), Street::Turn) && i == 3)
+                || (matches!(app.game.street(), Street::River) && i == 4);
+            render_card_widget(
+                f,
+                board_chunks[i],
+                board_cards.get(i).copied(),
+                if highlight { Some(Color::Yellow) } else { None },
+            );
+        }
     }
 
     // Seats ring layout approximation (top row and bottom row mimic circle)
@@ -137,21 +147,15 @@ pub(super) fn draw_table(f: &mut Frame, app: &AppState) {
         )));
     }
 
-    let (can_act, to_call, stack, current_bet) = if app.game.players().is_empty() {
-        (false, 0, 0, app.game.current_bet())
+    let legal = if app.hand_started && app.focus == app.game.current() {
+        app.game.legal_actions()
     } else {
-        let idx = app.focus.min(app.game.players().len().saturating_sub(1));
-        let p = &app.game.players()[idx];
-        let can_act = app.hand_started
-            && app.focus == app.game.current()
-            && !matches!(app.game.street(), Street::Showdown)
-            && matches!(p.status(), PlayerStatus::Active);
-        (can_act, app.game.to_call(app.game.current()), p.stack(), app.game.current_bet())
+        None
     };
-    let fold_enabled = can_act && to_call > 0;
-    let call_enabled = can_act && stack > 0;
-    let bet_enabled = can_act && current_bet == 0 && stack > 0;
-    let raise_enabled = can_act && current_bet > 0 && stack > to_call;
+    let fold_enabled = legal.is_some_and(|l| l.can_call);
+    let call_enabled = legal.is_some();
+    let bet_enabled = legal.is_some_and(|l| l.bet.is_some());
+    let raise_enabled = legal.is_some_and(|l| l.raise.is_some());
     let action_style = |enabled: bool| {
         if enabled {
             Style::default().add_modifier(Modifier::BOLD)
@@ -173,7 +177,7 @@ pub(super) fn draw_table(f: &mut Frame, app: &AppState) {
         left_info.push(action_line);
     }
 
-    let right_keys = vec![Line::from(""), Line::from("? help • H history • M menu")];
+    let right_keys = vec![Line::from(""), Line::from("? help • H history • N chart • M menu")];
     let left_para = Paragraph::new(left_info).wrap(Wrap { trim: true });
     let right_para =
         Paragraph::new(right_keys).wrap(Wrap { trim: true }).alignment(Alignment::Right);
@@ -184,6 +188,8 @@ pub(super) fn draw_table(f: &mut Frame, app: &AppState) {
         draw_help(f);
     } else if app.history_open() {
         draw_history(f, app);
+    } else if app.chart_open() {
+        draw_chart(f, app);
     } else if app.amount_entry_active() {
         draw_amount_entry(f, app);
     }
@@ -199,11 +205,14 @@ fn draw_history(f: &mut Frame, app: &AppState) {
     } else {
         for entry in entries {
             let amount = entry.amount.map(|v| format!(" {v}")).unwrap_or_default();
+            let cards: String =
+                entry.cards.iter().flat_map(|h| h.as_slice()).map(|c| format!(" {c}")).collect();
             let line = format!(
-                "P{} {}{} [{:?}]",
+                "P{} {}{}{} [{:?}]",
                 entry.seat + 1,
                 entry.verb.label(),
                 amount,
+                cards,
                 entry.street
             );
             lines.push(Line::from(line));
@@ -220,6 +229,60 @@ fn draw_history(f: &mut Frame, app: &AppState) {
     f.render_widget(para, inner(area));
 }
 
+fn draw_chart(f: &mut Frame, app: &AppState) {
+    let area = centered_rect(90, 80, f.area());
+    let (kind, behind) = app.chart_selection();
+    let (chart, title) = match kind {
+        ChartKind::Push => (push_chart(behind), format!("Push first in, {behind} behind")),
+        _ => (call_chart(behind), format!("Big blind call vs push with {behind} behind")),
+    };
+    let block = Block::default().title(title).borders(Borders::ALL);
+    let mut lines: Vec<Line> = Vec::new();
+    let focus = app.game.players().get(app.focus);
+    let big_blind = app.game.big_blind().max(1);
+    let stack_bb = focus.map(|p| (p.stack() + p.contributed()) as f64 / big_blind as f64);
+    if let Some(chart) = chart {
+        for row in 0..13 {
+            let cells: Vec<Span> = (0..13)
+                .map(|col| {
+                    let class = HandClass::from_index(row * 13 + col).expect("grid cell");
+                    let max_bb = chart.max_bb(class);
+                    let value = if max_bb >= MAX_CHART_BB {
+                        format!("{MAX_CHART_BB:.0}+")
+                    } else if max_bb == 0.0 {
+                        "-".to_string()
+                    } else {
+                        format!("{max_bb:.1}")
+                    };
+                    let text = format!("{:<4}{:>5} ", class.to_string(), value);
+                    match stack_bb {
+                        Some(bb) if bb <= max_bb => {
+                            Span::styled(text, Style::default().fg(Color::Green))
+                        }
+                        _ => Span::styled(text, Style::default().add_modifier(Modifier::DIM)),
+                    }
+                })
+                .collect();
+            lines.push(Line::from(cells));
+        }
+    }
+    lines.push(Line::from(""));
+    if let Some(bb) = stack_bb {
+        lines.push(Line::from(format!(
+            "Largest stack in BB for each hand; green: in range for P{} at {bb:.1} BB",
+            app.focus + 1
+        )));
+    }
+    lines.push(Line::from(Span::styled(
+        "Left/Right players behind • Tab push/call • Close: N or Esc",
+        Style::default().add_modifier(Modifier::DIM),
+    )));
+    let para = Paragraph::new(lines);
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+    f.render_widget(para, inner(area));
+}
+
 fn render_player_card(
     f: &mut Frame,
     seat_area: Rect,
@@ -256,6 +319,8 @@ fn render_player_card(
         PlayerStatus::Active => "Active",
         PlayerStatus::Folded => "Folded",
         PlayerStatus::AllIn => "All-in",
+        PlayerStatus::SittingOut => "Sitting out",
+        PlayerStatus::Empty => "Empty",
     };
     let dim = Style::default().add_modifier(Modifier::DIM);
     let make_line = |label: &str, value: Option<String>| -> Line {
@@ -273,11 +338,13 @@ fn render_player_card(
         None
     };
     let last_value = p.last_action().map(|s| s.to_string());
-    let category_value = if matches!(app.game.street(), Street::Showdown) {
-        app.game.showdown_categories().get(idx).and_then(|c| *c).map(|c| format!("{c:?}"))
-    } else {
-        None
-    };
+    let revealed = app.game.revealed_hole(idx).is_some();
+    let category_value =
+        if matches!(app.game.street(), Street::Showdown) && (revealed || idx == app.focus) {
+            app.game.showdown_categories().get(idx).and_then(|c| *c).map(|c| format!("{c:?}"))
+        } else {
+            None
+        };
     let mut lines: Vec<Line> = Vec::with_capacity(6);
     lines.push(Line::from(format!("Stack: ${}", p.stack())));
     lines.push(Line::from(format!("Bet: {}", p.bet())));
@@ -285,8 +352,8 @@ fn render_player_card(
     lines.push(make_line("Last: ", last_value));
     lines.push(make_line("Blind: ", blind_value));
     lines.push(make_line("Category: ", category_value));
-    let show_hole_cards = matches!(app.game.street(), Street::Showdown) || idx == app.focus;
-    if matches!(p.status(), PlayerStatus::Folded) {
+    let show_hole_cards = revealed || idx == app.focus;
+    if matches!(p.status(), PlayerStatus::Folded | PlayerStatus::SittingOut | PlayerStatus::Empty) {
         block = block.border_style(Style::default().fg(Color::DarkGray));
     } else if matches!(app.game.street(), Street::Showdown) && app.game.winners().contains(&idx) {
         block = block.border_style(Style::default().fg(Color::Green));
@@ -314,13 +381,29 @@ fn render_player_card(
     let para = Paragraph::new(lines).wrap(Wrap { trim: true });
     f.render_widget(para, text_area);
     if let (Some(h), Some(area)) = (p.hole(), cards_area) {
-        let cw = area.width.saturating_sub(2) / 2;
+        let cards = h.as_slice();
+        let cw = area.width.saturating_sub(2) / cards.len() as u16;
+        if cw < MIN_CARD_WIDTH {
+            // Too narrow for boxed cards (four Omaha cards on a full table):
+            // fall back to a single line of card glyphs.
+            let spans: Vec<Span> = cards
+                .iter()
+                .flat_map(|&c| {
+                    let (sg, style) = suit_glyph_and_style(c.suit());
+                    [Span::styled(format!("{}{}", rank_char(c.rank()), sg), style), Span::raw(" ")]
+                })
+                .collect();
+            let line_area = Rect { y: area.y + area.height.saturating_sub(1), height: 1, ..area };
+            f.render_widget(Paragraph::new(Line::from(spans)), line_area);
+            return;
+        }
         let card_chunks = Layout::default()
             .direction(Direction::Horizontal)
-            .constraints([Constraint::Length(cw), Constraint::Length(cw)])
+            .constraints(vec![Constraint::Length(cw); cards.len()])
             .split(area);
-        render_card_widget(f, card_chunks[0], Some(h.first()), Some(Color::Cyan));
-        render_card_widget(f, card_chunks[1], Some(h.second()), Some(Color::Cyan));
+        for (&card, &chunk) in cards.iter().zip(card_chunks.iter()) {
+            render_card_widget(f, chunk, Some(card), Some(Color::Cyan));
+        }
     }
 }
 
@@ -336,9 +419,11 @@ fn draw_help(f: &mut Frame) {
         Line::from("- B: bet min"),
         Line::from("- R: raise min"),
         Line::from("- D: cycle bot difficulty (focus)"),
+        Line::from("- U: rebuy / top up (focus, between hands)"),
         Line::from("- ] / [: focus next / prev"),
         Line::from("- 1-9: focus seat"),
         Line::from("- H: history"),
+        Line::from("- N: push/fold chart"),
         Line::from(""),
         Line::from(Span::styled("Amount Entry:", Style::default().add_modifier(Modifier::BOLD))),
         Line::from("- 0-9: edit amount"),
@@ -366,15 +451,12 @@ fn draw_help(f: &mut Frame) {
 fn draw_amount_entry(f: &mut Frame, app: &AppState) {
     let area = centered_rect(50, 30, f.area());
     let title = if app.game.current_bet() == 0 { "Bet Amount" } else { "Raise Amount" };
-    let min = if app.game.current_bet() == 0 {
-        app.game.big_blind().max(1)
-    } else {
-        app.game.current_bet() + app.game.min_raise()
-    };
+    let range = app.game.legal_actions().and_then(|l| l.bet.or(l.raise));
+    let (min, max) = range.map(|r| (r.min, r.max)).unwrap_or((0, 0));
     let current = app.amount_entry_text().unwrap_or("");
     let lines = vec![
         Line::from(format!("Current: {current}")),
-        Line::from(format!("Min: {min}")),
+        Line::from(format!("Min: {min}   Max: {max}")),
         Line::from("Digits to edit, Backspace to delete"),
         Line::from("+/- in BB steps, Enter submit, Esc cancel"),
     ];
@@ -401,6 +483,9 @@ fn pot_line(game: &crate::game::Game) -> Option<String> {
     for side in breakdown.sides {
         parts.push(side.to_string());
     }
+    if breakdown.uncalled > 0 {
+        parts.push(format!("{} uncalled", breakdown.uncalled));
+    }
     Some(format!("Pots: ${} = {}", game.pot(), parts.join(" + ")))
 }
 
@@ -433,6 +518,9 @@ fn rank_char(r: crate::cards::Rank) -> &'static str {
     }
 }
 
+/// Narrowest boxed card: two border columns around a rank and suit glyph.
+const MIN_CARD_WIDTH: u16 = 4;
+
 fn render_card_widget(
     f: &mut Frame,
     area: Rect,
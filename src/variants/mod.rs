@@ -1,3 +1,115 @@
 //! Poker variants beyond Texas Hold'em.
+//!
+//! A [`Variant`] decides the deck, how many hole cards each player gets and
+//! how hands are evaluated and ranked at showdown. [`Game`](crate::game::Game)
+//! runs any variant through the same betting engine and agent interface.
 
 pub mod omaha;
+pub mod short_deck;
+
+use crate::deck::Deck;
+use crate::evaluator::{evaluate_holdem, EvalError, Evaluation};
+use crate::hand::{Board, HoleCards};
+use core::cmp::Ordering;
+use core::fmt;
+use std::sync::Arc;
+
+pub use omaha::Omaha;
+pub use short_deck::ShortDeck;
+
+/// Rules that distinguish one flop game from another.
+///
+/// ```
+/// use poker_rs::variants::{Omaha, Variant};
+///
+/// assert_eq!(Omaha.hole_cards(), 4);
+/// assert_eq!(Omaha.deck().len(), 52);
+/// ```
+pub trait Variant: fmt::Debug + Send + Sync {
+    /// Display name, also used to identify the variant in snapshots.
+    fn name(&self) -> &'static str;
+
+    /// Which built-in game this is; [`VariantId::Custom`] for any other.
+    fn id(&self) -> VariantId {
+        VariantId::Custom
+    }
+
+    /// A fresh, unshuffled deck.
+    fn deck(&self) -> Deck;
+
+    /// Number of hole cards dealt to each player.
+    fn hole_cards(&self) -> usize;
+
+    /// Evaluate the best hand `hole` makes on a complete `board`.
+    fn evaluate(&self, hole: &HoleCards, board: &Board) -> Result<Evaluation, VariantError>;
+
+    /// Rank two evaluations from [`evaluate`](Self::evaluate); hands that
+    /// compare equal split the pot.
+    fn compare(&self, a: &Evaluation, b: &Evaluation) -> Ordering {
+        a.cmp(b)
+    }
+}
+
+/// Identifies a built-in variant, for code that adapts to the rules without
+/// comparing names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum VariantId {
+    Holdem,
+    Omaha,
+    ShortDeck,
+    /// A variant defined outside the crate
+    Custom,
+}
+
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum VariantError {
+    #[error(transparent)]
+    Eval(#[from] EvalError),
+    #[error(transparent)]
+    Omaha(#[from] omaha::OmahaError),
+}
+
+/// No-Limit Texas Hold'em: two hole cards, best five of seven.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Holdem;
+
+impl Variant for Holdem {
+    fn name(&self) -> &'static str {
+        "Hold'em"
+    }
+
+    fn id(&self) -> VariantId {
+        VariantId::Holdem
+    }
+
+    fn deck(&self) -> Deck {
+        Deck::standard()
+    }
+
+    fn hole_cards(&self) -> usize {
+        2
+    }
+
+    fn evaluate(&self, hole: &HoleCards, board: &Board) -> Result<Evaluation, VariantError> {
+        Ok(evaluate_holdem(hole, board)?)
+    }
+}
+
+/// Every variant that ships with the crate, Hold'em first.
+pub fn builtin() -> Vec<Arc<dyn Variant>> {
+    vec![Arc::new(Holdem), Arc::new(Omaha), Arc::new(ShortDeck)]
+}
+
+/// Look up a built-in variant by its [`name`](Variant::name).
+///
+/// ```
+/// use poker_rs::variants::by_name;
+///
+/// assert_eq!(by_name("Omaha").unwrap().hole_cards(), 4);
+/// assert!(by_name("Razz").is_none());
+/// ```
+pub fn by_name(name: &str) -> Option<Arc<dyn Variant>> {
+    builtin().into_iter().find(|v| v.name() == name)
+}
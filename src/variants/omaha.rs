@@ -1,6 +1,8 @@
 use crate::cards::{parse_cards, Card};
+use crate::deck::Deck;
 use crate::evaluator::{evaluate_five, Evaluation};
-use crate::hand::{Board, HandError};
+use crate::hand::{Board, HandError, HoleCards};
+use crate::variants::{Variant, VariantError, VariantId};
 use core::cmp::Ordering;
 use std::collections::HashSet;
 use std::str::FromStr;
@@ -37,6 +39,12 @@ impl OmahaHoleCards {
     }
 }
 
+impl From<OmahaHoleCards> for HoleCards {
+    fn from(hole: OmahaHoleCards) -> Self {
+        HoleCards::from_slice(&hole.as_array()).expect("Omaha hole cards are distinct")
+    }
+}
+
 impl FromStr for OmahaHoleCards {
     type Err = OmahaError;
 
@@ -183,3 +191,43 @@ pub fn compare_omaha(
     let vb = evaluate_omaha(b, board)?;
     Ok(va.cmp(&vb))
 }
+
+/// Omaha: four hole cards, of which exactly two must play.
+///
+/// ```
+/// use poker_rs::hand::{Board, HoleCards};
+/// use poker_rs::evaluator::Category;
+/// use poker_rs::variants::{Omaha, Variant};
+///
+/// // Four spades in hand but only two may play: no flush.
+/// let hole: HoleCards = "As Ks Qs Js".parse().unwrap();
+/// let board: Board = "2s 3s 8d 9c Th".parse().unwrap();
+/// assert_eq!(Omaha.evaluate(&hole, &board).unwrap().category, Category::Straight);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Omaha;
+
+impl Variant for Omaha {
+    fn name(&self) -> &'static str {
+        "Omaha"
+    }
+
+    fn id(&self) -> VariantId {
+        VariantId::Omaha
+    }
+
+    fn deck(&self) -> Deck {
+        Deck::standard()
+    }
+
+    fn hole_cards(&self) -> usize {
+        4
+    }
+
+    fn evaluate(&self, hole: &HoleCards, board: &Board) -> Result<Evaluation, VariantError> {
+        let &[a, b, c, d] = hole.as_slice() else {
+            return Err(OmahaError::HoleCount(hole.as_slice().len()).into());
+        };
+        Ok(evaluate_omaha(&OmahaHoleCards::try_new(a, b, c, d)?, board)?)
+    }
+}
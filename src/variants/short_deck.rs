@@ -0,0 +1,118 @@
+//! Short Deck (6+) Hold'em: sixes through aces, where a flush beats a full
+//! house and A-6-7-8-9 plays as the lowest straight.
+
+use crate::cards::{Card, Rank};
+use crate::deck::Deck;
+use crate::evaluator::{evaluate_five, Category, EvalError, Evaluation};
+use crate::hand::{validate_holdem, Board, HoleCards};
+use crate::variants::{Variant, VariantError, VariantId};
+use core::cmp::Ordering;
+
+/// Short Deck Hold'em on a 36-card deck.
+///
+/// ```
+/// use poker_rs::evaluator::Category;
+/// use poker_rs::hand::{Board, HoleCards};
+/// use poker_rs::variants::{ShortDeck, Variant};
+///
+/// let hole: HoleCards = "As 9d".parse().unwrap();
+/// let board: Board = "8c 7h 6s Kd Kh".parse().unwrap();
+/// let eval = ShortDeck.evaluate(&hole, &board).unwrap();
+/// assert_eq!(eval.category, Category::Straight);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ShortDeck;
+
+impl Variant for ShortDeck {
+    fn name(&self) -> &'static str {
+        "Short Deck"
+    }
+
+    fn id(&self) -> VariantId {
+        VariantId::ShortDeck
+    }
+
+    fn deck(&self) -> Deck {
+        Deck::short()
+    }
+
+    fn hole_cards(&self) -> usize {
+        2
+    }
+
+    fn evaluate(&self, hole: &HoleCards, board: &Board) -> Result<Evaluation, VariantError> {
+        use crate::evaluator::combinations::Combinations7Choose5;
+
+        validate_holdem(hole, board).map_err(EvalError::from)?;
+        let board_cards = board.as_slice();
+        if board_cards.len() < 5 {
+            return Err(EvalError::NotEnoughCards.into());
+        }
+        let [a, b] = hole.as_array().expect("validated as two hole cards");
+        let seven =
+            [a, b, board_cards[0], board_cards[1], board_cards[2], board_cards[3], board_cards[4]];
+
+        let mut best: Option<Evaluation> = None;
+        for indices in Combinations7Choose5::new() {
+            let hand = indices.map(|i| seven[i]);
+            let eval = evaluate_short_five(&hand);
+            if best.as_ref().map_or(true, |b| self.compare(&eval, b).is_gt()) {
+                best = Some(eval);
+            }
+        }
+        Ok(best.unwrap_or_else(|| evaluate_short_five(&[a, b, seven[2], seven[3], seven[4]])))
+    }
+
+    fn compare(&self, a: &Evaluation, b: &Evaluation) -> Ordering {
+        strength(a.category).cmp(&strength(b.category)).then_with(|| a.cmp(b))
+    }
+}
+
+/// Best Short Deck hand among every five of `cards`; `None` for fewer than
+/// five.
+pub(crate) fn best_hand(cards: &[Card]) -> Option<Evaluation> {
+    if cards.len() < 5 {
+        return None;
+    }
+    let mut best: Option<Evaluation> = None;
+    for mask in 0u32..1 << cards.len() {
+        if mask.count_ones() != 5 {
+            continue;
+        }
+        let mut five = [cards[0]; 5];
+        for (slot, i) in (0..cards.len()).filter(|i| mask & 1 << i != 0).enumerate() {
+            five[slot] = cards[i];
+        }
+        let eval = evaluate_short_five(&five);
+        if best.as_ref().map_or(true, |b| ShortDeck.compare(&eval, b).is_gt()) {
+            best = Some(eval);
+        }
+    }
+    best
+}
+
+/// Category order with flushes above full houses.
+pub(crate) fn strength(category: Category) -> u8 {
+    match category {
+        Category::Flush => Category::FullHouse.ordinal(),
+        Category::FullHouse => Category::Flush.ordinal(),
+        other => other.ordinal(),
+    }
+}
+
+/// Evaluate five cards, counting A-6-7-8-9 as a nine-high straight.
+fn evaluate_short_five(cards: &[Card; 5]) -> Evaluation {
+    let eval = evaluate_five(cards);
+    let mut ranks = cards.map(|c| c.rank());
+    ranks.sort_unstable_by(|a, b| b.cmp(a));
+    if ranks != [Rank::Ace, Rank::Nine, Rank::Eight, Rank::Seven, Rank::Six] {
+        return eval;
+    }
+    let flush = cards.iter().all(|c| c.suit() == cards[0].suit());
+    let category = if flush { Category::StraightFlush } else { Category::Straight };
+    Evaluation::new(
+        category,
+        eval.best_five,
+        [Rank::Nine, Rank::Two, Rank::Two, Rank::Two, Rank::Two],
+    )
+}
@@ -0,0 +1,131 @@
+//! Per-seat views: what one player is allowed to know about a hand.
+//!
+//! A `PlayerView` is built for a specific seat and contains only that seat's
+//! hole cards plus public information (board, stacks, bets, positions and the
+//! action history). Agents decide from a view rather than the engine, so they
+//! cannot peek at opponents' cards.
+
+use crate::game::{Game, HandHistoryEntry, LegalActions, PlayerStatus, Street};
+use crate::hand::{Board, HoleCards};
+use crate::variants::VariantId;
+
+/// Public information about one seat.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SeatView {
+    pub name: String,
+    pub stack: u64,
+    /// Chips committed in the current betting round
+    pub bet: u64,
+    pub status: PlayerStatus,
+    /// Hole cards this seat tabled at showdown; mucked hands stay hidden
+    pub shown: Option<HoleCards>,
+}
+
+/// Everything the player at `seat` may see when deciding an action.
+///
+/// ```
+/// use poker_rs::game::Game;
+///
+/// let mut game = Game::new(3, 1000, 5, 10);
+/// game.new_hand().unwrap();
+/// let seat = game.current();
+/// let view = game.player_view(seat);
+/// assert!(view.hole.is_some());
+/// assert!(view.legal.is_some());
+/// assert_eq!(view.to_call, 10);
+///
+/// let other = game.player_view((seat + 1) % 3);
+/// assert!(other.legal.is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct PlayerView {
+    /// Seat this view was built for
+    pub seat: usize,
+    /// Which game is being played
+    pub variant: VariantId,
+    /// This seat's own hole cards; opponents' cards are never included
+    pub hole: Option<HoleCards>,
+    pub board: Board,
+    /// Second board of a double-board hand
+    pub second_board: Option<Board>,
+    pub street: Street,
+    pub pot: u64,
+    pub current_bet: u64,
+    pub min_raise: u64,
+    /// Amount this seat must add to call
+    pub to_call: u64,
+    pub dealer: usize,
+    pub sb_pos: Option<usize>,
+    pub bb_pos: Option<usize>,
+    /// Seat whose turn it is
+    pub current: usize,
+    /// Public state of every seat, in table order
+    pub seats: Vec<SeatView>,
+    /// Legal options when it is this seat's turn, otherwise `None`
+    pub legal: Option<LegalActions>,
+    /// Public actions of the current hand, oldest first
+    pub history: Vec<HandHistoryEntry>,
+    /// Whether this seat lost at showdown and may still muck instead of showing
+    pub can_muck: bool,
+}
+
+impl PlayerView {
+    /// Number of seats at the table.
+    pub fn num_players(&self) -> usize {
+        self.seats.len()
+    }
+
+    /// This seat's stack behind; `0` for a seat not at the table.
+    pub fn stack(&self) -> u64 {
+        self.seats.get(self.seat).map_or(0, |s| s.stack)
+    }
+
+    /// This seat's bet in the current round; `0` for a seat not at the table.
+    pub fn bet(&self) -> u64 {
+        self.seats.get(self.seat).map_or(0, |s| s.bet)
+    }
+
+    /// Whether this seat is the one to act.
+    pub fn is_my_turn(&self) -> bool {
+        self.legal.is_some()
+    }
+}
+
+impl Game {
+    /// Build the view seen by the player at `seat`.
+    pub fn player_view(&self, seat: usize) -> PlayerView {
+        PlayerView {
+            seat,
+            variant: self.variant.id(),
+            hole: self.players.get(seat).and_then(|p| p.hole),
+            board: self.board.clone(),
+            second_board: self.second_board.clone(),
+            street: self.street,
+            pot: self.pot,
+            current_bet: self.current_bet,
+            min_raise: self.min_raise,
+            to_call: self.players.get(seat).map(|_| self.to_call(seat)).unwrap_or(0),
+            dealer: self.dealer,
+            sb_pos: self.sb_pos,
+            bb_pos: self.bb_pos,
+            current: self.current,
+            seats: self
+                .players
+                .iter()
+                .enumerate()
+                .map(|(i, p)| SeatView {
+                    name: p.name.clone(),
+                    stack: p.stack,
+                    bet: p.bet,
+                    status: p.status,
+                    shown: self.revealed_hole(i),
+                })
+                .collect(),
+            legal: self.legal_actions().filter(|l| l.seat == seat),
+            history: self.hand_history.clone(),
+            can_muck: self.pending_mucks().contains(&seat),
+        }
+    }
+}
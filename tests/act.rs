@@ -0,0 +1,53 @@
+use poker_rs::agents::Action;
+use poker_rs::game::{ActionError, Game, Street};
+
+#[test]
+fn out_of_turn_action_is_rejected_without_side_effects() {
+    let mut g = Game::new(4, 1000, 5, 10);
+    g.new_hand().unwrap();
+    let cur = g.current();
+    let stale = (cur + 2) % 4;
+    let pot = g.pot();
+    let history = g.history_len();
+    let last = g.players()[stale].last_action().map(str::to_string);
+
+    let err = g.act(stale, Action::RaiseTo(40)).unwrap_err();
+    assert_eq!(err, ActionError::NotYourTurn { seat: stale, current: cur });
+    assert_eq!(g.current(), cur);
+    assert_eq!(g.pot(), pot);
+    assert_eq!(g.history_len(), history);
+    assert_eq!(g.players()[stale].last_action().map(str::to_string), last);
+}
+
+#[test]
+fn act_dispatches_each_action() {
+    let mut g = Game::new(3, 1000, 5, 10);
+    g.new_hand().unwrap();
+    let utg = g.current();
+    g.act(utg, Action::RaiseTo(30)).unwrap();
+    assert_eq!(g.current_bet(), 30);
+    let next = g.current();
+    g.act(next, Action::RaiseMin).unwrap();
+    assert_eq!(g.current_bet(), 50);
+    let next = g.current();
+    g.act(next, Action::Fold).unwrap();
+    let next = g.current();
+    g.act(next, Action::CheckCall).unwrap();
+    assert_eq!(g.street(), Street::Flop);
+    let next = g.current();
+    g.act(next, Action::BetMin).unwrap();
+    assert_eq!(g.current_bet(), 10);
+    let next = g.current();
+    g.act(next, Action::RaiseTo(40)).unwrap();
+    let next = g.current();
+    assert_eq!(g.act(next, Action::Bet(100)), Err(ActionError::BetNotAllowed));
+}
+
+#[test]
+fn act_after_hand_ends_reports_showdown() {
+    let mut g = Game::new(2, 1000, 5, 10);
+    g.new_hand().unwrap();
+    let cur = g.current();
+    g.act(cur, Action::Fold).unwrap();
+    assert_eq!(g.act(cur, Action::CheckCall), Err(ActionError::Showdown));
+}
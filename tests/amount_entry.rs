@@ -7,7 +7,7 @@ fn mk_game(n: usize) -> Game {
 #[test]
 fn raise_to_requires_min_raise() {
     let mut g = mk_game(3);
-    g.new_hand();
+    g.new_hand().unwrap();
     let cur = g.current();
     let target = g.current_bet() + g.min_raise() - 1;
     let err = g.action_raise_to(target).unwrap_err();
@@ -20,7 +20,7 @@ fn raise_to_requires_min_raise() {
 #[test]
 fn raise_to_min_works() {
     let mut g = mk_game(3);
-    g.new_hand();
+    g.new_hand().unwrap();
     let cur = g.current();
     let target = g.current_bet() + g.min_raise();
     g.action_raise_to(target).unwrap();
@@ -32,7 +32,7 @@ fn raise_to_min_works() {
 #[test]
 fn bet_requires_no_current_bet_and_min_amount() {
     let mut g = mk_game(3);
-    g.new_hand();
+    g.new_hand().unwrap();
     while g.street() == Street::Preflop {
         g.action_check_call().unwrap();
     }
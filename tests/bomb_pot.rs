@@ -0,0 +1,112 @@
+use poker_rs::game::{
+    ButtonRule, Game, HandHistoryVerb, HandOptions, MissedBlind, PlayerStatus, Street,
+};
+
+fn table(stacks: &[u64]) -> Game {
+    let mut g = Game::empty_table(stacks.len(), 1000, 5, 10);
+    for (seat, &stack) in stacks.iter().enumerate() {
+        g.join(seat, &format!("P{seat}"), stack).unwrap();
+    }
+    g
+}
+
+#[test]
+fn everyone_antes_and_action_starts_on_the_flop() {
+    let mut g = Game::new(4, 1000, 5, 10);
+    g.new_hand_with(HandOptions::bomb_pot(50)).unwrap();
+
+    assert_eq!(g.street(), Street::Flop);
+    assert_eq!(g.board().len(), 3);
+    assert_eq!(g.pot(), 200);
+    assert_eq!((g.sb_pos(), g.bb_pos()), (None, None));
+    assert_eq!(g.current(), (g.dealer() + 1) % 4);
+    assert_eq!(g.current_bet(), 0);
+    for p in g.players() {
+        assert_eq!((p.stack(), p.bet(), p.contributed()), (950, 0, 50));
+    }
+    let antes: Vec<_> =
+        g.history_recent(10).into_iter().filter(|e| e.verb == HandHistoryVerb::Ante).collect();
+    assert_eq!(antes.len(), 4);
+    assert!(antes.iter().all(|e| e.amount == Some(50) && e.street == Street::Preflop));
+
+    let legal = g.legal_actions().unwrap();
+    assert!(legal.can_check);
+    assert!(legal.bet.is_some());
+}
+
+#[test]
+fn sitting_out_players_do_not_ante() {
+    let mut g = Game::new(4, 1000, 5, 10);
+    g.sit_out(2).unwrap();
+    g.new_hand_with(HandOptions::bomb_pot(20)).unwrap();
+    assert_eq!(g.pot(), 60);
+    assert_eq!(g.players()[2].stack(), 1000);
+    assert_eq!(g.players()[2].status(), PlayerStatus::SittingOut);
+}
+
+#[test]
+fn short_ante_creates_a_side_pot() {
+    let mut g = table(&[30, 1000, 1000]);
+    g.new_hand_with(HandOptions::bomb_pot(50)).unwrap();
+    assert_eq!(g.players()[0].status(), PlayerStatus::AllIn);
+    assert_eq!(g.pot(), 130);
+    assert_eq!(g.street(), Street::Flop);
+
+    while g.street() != Street::Showdown {
+        g.action_check_call().unwrap();
+    }
+    let result = g.showdown_result().unwrap();
+    assert_eq!(result.pots.len(), 2);
+    assert_eq!((result.pots[0].amount, result.pots[0].eligible.clone()), (90, vec![0, 1, 2]));
+    assert_eq!((result.pots[1].amount, result.pots[1].eligible.clone()), (40, vec![1, 2]));
+    let total: u64 = g.players().iter().map(|p| p.stack()).sum();
+    assert_eq!(total, 2030);
+}
+
+#[test]
+fn antes_that_leave_no_one_to_bet_run_out_the_board() {
+    let mut g = table(&[30, 40, 1000]);
+    g.new_hand_with(HandOptions::bomb_pot(50)).unwrap();
+    assert_eq!(g.street(), Street::Showdown);
+    assert_eq!(g.board().len(), 5);
+    assert_eq!(g.showdown_result().unwrap().uncalled, Some((2, 10)));
+    let total: u64 = g.players().iter().map(|p| p.stack()).sum();
+    assert_eq!(total, 1070);
+}
+
+#[test]
+fn next_hand_posts_blinds_again() {
+    let mut g = Game::new(3, 1000, 5, 10);
+    g.new_hand_with(HandOptions::bomb_pot(10)).unwrap();
+    while g.street() != Street::Showdown {
+        g.action_check_call().unwrap();
+    }
+    g.new_hand().unwrap();
+    assert_eq!(g.street(), Street::Preflop);
+    assert!(g.sb_pos().is_some() && g.bb_pos().is_some());
+    assert_eq!(g.pot(), 15);
+}
+
+#[test]
+fn bomb_pot_moves_the_dead_button_blinds() {
+    let mut g = table(&[1000; 5]);
+    g.set_button_rule(ButtonRule::Dead);
+    g.sit_out(4).unwrap();
+    g.new_hand().unwrap();
+    assert_eq!((g.sb_pos(), g.bb_pos()), (Some(2), Some(3)));
+    while g.street() != Street::Showdown {
+        g.action_fold().unwrap();
+    }
+
+    // The big blind would have skipped seat 4, so it owes one.
+    g.new_hand_with(HandOptions::bomb_pot(10)).unwrap();
+    assert_eq!((g.sb_pos(), g.bb_pos()), (None, None));
+    assert_eq!(g.players()[4].missed_blind(), Some(MissedBlind::Big));
+    while g.street() != Street::Showdown {
+        g.action_check_call().unwrap();
+    }
+
+    // The next hand's blinds pick up one seat on.
+    g.new_hand().unwrap();
+    assert_eq!((g.dealer(), g.sb_pos(), g.bb_pos()), (3, Some(0), Some(1)));
+}
@@ -0,0 +1,17 @@
+//! Helpers shared by the integration tests.
+#![allow(dead_code)]
+
+use poker_rs::game::{Game, Street};
+use poker_rs::hand::HoleCards;
+
+/// Parse hole cards such as `"As Kd"`.
+pub fn hole(s: &str) -> HoleCards {
+    s.parse().expect("valid hole cards")
+}
+
+/// Check or call every decision until the hand reaches showdown.
+pub fn check_down(g: &mut Game) {
+    while g.street() != Street::Showdown {
+        g.action_check_call().unwrap();
+    }
+}
@@ -0,0 +1,137 @@
+use poker_rs::game::{ButtonRule, Game, HandHistoryVerb, MissedBlind, Street};
+
+fn play_out(g: &mut Game) {
+    while g.street() != Street::Showdown {
+        g.action_fold().unwrap();
+    }
+}
+
+fn positions(g: &Game) -> (usize, Option<usize>, Option<usize>) {
+    (g.dealer(), g.sb_pos(), g.bb_pos())
+}
+
+#[test]
+fn moving_button_is_the_default() {
+    let g = Game::new(3, 1000, 5, 10);
+    assert_eq!(g.button_rule(), ButtonRule::Moving);
+}
+
+#[test]
+fn dead_button_keeps_big_blind_moving_one_seat() {
+    let mut g = Game::new(4, 1000, 5, 10);
+    g.set_button_rule(ButtonRule::Dead);
+    g.new_hand().unwrap();
+    assert_eq!(positions(&g), (1, Some(2), Some(3)));
+    play_out(&mut g);
+
+    // Last hand's big blind leaves: the small blind is dead.
+    g.sit_out(3).unwrap();
+    g.new_hand().unwrap();
+    assert_eq!(positions(&g), (2, None, Some(0)));
+    assert_eq!(g.pot(), 10);
+    assert_eq!(g.players()[3].missed_blind(), Some(MissedBlind::Small));
+    play_out(&mut g);
+
+    // The button then lands on the empty small blind seat.
+    g.new_hand().unwrap();
+    assert_eq!(positions(&g), (3, Some(0), Some(1)));
+    play_out(&mut g);
+
+    g.new_hand().unwrap();
+    assert_eq!(positions(&g), (0, Some(1), Some(2)));
+    play_out(&mut g);
+
+    // The big blind skips the sitting-out seat, which now owes it.
+    g.new_hand().unwrap();
+    assert_eq!(positions(&g), (1, Some(2), Some(0)));
+    assert_eq!(g.players()[3].missed_blind(), Some(MissedBlind::Big));
+}
+
+#[test]
+fn moving_button_skips_ineligible_seats() {
+    let mut g = Game::new(4, 1000, 5, 10);
+    g.new_hand().unwrap();
+    assert_eq!(positions(&g), (1, Some(2), Some(3)));
+    play_out(&mut g);
+    g.sit_out(3).unwrap();
+    g.new_hand().unwrap();
+    assert_eq!(positions(&g), (2, Some(0), Some(1)));
+    assert_eq!(g.players()[3].missed_blind(), Some(MissedBlind::Small));
+    play_out(&mut g);
+    g.new_hand().unwrap();
+    play_out(&mut g);
+    g.new_hand().unwrap();
+    assert_eq!(positions(&g), (1, Some(2), Some(0)));
+    assert_eq!(g.players()[3].missed_blind(), Some(MissedBlind::Big));
+}
+
+#[test]
+fn returning_player_posts_live_big_and_dead_small_blind() {
+    let mut g = Game::new(4, 1000, 5, 10);
+    g.set_button_rule(ButtonRule::Dead);
+    g.new_hand().unwrap();
+    play_out(&mut g);
+    g.sit_out(3).unwrap();
+    for _ in 0..4 {
+        g.new_hand().unwrap();
+        play_out(&mut g);
+    }
+    assert_eq!(g.players()[3].missed_blind(), Some(MissedBlind::Big));
+
+    g.sit_in(3).unwrap();
+    let stack = g.players()[3].stack();
+    g.new_hand().unwrap();
+    let seat = &g.players()[3];
+    assert_eq!(seat.missed_blind(), None);
+    assert_eq!(seat.bet(), 10);
+    assert_eq!(seat.contributed(), 15);
+    assert_eq!(seat.stack(), stack - 15);
+    assert_eq!(g.pot(), 30);
+    assert_eq!(g.current_bet(), 10);
+    let verbs: Vec<_> =
+        g.history_recent(10).into_iter().filter(|e| e.seat == 3).map(|e| e.verb).collect();
+    assert_eq!(verbs, vec![HandHistoryVerb::BigBlind, HandHistoryVerb::DeadBlind]);
+
+    while g.street() == Street::Preflop {
+        g.action_check_call().unwrap();
+    }
+    assert_eq!(g.players()[3].contributed(), 15);
+    assert_eq!(g.pot(), 45);
+}
+
+#[test]
+fn dead_small_blind_owed_alone_goes_to_pot() {
+    let mut g = Game::new(4, 1000, 5, 10);
+    g.set_button_rule(ButtonRule::Dead);
+    g.new_hand().unwrap();
+    play_out(&mut g);
+    g.sit_out(3).unwrap();
+    g.new_hand().unwrap();
+    play_out(&mut g);
+    g.sit_in(3).unwrap();
+    g.new_hand().unwrap();
+    assert_eq!(positions(&g), (3, Some(0), Some(1)));
+    let seat = &g.players()[3];
+    assert_eq!(seat.bet(), 0);
+    assert_eq!(seat.contributed(), 5);
+    assert_eq!(g.pot(), 20);
+    assert_eq!(g.current_bet(), 10);
+}
+
+#[test]
+fn returning_in_the_blinds_posts_only_that_blind() {
+    let mut g = Game::new(4, 1000, 5, 10);
+    g.new_hand().unwrap();
+    play_out(&mut g);
+    g.sit_out(0).unwrap();
+    g.new_hand().unwrap();
+    assert_eq!(positions(&g), (2, Some(3), Some(1)));
+    assert_eq!(g.players()[0].missed_blind(), Some(MissedBlind::Big));
+    play_out(&mut g);
+    g.sit_in(0).unwrap();
+    g.new_hand().unwrap();
+    assert_eq!(g.sb_pos(), Some(0));
+    assert_eq!(g.players()[0].missed_blind(), None);
+    assert_eq!(g.players()[0].contributed(), 5);
+    assert_eq!(g.pot(), 15);
+}
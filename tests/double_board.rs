@@ -0,0 +1,131 @@
+use poker_rs::cards::{parse_cards, Card};
+use poker_rs::game::{DealError, DealScript, Game, HandHistoryVerb, PotShare, Street};
+use std::collections::HashSet;
+
+mod common;
+use common::{check_down, hole};
+
+fn double(n: usize) -> Game {
+    let mut g = Game::new(n, 1000, 5, 10);
+    g.set_double_board(true).unwrap();
+    g
+}
+
+/// Aces win board one; kings make a set on board two.
+fn aces_vs_kings(second: &str) -> DealScript {
+    DealScript::new()
+        .hole(0, hole("As Ah"))
+        .hole(1, hole("Ks Kh"))
+        .board(parse_cards("2c 7d 9s 3h 4c").unwrap())
+        .second_board(parse_cards(second).unwrap())
+}
+
+#[test]
+fn two_boards_are_dealt_on_each_street() {
+    let mut g = double(3);
+    g.new_hand().unwrap();
+    assert_eq!(g.second_board().map(|b| b.len()), Some(0));
+    for (street, len) in [(Street::Flop, 3), (Street::Turn, 4), (Street::River, 5)] {
+        while g.street() != street {
+            g.action_check_call().unwrap();
+        }
+        assert_eq!(g.board().len(), len);
+        assert_eq!(g.second_board().unwrap().len(), len);
+    }
+    let cards: HashSet<Card> =
+        g.board().as_slice().iter().chain(g.second_board().unwrap().as_slice()).copied().collect();
+    assert_eq!(cards.len(), 10);
+    assert_eq!(g.player_view(0).second_board.as_ref(), g.second_board());
+}
+
+#[test]
+fn single_board_by_default() {
+    let mut g = Game::new(2, 1000, 5, 10);
+    assert!(!g.double_board());
+    g.new_hand().unwrap();
+    assert!(g.second_board().is_none());
+}
+
+#[test]
+fn each_board_wins_half_the_pot() {
+    let mut g = double(2);
+    g.new_hand_scripted(&aces_vs_kings("Kd 8c 5s 2h Jd")).unwrap();
+    check_down(&mut g);
+
+    let result = g.showdown_result().unwrap();
+    assert_eq!(
+        result.pots[0].winners,
+        vec![PotShare { seat: 0, amount: 10 }, PotShare { seat: 1, amount: 10 }]
+    );
+    assert_eq!(g.players()[0].stack(), 1000);
+    assert_eq!(g.players()[1].stack(), 1000);
+    let verbs: Vec<_> = g
+        .history_recent(20)
+        .into_iter()
+        .filter(|e| matches!(e.verb, HandHistoryVerb::Win | HandHistoryVerb::Split))
+        .map(|e| e.verb)
+        .collect();
+    assert_eq!(verbs, vec![HandHistoryVerb::Split, HandHistoryVerb::Split]);
+
+    let kings = result.hand(1).unwrap();
+    assert!(kings.evaluation.unwrap() < result.hand(0).unwrap().evaluation.unwrap());
+    assert!(kings.second_evaluation.unwrap() > result.hand(0).unwrap().second_evaluation.unwrap());
+    assert!(!kings.can_muck, "a hand that wins one board cannot muck");
+}
+
+#[test]
+fn best_hand_on_both_boards_scoops() {
+    let mut g = double(2);
+    g.new_hand_scripted(&aces_vs_kings("Qd 8c 5s 2h Jd")).unwrap();
+    check_down(&mut g);
+    let result = g.showdown_result().unwrap();
+    assert_eq!(result.won_by(0), 20);
+    assert!(g.history_recent(20).iter().any(|e| e.verb == HandHistoryVerb::Win && e.seat == 0));
+}
+
+#[test]
+fn odd_chip_goes_to_board_one() {
+    // Seat 2 folds its small blind: 25 chips split between the two boards.
+    let mut g = double(3);
+    let script = aces_vs_kings("Kd 8c 5s 2h Jd").hole(2, hole("4h 5h"));
+    g.new_hand_scripted(&script).unwrap();
+    g.action_check_call().unwrap();
+    g.action_fold().unwrap();
+    check_down(&mut g);
+    let result = g.showdown_result().unwrap();
+    assert_eq!((result.won_by(0), result.won_by(1)), (13, 12));
+}
+
+#[test]
+fn all_in_runs_out_both_boards() {
+    let mut g = double(2);
+    g.new_hand().unwrap();
+    g.action_raise_to(1000).unwrap();
+    g.action_check_call().unwrap();
+    assert_eq!(g.street(), Street::Showdown);
+    assert_eq!(g.board().len(), 5);
+    assert_eq!(g.second_board().unwrap().len(), 5);
+    let total: u64 = g.players().iter().map(|p| p.stack()).sum();
+    assert_eq!(total, 2000);
+}
+
+#[test]
+fn second_board_script_requires_double_board() {
+    let mut g = Game::new(2, 1000, 5, 10);
+    assert_eq!(
+        g.new_hand_scripted(&aces_vs_kings("Kd 8c 5s 2h Jd")),
+        Err(DealError::NoSecondBoard)
+    );
+}
+
+#[test]
+fn snapshot_keeps_the_second_board() {
+    let mut g = double(3);
+    g.new_hand().unwrap();
+    while g.street() != Street::Turn {
+        g.action_check_call().unwrap();
+    }
+    let restored = Game::restore(g.snapshot()).unwrap();
+    assert!(restored.double_board());
+    assert_eq!(restored.second_board(), g.second_board());
+}
@@ -14,7 +14,7 @@ fn advance_with_checks(game: &mut Game, street: Street) {
 #[test]
 fn auto_showdown_when_all_players_all_in() {
     let mut game = Game::new(3, 20, 5, 10);
-    game.new_hand();
+    game.new_hand().unwrap();
 
     game.action_raise_to(20).unwrap();
     game.action_check_call().unwrap();
@@ -27,7 +27,7 @@ fn auto_showdown_when_all_players_all_in() {
 #[test]
 fn check_down_advances_to_showdown() {
     let mut game = Game::new(3, 100, 5, 10);
-    game.new_hand();
+    game.new_hand().unwrap();
 
     advance_with_checks(&mut game, Street::Preflop);
     advance_with_checks(&mut game, Street::Flop);
@@ -41,7 +41,7 @@ fn check_down_advances_to_showdown() {
 #[test]
 fn postflop_bet_and_calls_advance() {
     let mut game = Game::new(3, 100, 5, 10);
-    game.new_hand();
+    game.new_hand().unwrap();
 
     advance_with_checks(&mut game, Street::Preflop);
     assert!(matches!(game.street(), Street::Flop));
@@ -51,3 +51,19 @@ fn postflop_bet_and_calls_advance() {
 
     assert!(matches!(game.street(), Street::Turn));
 }
+
+#[test]
+fn fold_leaves_the_last_player_to_answer_an_all_in() {
+    let mut game = Game::new(3, 1000, 5, 10);
+    game.new_hand().unwrap();
+    let bb = game.bb_pos().unwrap();
+
+    game.action_raise_to(1000).unwrap();
+    game.action_fold().unwrap();
+    assert_eq!(game.street(), Street::Preflop);
+    assert_eq!(game.current(), bb);
+
+    game.action_check_call().unwrap();
+    assert_eq!(game.street(), Street::Showdown);
+    assert_eq!(game.board().len(), 5);
+}
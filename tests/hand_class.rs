@@ -0,0 +1,95 @@
+use poker_rs::cards::Rank;
+use poker_rs::equity::all_combos;
+use poker_rs::hand::HoleCards;
+use poker_rs::hand_class::{HandClass, HandClassParseError};
+use std::collections::HashSet;
+
+#[test]
+fn classes_cover_every_starting_hand_once() {
+    let classes: Vec<HandClass> = HandClass::all().collect();
+    assert_eq!(classes.len(), HandClass::COUNT);
+    for (i, class) in classes.iter().enumerate() {
+        assert_eq!(class.index(), i);
+        assert_eq!(HandClass::from_index(i), Some(*class));
+    }
+    assert_eq!(HandClass::from_index(HandClass::COUNT), None);
+    assert_eq!(classes.iter().map(|c| c.combo_count()).sum::<usize>(), 1326);
+
+    let cards = |hole: &HoleCards| {
+        let mut pair = hole.as_array().unwrap();
+        pair.sort();
+        pair
+    };
+    let mut seen = HashSet::new();
+    for class in &classes {
+        let combos = class.combos();
+        assert_eq!(combos.len(), class.combo_count(), "{class}");
+        for hole in combos {
+            assert_eq!(HandClass::of(&hole), *class);
+            assert!(seen.insert(cards(&hole)));
+        }
+    }
+    assert!(all_combos().iter().all(|h| seen.contains(&cards(h))));
+}
+
+#[test]
+fn grid_puts_pairs_on_the_diagonal() {
+    assert_eq!(HandClass::from_index(0), Some(HandClass::pair(Rank::Ace)));
+    assert_eq!(HandClass::from_index(1).unwrap().to_string(), "AKs");
+    assert_eq!(HandClass::from_index(13).unwrap().to_string(), "AKo");
+    assert_eq!(HandClass::from_index(168).unwrap().to_string(), "22");
+    let t9o = HandClass::of(&"9c Th".parse().unwrap());
+    assert_eq!((t9o.high(), t9o.low()), (Rank::Ten, Rank::Nine));
+    assert_eq!(t9o.index(), 5 * 13 + 4);
+}
+
+#[test]
+fn parsing_round_trips_and_rejects_malformed_names() {
+    for class in HandClass::all() {
+        assert_eq!(class.to_string().parse::<HandClass>(), Ok(class));
+    }
+    assert_eq!("ka s".parse::<HandClass>().ok(), None);
+    assert_eq!("KAs".parse().ok(), HandClass::suited(Rank::Ace, Rank::King));
+    for bad in ["AK", "AAs", "AKx", "A", "AKQs", "1Ks"] {
+        assert_eq!(bad.parse::<HandClass>(), Err(HandClassParseError::Invalid(bad.to_string())));
+    }
+}
+
+#[test]
+fn equity_table_orders_the_extremes() {
+    let aces: HandClass = "AA".parse().unwrap();
+    let worst: HandClass = "32o".parse().unwrap();
+    assert!((aces.equity_vs_random() - 0.852).abs() < 0.005);
+    assert!((worst.equity_vs_random() - 0.323).abs() < 0.005);
+    let best =
+        HandClass::all().max_by(|a, b| a.equity_vs_random().total_cmp(&b.equity_vs_random()));
+    assert_eq!(best, Some(aces));
+    let suited: HandClass = "AKs".parse().unwrap();
+    let offsuit: HandClass = "AKo".parse().unwrap();
+    assert!(suited.equity_vs_random() > offsuit.equity_vs_random());
+    assert!(
+        suited.contains(&"As Ks".parse().unwrap()) && !suited.contains(&"As Kd".parse().unwrap())
+    );
+}
+
+#[test]
+fn constructors_only_build_real_classes() {
+    let aks = HandClass::suited(Rank::King, Rank::Ace).unwrap();
+    assert_eq!(aks, HandClass::suited(Rank::Ace, Rank::King).unwrap());
+    assert_eq!((aks.high(), aks.low(), aks.to_string()), (Rank::Ace, Rank::King, "AKs".into()));
+    assert!(aks.is_suited() && !aks.is_pair());
+    assert_eq!(HandClass::offsuit(Rank::Two, Rank::Seven).unwrap().to_string(), "72o");
+    assert!(HandClass::pair(Rank::Five).is_pair());
+    assert_eq!(HandClass::suited(Rank::Ace, Rank::Ace), None);
+    assert_eq!(HandClass::offsuit(Rank::Nine, Rank::Nine), None);
+    for class in HandClass::all() {
+        let rebuilt = if class.is_pair() {
+            Some(HandClass::pair(class.high()))
+        } else if class.is_suited() {
+            HandClass::suited(class.low(), class.high())
+        } else {
+            HandClass::offsuit(class.low(), class.high())
+        };
+        assert_eq!(rebuilt, Some(class));
+    }
+}
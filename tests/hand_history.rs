@@ -3,7 +3,7 @@ use poker_rs::game::{Game, HandHistoryVerb, Street};
 #[test]
 fn history_records_blinds_and_actions() {
     let mut game = Game::new(2, 1000, 5, 10);
-    game.new_hand();
+    game.new_hand().unwrap();
 
     let history = game.history_recent(10);
     assert_eq!(history.len(), 2);
@@ -25,7 +25,7 @@ fn history_records_blinds_and_actions() {
 #[test]
 fn history_offset_pages_from_the_end() {
     let mut game = Game::new(2, 1000, 1, 2);
-    game.new_hand();
+    game.new_hand().unwrap();
     for _ in 0..5 {
         game.action_check_call().unwrap();
     }
@@ -0,0 +1,176 @@
+use poker_rs::agents::{play_turn, BotAgent, BotProfile};
+use poker_rs::equity::{all_combos, equity_vs_random, EquityError};
+use poker_rs::game::{DealScript, Game, PlayerStatus, Street};
+use poker_rs::icm::{
+    call_fold_ev, icm_equity, push_fold_ev, CallFoldSpot, Caller, IcmError, PushFoldSpot,
+    MAX_ICM_PLAYERS,
+};
+use rand::{rngs::StdRng, SeedableRng};
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+#[test]
+fn winner_take_all_is_the_chip_share() {
+    let equity = icm_equity(&[600, 300, 100], &[1.0]).unwrap();
+    assert!(close(equity[0], 0.6) && close(equity[1], 0.3) && close(equity[2], 0.1));
+}
+
+#[test]
+fn equities_match_the_harville_formula() {
+    // A finishes second behind B with probability 0.3 * 0.5 / 0.7 and behind
+    // C with probability 0.2 * 0.5 / 0.8.
+    let equity = icm_equity(&[500, 300, 200], &[70.0, 30.0]).unwrap();
+    let second = 0.3 * 0.5 / 0.7 + 0.2 * 0.5 / 0.8;
+    assert!(close(equity[0], 0.5 * 70.0 + second * 30.0));
+    assert!(close(equity.iter().sum::<f64>(), 100.0));
+}
+
+#[test]
+fn equal_stacks_split_the_prizes_evenly() {
+    let equity = icm_equity(&[1500; 10], &[50.0, 30.0, 20.0]).unwrap();
+    assert!(equity.iter().all(|&e| close(e, 10.0)));
+}
+
+#[test]
+fn busted_players_and_unreached_places_pay_nothing() {
+    let equity = icm_equity(&[0, 400, 600], &[50.0, 30.0, 20.0]).unwrap();
+    assert_eq!(equity[0], 0.0);
+    assert!(close(equity[1] + equity[2], 80.0));
+}
+
+#[test]
+fn invalid_inputs_are_rejected() {
+    assert_eq!(icm_equity(&[0, 0], &[1.0]), Err(IcmError::NoChips));
+    assert_eq!(icm_equity(&[10, 10], &[1.0, -1.0]), Err(IcmError::InvalidPayout(2)));
+    assert_eq!(
+        icm_equity(&[1; MAX_ICM_PLAYERS + 1], &[1.0]),
+        Err(IcmError::TooManyPlayers { max: MAX_ICM_PLAYERS, got: MAX_ICM_PLAYERS + 1 })
+    );
+
+    let mut rng = StdRng::seed_from_u64(1);
+    let hand = "As Ah".parse().unwrap();
+    let spot = PushFoldSpot::new(vec![100, 100], vec![5, 10], 0, vec![], 2);
+    assert_eq!(
+        push_fold_ev(&spot, &[1.0], &hand, 10, &mut rng),
+        Err(IcmError::SeatOutOfRange { seat: 2, num_seats: 2 })
+    );
+    let spot = PushFoldSpot::new(vec![100, 100], vec![5], 0, vec![], 1);
+    assert_eq!(
+        push_fold_ev(&spot, &[1.0], &hand, 10, &mut rng),
+        Err(IcmError::LengthMismatch { stacks: 2, posted: 1 })
+    );
+    let spot =
+        PushFoldSpot::new(vec![100, 100], vec![5, 10], 0, vec![Caller::new(1, all_combos())], 1);
+    assert_eq!(
+        push_fold_ev(&spot, &[1.0], &hand, 0, &mut rng),
+        Err(IcmError::Equity(EquityError::NoTrials))
+    );
+}
+
+#[test]
+fn bubble_pressure_turns_a_shove_into_a_fold() {
+    // Small blind with 10 big blinds, the chip leader in the big blind calls
+    // with anything, and two micro stacks are about to bust.
+    let spot = PushFoldSpot::new(
+        vec![950, 4900, 100, 100],
+        vec![50, 100, 0, 0],
+        0,
+        vec![Caller::new(1, all_combos())],
+        1,
+    );
+    let hand = "Qs Js".parse().unwrap();
+    let mut rng = StdRng::seed_from_u64(3);
+    let chips = push_fold_ev(&spot, &[1.0], &hand, 2000, &mut rng).unwrap();
+    let prizes = push_fold_ev(&spot, &[50.0, 30.0, 20.0], &hand, 2000, &mut rng).unwrap();
+    assert!(chips.should_push());
+    assert!(!prizes.should_push());
+}
+
+#[test]
+fn nobody_calling_wins_the_blinds() {
+    let spot = PushFoldSpot::new(vec![95, 90], vec![5, 10], 0, vec![Caller::new(1, vec![])], 1);
+    let mut rng = StdRng::seed_from_u64(1);
+    let ev = push_fold_ev(&spot, &[1.0], &"7c 2d".parse().unwrap(), 10, &mut rng).unwrap();
+    assert!(close(ev.push, 110.0 / 200.0) && close(ev.fold, 95.0 / 200.0));
+}
+
+#[test]
+fn equity_against_random_hands_falls_with_more_opponents() {
+    let mut rng = StdRng::seed_from_u64(5);
+    let aces = "As Ah".parse().unwrap();
+    let one = equity_vs_random(&aces, 1, &[], 2000, &mut rng).unwrap();
+    let four = equity_vs_random(&aces, 4, &[], 2000, &mut rng).unwrap();
+    assert!((one - 0.85).abs() < 0.03, "{one}");
+    assert!(four < one && four > 0.45, "{four}");
+    assert_eq!(
+        equity_vs_random(&aces, 1, &["As".parse().unwrap()], 10, &mut rng),
+        Err(EquityError::DuplicateCard("As".parse().unwrap()))
+    );
+}
+
+#[test]
+fn tournament_bots_play_to_a_winner() {
+    let mut g = Game::with_seed(4, 300, 10, 20, 21);
+    g.set_invariant_checks(true);
+    let mut bots: Vec<BotAgent> = (0..4)
+        .map(|i| BotAgent::new(BotProfile::default().with_seed(i).with_payouts(vec![60.0, 40.0])))
+        .collect();
+    for _ in 0..300 {
+        if g.players().iter().filter(|p| p.stack() > 0).count() < 2 {
+            break;
+        }
+        g.new_hand().unwrap();
+        while g.street() != Street::Showdown {
+            let seat = g.current();
+            assert!(play_turn(&mut bots[seat], &mut g, seat).unwrap());
+        }
+    }
+    assert_eq!(g.players().iter().map(|p| p.stack()).sum::<u64>(), 1200);
+    assert_eq!(g.check_invariants(), Ok(()));
+}
+
+#[test]
+fn bubble_pressure_turns_a_call_into_a_fold() {
+    // The chip leader shoves any two cards into the big blind, who has 10
+    // big blinds while two micro stacks are about to bust.
+    let spot =
+        CallFoldSpot::new(vec![3900, 900, 100, 100], vec![1000, 100, 0, 0], 1, 0, all_combos());
+    let hand = "Kd 9c".parse().unwrap();
+    let mut rng = StdRng::seed_from_u64(3);
+    let chips = call_fold_ev(&spot, &[1.0], &hand, 2000, &mut rng).unwrap();
+    let prizes = call_fold_ev(&spot, &[50.0, 30.0, 20.0], &hand, 2000, &mut rng).unwrap();
+    assert!(chips.should_call());
+    assert!(!prizes.should_call());
+}
+
+#[test]
+fn folding_a_call_leaves_the_pot_to_the_pusher() {
+    let spot = CallFoldSpot::new(vec![0, 90], vec![100, 10], 1, 0, all_combos());
+    let mut rng = StdRng::seed_from_u64(1);
+    let ev = call_fold_ev(&spot, &[1.0], &"7c 2d".parse().unwrap(), 10, &mut rng).unwrap();
+    assert!(close(ev.fold, 90.0 / 200.0));
+    assert_eq!(
+        call_fold_ev(&spot, &[1.0], &"7c 2d".parse().unwrap(), 0, &mut rng),
+        Err(IcmError::Equity(EquityError::NoTrials))
+    );
+}
+
+#[test]
+fn tournament_bots_call_all_ins_by_icm() {
+    // Three-handed on the bubble with 8 big blinds each: the button shoves
+    // and the small blind decides.
+    let called = |payouts: Vec<f64>, hand: &str| {
+        let mut bot = BotAgent::new(BotProfile::default().with_seed(1).with_payouts(payouts));
+        let mut g = Game::new(3, 80, 5, 10);
+        g.new_hand_scripted(&DealScript::new().hole(2, hand.parse().unwrap())).unwrap();
+        g.action_raise_to(80).unwrap();
+        assert_eq!(g.current(), 2);
+        play_turn(&mut bot, &mut g, 2).unwrap();
+        g.players()[2].status() == PlayerStatus::AllIn
+    };
+    assert!(called(vec![60.0, 40.0], "Ts Th"));
+    assert!(!called(vec![60.0, 40.0], "As Qd"));
+    assert!(called(vec![], "As Qd"), "a cash game bot calls");
+}
@@ -0,0 +1,185 @@
+use poker_rs::agents::Action;
+use poker_rs::game::{ActionError, BetLimit, Game, HandOptions, LegalActions, Street};
+use poker_rs::invariants::InvariantViolation;
+use poker_rs::variants::builtin;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const SEATS: usize = 6;
+const BUY_IN: u64 = 500;
+
+fn random_action(rng: &mut StdRng, legal: &LegalActions) -> Action {
+    match rng.random_range(0..10) {
+        0 if legal.can_fold => Action::Fold,
+        1..=2 => {
+            if let Some(bet) = legal.bet {
+                return Action::Bet(rng.random_range(bet.min..=bet.max));
+            }
+            if let Some(raise) = legal.raise {
+                return Action::RaiseTo(rng.random_range(raise.min..=raise.max));
+            }
+            Action::CheckCall
+        }
+        3 => match (legal.bet, legal.raise) {
+            (Some(bet), _) => Action::Bet(bet.max),
+            (_, Some(raise)) => Action::RaiseTo(raise.max),
+            _ => Action::CheckCall,
+        },
+        _ => Action::CheckCall,
+    }
+}
+
+/// Seat, bust out and rotate players between hands so every hand starts
+/// with a different table.
+fn shuffle_seats(rng: &mut StdRng, g: &mut Game, total: &mut u64) {
+    for seat in 0..SEATS {
+        let p = &g.players()[seat];
+        if p.is_seated() && (p.stack() == 0 || rng.random_ratio(1, 20)) {
+            *total -= g.leave(seat).unwrap();
+        } else if p.is_seated() && rng.random_ratio(1, 20) {
+            g.sit_out(seat).unwrap();
+        } else if p.is_seated() && rng.random_ratio(1, 2) {
+            let _ = g.sit_in(seat);
+        }
+    }
+    for seat in 0..SEATS {
+        let empty = !g.players()[seat].is_seated();
+        if empty && (g.seated_count() < 2 || rng.random_ratio(1, 3)) {
+            let buy_in = rng.random_range(BUY_IN / 10..=BUY_IN * 2);
+            g.join(seat, &format!("Guest{seat}"), buy_in).unwrap();
+            *total += buy_in;
+        }
+    }
+}
+
+fn configure_hand(rng: &mut StdRng, g: &mut Game) -> HandOptions {
+    if rng.random_ratio(1, 5) {
+        let variants = builtin();
+        g.set_variant(variants[rng.random_range(0..variants.len())].clone()).unwrap();
+    }
+    if rng.random_ratio(1, 5) {
+        g.set_bet_limit(if rng.random() { BetLimit::PotLimit } else { BetLimit::NoLimit });
+    }
+    if rng.random_ratio(1, 8) {
+        g.set_double_board(!g.double_board()).unwrap();
+    }
+    if rng.random_ratio(1, 10) {
+        HandOptions::bomb_pot(rng.random_range(1..=20))
+    } else {
+        HandOptions::default()
+    }
+}
+
+fn play_hand(rng: &mut StdRng, g: &mut Game) {
+    for _ in 0..500 {
+        if g.street() == Street::Showdown {
+            return;
+        }
+        if g.undo_depth() > 0 && rng.random_ratio(1, 15) {
+            assert!(g.undo());
+            g.check_invariants().unwrap();
+            continue;
+        }
+        if rng.random_ratio(1, 25) {
+            let checks = g.invariant_checks();
+            *g = Game::restore(g.snapshot()).unwrap();
+            g.set_invariant_checks(checks);
+            g.check_invariants().unwrap();
+        }
+        let legal = g.legal_actions().expect("someone is to act");
+        let action = random_action(rng, &legal);
+        if let Err(e) = g.act(legal.seat, action) {
+            panic!("{action:?} by seat {} failed: {e}", legal.seat);
+        }
+    }
+    panic!("hand {} did not finish", g.hand_number());
+}
+
+fn stress(seed: u64, hands: usize) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut g = Game::with_seed(SEATS, BUY_IN, 5, 10, seed);
+    g.set_invariant_checks(true);
+    let mut total = SEATS as u64 * BUY_IN;
+    for _ in 0..hands {
+        shuffle_seats(&mut rng, &mut g, &mut total);
+        let options = configure_hand(&mut rng, &mut g);
+        g.new_hand_with(options).unwrap();
+        g.check_invariants().unwrap();
+        play_hand(&mut rng, &mut g);
+        g.check_invariants().unwrap();
+        let chips: u64 = g.players().iter().map(|p| p.stack()).sum();
+        assert_eq!(chips + g.pot(), total, "hand {}", g.hand_number());
+    }
+}
+
+#[test]
+fn checks_are_off_by_default() {
+    let g = Game::new(3, 1000, 5, 10);
+    assert!(!g.invariant_checks());
+    assert_eq!(g.check_invariants(), Ok(()));
+}
+
+#[test]
+fn random_play_keeps_every_invariant() {
+    for seed in 0..8 {
+        stress(seed, 300);
+    }
+}
+
+#[test]
+fn tampered_pot_is_reported() {
+    let mut g = Game::new(3, 1000, 5, 10);
+    g.new_hand().unwrap();
+    g.action_raise_to(40).unwrap();
+    let mut snapshot = g.snapshot();
+    snapshot.pot += 7;
+    snapshot.chips_in_play = snapshot.chips_in_play.map(|c| c + 7);
+    let mut restored = Game::restore(snapshot).unwrap();
+    assert_eq!(
+        restored.check_invariants(),
+        Err(InvariantViolation::PotMismatch { pot: 62, contributed: 55 })
+    );
+
+    restored.set_invariant_checks(true);
+    assert_eq!(
+        restored.action_check_call(),
+        Err(ActionError::Invariant(InvariantViolation::PotMismatch { pot: 97, contributed: 90 }))
+    );
+}
+
+#[test]
+fn repaired_pot_is_reported() {
+    let mut g = Game::new(2, 1000, 5, 10);
+    g.new_hand().unwrap();
+    let mut snapshot = g.snapshot();
+    snapshot.pot += 3;
+    snapshot.chips_in_play = snapshot.chips_in_play.map(|c| c + 3);
+    let mut restored = Game::restore(snapshot).unwrap();
+    while restored.street() != Street::Showdown {
+        restored.action_check_call().unwrap();
+    }
+    assert_eq!(
+        restored.check_invariants(),
+        Err(InvariantViolation::PotRepaired { pot: 23, contributed: 20 })
+    );
+    // The next hand forgets the repair, but not the chips it lost.
+    restored.new_hand().unwrap();
+    assert_eq!(
+        restored.check_invariants(),
+        Err(InvariantViolation::ChipsNotConserved { expected: 2003, found: 2000 })
+    );
+}
+
+#[test]
+fn big_blind_all_in_for_less_than_the_small_blind() {
+    let mut g = Game::empty_table(3, 1000, 5, 10);
+    g.join(0, "A", 3).unwrap();
+    g.join(1, "B", 1000).unwrap();
+    g.join(2, "C", 1000).unwrap();
+    g.set_invariant_checks(true);
+    g.new_hand().unwrap();
+    assert_eq!(g.bb_pos(), Some(0));
+    assert_eq!(g.current_bet(), 5);
+    assert_eq!(g.check_invariants(), Ok(()));
+    assert_eq!(g.legal_actions().unwrap().call_amount, 5);
+}
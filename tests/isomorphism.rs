@@ -0,0 +1,90 @@
+use poker_rs::cards::{Card, Rank, Suit};
+use poker_rs::hand::{Board, HoleCards};
+use poker_rs::isomorphism::{canonical_board, canonicalize, distinct_flops, SuitMap};
+use proptest::prelude::*;
+
+fn deck() -> Vec<Card> {
+    Rank::ALL.iter().flat_map(|&r| Suit::ALL.iter().map(move |&s| Card::new(r, s))).collect()
+}
+
+/// Two hole cards and up to five board cards, all distinct.
+fn any_situation() -> impl Strategy<Value = (HoleCards, Board)> {
+    (Just(deck()).prop_shuffle(), 0usize..=5).prop_map(|(cards, board)| {
+        let hole = HoleCards::try_new(cards[0], cards[1]).unwrap();
+        (hole, Board::new(cards[2..2 + board].to_vec()))
+    })
+}
+
+fn any_suit_map() -> impl Strategy<Value = SuitMap> {
+    (0usize..24).prop_map(|i| SuitMap::all().nth(i).unwrap())
+}
+
+fn renamed(map: &SuitMap, hole: &HoleCards, board: &Board) -> (HoleCards, Board) {
+    let cards: Vec<Card> = hole.as_slice().iter().map(|&c| map.apply(c)).collect();
+    let board = board.as_slice().iter().map(|&c| map.apply(c)).collect();
+    (HoleCards::from_slice(&cards).unwrap(), Board::new(board))
+}
+
+proptest! {
+    #[test]
+    fn renaming_suits_keeps_the_canonical_form((hole, board) in any_situation(), map in any_suit_map()) {
+        let (other_hole, other_board) = renamed(&map, &hole, &board);
+        let a = canonicalize(&hole, &board);
+        let b = canonicalize(&other_hole, &other_board);
+        prop_assert_eq!(&a.hole, &b.hole);
+        prop_assert_eq!(&a.board, &b.board);
+    }
+
+    #[test]
+    fn the_map_takes_the_original_to_the_canonical_form((hole, board) in any_situation()) {
+        let canonical = canonicalize(&hole, &board);
+        let (mapped_hole, mapped_board) = renamed(&canonical.map, &hole, &board);
+        let mut expected: Vec<Card> = mapped_hole.as_slice().to_vec();
+        expected.sort();
+        prop_assert_eq!(canonical.hole.as_slice(), expected.as_slice());
+        let (flop, rest) = mapped_board.as_slice().split_at(board.len().min(3));
+        let mut flop = flop.to_vec();
+        flop.sort();
+        prop_assert_eq!(&canonical.board.as_slice()[..flop.len()], flop.as_slice());
+        prop_assert_eq!(&canonical.board.as_slice()[flop.len()..], rest);
+        let inverse = canonical.map.inverse();
+        prop_assert!(Suit::ALL.iter().all(|&s| inverse.suit(canonical.map.suit(s)) == s));
+    }
+}
+
+#[test]
+fn suit_maps_are_the_24_permutations() {
+    let maps: Vec<SuitMap> = SuitMap::all().collect();
+    assert_eq!(maps.len(), 24);
+    assert_eq!(maps[0], SuitMap::identity());
+    for (i, a) in maps.iter().enumerate() {
+        assert!(maps[i + 1..].iter().all(|b| b != a));
+    }
+}
+
+#[test]
+fn turn_and_river_keep_their_place() {
+    let hole: HoleCards = "Ah Kh".parse().unwrap();
+    let turn_first = canonicalize(&hole, &"2c 7d 9s Qc Qd".parse().unwrap());
+    let river_first = canonicalize(&hole, &"2c 7d 9s Qd Qc".parse().unwrap());
+    assert_ne!(turn_first.board, river_first.board);
+    let flop_order = canonicalize(&hole, &"9s 2c 7d Qc Qd".parse().unwrap());
+    assert_eq!(flop_order.board, turn_first.board);
+}
+
+#[test]
+fn flop_weights_follow_the_suit_pattern() {
+    let flops = distinct_flops();
+    let weight = |flop: &str| {
+        let (board, _) = canonical_board(&flop.parse().unwrap());
+        let cards: Vec<Card> = board.as_slice().to_vec();
+        flops.iter().find(|f| f.cards.as_slice() == cards.as_slice()).unwrap().weight
+    };
+    assert_eq!(weight("2c 2d 2h"), 4);
+    assert_eq!(weight("As Ks Qs"), 4);
+    assert_eq!(weight("As Kd Qh"), 24);
+    assert_eq!(weight("As Ks Qh"), 12);
+    assert_eq!(weight("As Ad Kd"), 12);
+    assert_eq!(weight("As Ad Kc"), 12);
+    assert_eq!(flops.iter().filter(|f| f.weight == 4).count(), 13 + 286);
+}
@@ -0,0 +1,131 @@
+use poker_rs::agents::{play_turn, BotAgent, BotProfile};
+use poker_rs::cards::parse_cards;
+use poker_rs::game::{DealScript, Game, Street};
+use poker_rs::ledger::{HandRecord, SeatResult, SessionLedger};
+use poker_rs::snapshot::GameSnapshot;
+
+mod common;
+use common::check_down;
+
+#[test]
+fn starting_players_are_bought_in() {
+    let g = Game::new(3, 1000, 5, 10);
+    let ledger = g.ledger();
+    assert_eq!(ledger.players().len(), 3);
+    assert!(ledger.players().iter().all(|p| p.bought_in == 1000 && p.hands == 0));
+    assert_eq!(ledger.bb_per_100("P1"), None);
+    assert!(Game::empty_table(6, 1000, 5, 10).ledger().players().is_empty());
+}
+
+#[test]
+fn showdown_results_are_split_from_uncontested_ones() {
+    let mut g = Game::new(2, 1000, 5, 10);
+    let script = DealScript::new()
+        .hole(0, "As Ah".parse().unwrap())
+        .hole(1, "Ks Kh".parse().unwrap())
+        .board(parse_cards("2c 7d 9s 3h 4c").unwrap());
+    g.new_hand_scripted(&script).unwrap();
+    check_down(&mut g);
+    g.new_hand().unwrap();
+    g.action_fold().unwrap();
+
+    let winner = g.winners()[0];
+    let ledger = g.ledger();
+    let p1 = ledger.player("P1").unwrap();
+    let p2 = ledger.player("P2").unwrap();
+    assert_eq!((p1.hands, p1.showdown_net), (2, 10));
+    assert_eq!(p2.showdown_net, -10);
+    let folded = if winner == 0 { p2 } else { p1 };
+    assert_eq!(folded.non_showdown_net, -5);
+    assert_eq!(p1.net, p1.showdown_net + p1.non_showdown_net);
+    assert_eq!(ledger.totals().net, 0);
+    assert_eq!(ledger.hands().len(), 2);
+    assert!(ledger.hands()[0].results.iter().all(|r| r.showdown));
+}
+
+#[test]
+fn seating_changes_are_recorded_by_name() {
+    let mut g = Game::empty_table(4, 1000, 5, 10);
+    g.join(0, "Alice", 500).unwrap();
+    g.join(2, "Bob", 800).unwrap();
+    g.new_hand().unwrap();
+    check_down(&mut g);
+    let stack = g.players()[0].stack();
+    assert_eq!(g.leave(0).unwrap(), stack);
+    g.join(3, "Alice", 300).unwrap();
+
+    let alice = g.ledger().player("Alice").unwrap();
+    assert_eq!((alice.bought_in, alice.cashed_out, alice.hands), (800, stack, 1));
+    assert_eq!(alice.invested(), 800);
+    assert_eq!(g.ledger().players().len(), 2);
+}
+
+#[test]
+fn undoing_the_last_action_retracts_the_hand() {
+    let mut g = Game::new(3, 1000, 5, 10);
+    g.new_hand().unwrap();
+    check_down(&mut g);
+    assert_eq!(g.ledger().hands().len(), 1);
+    assert!(g.undo());
+    assert!(g.ledger().hands().is_empty());
+    assert!(g.ledger().players().iter().all(|p| p.hands == 0 && p.net == 0));
+    g.action_check_call().unwrap();
+    assert_eq!(g.ledger().hands().len(), 1);
+}
+
+#[test]
+fn snapshots_keep_the_ledger() {
+    let mut g = Game::new(3, 1000, 5, 10);
+    g.new_hand().unwrap();
+    check_down(&mut g);
+    g.new_hand().unwrap();
+    let restored = Game::restore(g.snapshot()).unwrap();
+    assert_eq!(restored.ledger(), g.ledger());
+
+    let mut json: serde_json::Value = serde_json::to_value(g.snapshot()).unwrap();
+    json.as_object_mut().unwrap().remove("ledger");
+    let old = Game::restore(serde_json::from_value::<GameSnapshot>(json).unwrap()).unwrap();
+    assert_eq!(old.ledger(), &SessionLedger::new(10));
+}
+
+#[test]
+fn rake_and_win_rate() {
+    let mut ledger = SessionLedger::new(10);
+    ledger.record_buy_in("Hero", 1000);
+    ledger.record_rebuy("Hero", 500);
+    for hand_number in 1..=4 {
+        let results =
+            vec![SeatResult::new(0, "Hero", 15, false), SeatResult::new(1, "Villain", -15, false)];
+        ledger.record_hand(HandRecord::new(hand_number, results));
+    }
+    ledger.record_rake("Hero", 6);
+    let hero = ledger.player("Hero").unwrap();
+    assert_eq!((hero.invested(), hero.net, hero.net_after_rake()), (1500, 60, 54));
+    assert_eq!(ledger.bb_per_100("Hero"), Some(150.0));
+    assert_eq!(ledger.bb_per_100("Villain"), Some(-150.0));
+    assert_eq!(ledger.totals().rake, 6);
+}
+
+#[test]
+fn bot_session_results_match_stacks() {
+    let mut g = Game::with_seed(6, 1000, 5, 10, 11);
+    let mut bots: Vec<BotAgent> =
+        (0..6).map(|i| BotAgent::new(BotProfile::default().with_seed(i))).collect();
+    for _ in 0..50 {
+        if g.players().iter().filter(|p| p.stack() > 0).count() < 2 {
+            break;
+        }
+        g.new_hand().unwrap();
+        while g.street() != Street::Showdown {
+            let seat = g.current();
+            assert!(play_turn(&mut bots[seat], &mut g, seat).unwrap());
+        }
+    }
+    let ledger = g.ledger();
+    assert_eq!(ledger.totals().net, 0);
+    for p in g.players() {
+        let totals = ledger.player(p.name()).unwrap();
+        assert_eq!(totals.net, p.stack() as i64 - totals.invested() as i64);
+        assert_eq!(totals.net, totals.showdown_net + totals.non_showdown_net);
+    }
+}
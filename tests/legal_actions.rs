@@ -0,0 +1,82 @@
+use poker_rs::game::{BetRange, Game, Street};
+
+#[test]
+fn preflop_facing_big_blind() {
+    let mut g = Game::new(3, 1000, 5, 10);
+    g.new_hand().unwrap();
+    let legal = g.legal_actions().expect("someone to act");
+    assert_eq!(legal.seat, g.current());
+    assert!(legal.can_fold && legal.can_call && !legal.can_check);
+    assert_eq!(legal.call_amount, 10);
+    assert_eq!(legal.bet, None);
+    assert_eq!(legal.raise, Some(BetRange { min: 20, max: 1000 }));
+    assert_eq!(legal.all_in, 1000);
+}
+
+#[test]
+fn big_blind_option_can_check_or_raise() {
+    let mut g = Game::new(3, 1000, 5, 10);
+    g.new_hand().unwrap();
+    g.action_check_call().unwrap();
+    g.action_check_call().unwrap();
+    let legal = g.legal_actions().unwrap();
+    assert_eq!(Some(legal.seat), g.bb_pos());
+    assert!(legal.can_check && !legal.can_call);
+    assert_eq!(legal.call_amount, 0);
+    assert_eq!(legal.raise, Some(BetRange { min: 20, max: 1000 }));
+}
+
+#[test]
+fn postflop_unopened_offers_bet_range() {
+    let mut g = Game::new(3, 1000, 5, 10);
+    g.new_hand().unwrap();
+    while g.street() == Street::Preflop {
+        g.action_check_call().unwrap();
+    }
+    let legal = g.legal_actions().unwrap();
+    assert!(legal.can_check);
+    assert_eq!(legal.bet, Some(BetRange { min: 10, max: 990 }));
+    assert_eq!(legal.raise, None);
+
+    g.action_bet(50).unwrap();
+    let legal = g.legal_actions().unwrap();
+    assert_eq!(legal.call_amount, 50);
+    assert_eq!(legal.raise, Some(BetRange { min: 100, max: 990 }));
+}
+
+#[test]
+fn short_stack_can_only_raise_all_in() {
+    // Heads-up: the button posts 5 and has 10 behind, short of a full raise to 20.
+    let mut g = Game::new(2, 15, 5, 10);
+    g.new_hand().unwrap();
+    let legal = g.legal_actions().unwrap();
+    assert_eq!(legal.call_amount, 5);
+    assert_eq!(legal.raise, Some(BetRange { min: 15, max: 15 }));
+    g.action_raise_to(15).unwrap();
+
+    // The big blind has 5 behind: calling puts them all-in and raising is impossible.
+    let legal = g.legal_actions().unwrap();
+    assert_eq!(legal.call_amount, 5);
+    assert_eq!(legal.raise, None);
+}
+
+#[test]
+fn short_stack_may_bet_all_in_below_big_blind() {
+    let mut g = Game::new(2, 14, 2, 10);
+    g.new_hand().unwrap();
+    g.action_check_call().unwrap();
+    g.action_check_call().unwrap();
+    assert_eq!(g.street(), Street::Flop);
+    let legal = g.legal_actions().unwrap();
+    assert_eq!(legal.bet, Some(BetRange { min: 4, max: 4 }));
+    g.action_bet(4).unwrap();
+}
+
+#[test]
+fn no_legal_actions_at_showdown() {
+    let mut g = Game::new(2, 1000, 5, 10);
+    g.new_hand().unwrap();
+    g.action_fold().unwrap();
+    assert_eq!(g.street(), Street::Showdown);
+    assert!(g.legal_actions().is_none());
+}
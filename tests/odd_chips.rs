@@ -0,0 +1,73 @@
+use poker_rs::cards::parse_cards;
+use poker_rs::game::{DealScript, Game, OddChipRule, Street};
+
+mod common;
+use common::hole;
+
+/// Seats 0 and 1 chop a 25-chip pot after the small blind (seat 2) folds.
+/// Seat 1 deals, so seat 2 is first left of the button and seat 0 next.
+fn chop(configure: impl FnOnce(&mut Game)) -> Game {
+    let mut g = Game::new(3, 1000, 5, 10);
+    configure(&mut g);
+    let script = DealScript::new()
+        .hole(0, hole("Ac 2d"))
+        .hole(1, hole("Ad 3c"))
+        .hole(2, hole("4h 5h"))
+        .board(parse_cards("Ks Qs Js Ts 8h").unwrap());
+    g.new_hand_scripted(&script).unwrap();
+    assert_eq!(g.dealer(), 1);
+    g.action_check_call().unwrap();
+    g.action_fold().unwrap();
+    while g.street() != Street::Showdown {
+        g.action_check_call().unwrap();
+    }
+    g
+}
+
+fn won(g: &Game) -> (u64, u64) {
+    let result = g.showdown_result().unwrap();
+    (result.won_by(0), result.won_by(1))
+}
+
+#[test]
+fn odd_chip_goes_left_of_button_by_default() {
+    let g = chop(|_| {});
+    assert_eq!(g.odd_chip_rule(), OddChipRule::LeftOfButton);
+    assert_eq!(g.chip_unit(), 1);
+    assert_eq!(won(&g), (13, 12));
+}
+
+#[test]
+fn odd_chip_goes_to_highest_card_by_suit() {
+    let g = chop(|g| g.set_odd_chip_rule(OddChipRule::HighCardBySuit));
+    assert_eq!(won(&g), (12, 13), "the ace of diamonds outranks the ace of clubs");
+}
+
+#[test]
+fn pots_split_in_whole_chips_of_the_smallest_denomination() {
+    let g = chop(|g| g.set_chip_unit(5));
+    assert_eq!(won(&g), (15, 10));
+
+    let g = chop(|g| {
+        g.set_chip_unit(5);
+        g.set_odd_chip_rule(OddChipRule::HighCardBySuit);
+    });
+    assert_eq!(won(&g), (10, 15));
+}
+
+#[test]
+fn zero_chip_unit_is_clamped() {
+    let mut g = Game::new(2, 1000, 5, 10);
+    g.set_chip_unit(0);
+    assert_eq!(g.chip_unit(), 1);
+}
+
+#[test]
+fn settings_survive_snapshots() {
+    let mut g = Game::new(3, 1000, 5, 10);
+    g.set_odd_chip_rule(OddChipRule::HighCardBySuit);
+    g.set_chip_unit(5);
+    let restored = Game::restore(g.snapshot()).unwrap();
+    assert_eq!(restored.odd_chip_rule(), OddChipRule::HighCardBySuit);
+    assert_eq!(restored.chip_unit(), 5);
+}
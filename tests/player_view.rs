@@ -0,0 +1,88 @@
+use poker_rs::agents::{play_turn, Action, PlayerAgent};
+use poker_rs::game::{Game, Street};
+use poker_rs::view::PlayerView;
+
+#[test]
+fn view_exposes_only_own_hole_cards() {
+    let mut g = Game::new(4, 1000, 5, 10);
+    g.new_hand().unwrap();
+    for seat in 0..4 {
+        let view = g.player_view(seat);
+        assert_eq!(view.seat, seat);
+        assert_eq!(view.hole, g.players()[seat].hole());
+        let own = view.hole.unwrap().as_array().unwrap();
+        let debug = format!("{view:?}");
+        for other in (0..4).filter(|&s| s != seat) {
+            for card in g.players()[other].hole().unwrap().as_array().unwrap() {
+                if !own.contains(&card) {
+                    assert!(!debug.contains(&format!("{card:?}")), "seat {seat} sees {card:?}");
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn view_carries_public_state() {
+    let mut g = Game::new(3, 1000, 5, 10);
+    g.new_hand().unwrap();
+    g.action_raise_to(30).unwrap();
+    let view = g.player_view(g.current());
+    assert_eq!(view.pot, g.pot());
+    assert_eq!(view.current_bet, 30);
+    assert_eq!(view.dealer, g.dealer());
+    assert_eq!(view.sb_pos, g.sb_pos());
+    assert_eq!(view.bb_pos, g.bb_pos());
+    assert_eq!(view.history.len(), g.history_len());
+    assert_eq!(view.to_call, g.to_call(view.seat));
+    assert_eq!(view.legal, g.legal_actions());
+    let stacks: Vec<u64> = view.seats.iter().map(|s| s.stack).collect();
+    let expected: Vec<u64> = g.players().iter().map(|p| p.stack()).collect();
+    assert_eq!(stacks, expected);
+}
+
+#[test]
+fn legal_actions_only_for_seat_to_act() {
+    let mut g = Game::new(3, 1000, 5, 10);
+    g.new_hand().unwrap();
+    let cur = g.current();
+    assert!(g.player_view(cur).is_my_turn());
+    assert!(!g.player_view((cur + 1) % 3).is_my_turn());
+    while g.street() != Street::Showdown {
+        g.action_fold().unwrap();
+    }
+    assert!((0..3).all(|s| g.player_view(s).legal.is_none()));
+}
+
+struct AlwaysCall {
+    seen: Vec<usize>,
+}
+
+impl PlayerAgent for AlwaysCall {
+    fn on_turn(&mut self, view: &PlayerView) -> Option<Action> {
+        self.seen.push(view.seat);
+        view.is_my_turn().then_some(Action::CheckCall)
+    }
+}
+
+#[test]
+fn third_party_agent_decides_from_view() {
+    let mut g = Game::new(3, 1000, 5, 10);
+    g.new_hand().unwrap();
+    let cur = g.current();
+    let mut agent = AlwaysCall { seen: Vec::new() };
+    assert!(!play_turn(&mut agent, &mut g, (cur + 1) % 3).unwrap());
+    assert_eq!(g.current(), cur);
+    assert!(play_turn(&mut agent, &mut g, cur).unwrap());
+    assert_ne!(g.current(), cur);
+    assert_eq!(agent.seen, vec![(cur + 1) % 3, cur]);
+}
+
+#[test]
+fn view_for_a_seat_off_the_table_is_empty() {
+    let mut g = Game::new(3, 1000, 5, 10);
+    g.new_hand().unwrap();
+    let view = g.player_view(7);
+    assert_eq!((view.stack(), view.bet(), view.to_call), (0, 0, 0));
+    assert!(view.hole.is_none() && !view.is_my_turn());
+}
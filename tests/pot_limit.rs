@@ -0,0 +1,93 @@
+use poker_rs::agents::{play_turn, BotAgent, BotProfile};
+use poker_rs::game::{ActionError, BetLimit, Game, Street};
+use poker_rs::variants::Omaha;
+use std::sync::Arc;
+
+fn plo(stacks: &[u64]) -> Game {
+    let mut g = Game::empty_table(stacks.len(), 1000, 5, 10);
+    for (seat, &stack) in stacks.iter().enumerate() {
+        g.join(seat, &format!("P{seat}"), stack).unwrap();
+    }
+    g.set_variant(Arc::new(Omaha)).unwrap();
+    g.set_bet_limit(BetLimit::PotLimit);
+    g
+}
+
+#[test]
+fn no_limit_is_the_default() {
+    let mut g = Game::new(3, 1000, 5, 10);
+    assert_eq!(g.bet_limit(), BetLimit::NoLimit);
+    g.new_hand().unwrap();
+    assert_eq!(g.legal_actions().unwrap().raise.unwrap().max, 1000);
+}
+
+#[test]
+fn preflop_raises_are_capped_at_the_pot() {
+    let mut g = plo(&[1000, 1000, 1000]);
+    g.new_hand().unwrap();
+    let legal = g.legal_actions().unwrap();
+    assert_eq!((legal.raise.unwrap().min, legal.raise.unwrap().max), (20, 35));
+    assert_eq!(legal.all_in, 1000);
+    assert_eq!(g.action_raise_to(36), Err(ActionError::AmountTooLarge { max: 35, got: 36 }));
+
+    g.action_raise_to(35).unwrap();
+    // Small blind: calling 30 makes the pot 80, raised on top of the 35 bet.
+    assert_eq!(g.legal_actions().unwrap().raise.unwrap().max, 115);
+}
+
+#[test]
+fn postflop_bets_and_raises_are_capped_at_the_pot() {
+    let mut g = plo(&[1000, 1000, 1000]);
+    g.new_hand().unwrap();
+    while g.street() == Street::Preflop {
+        g.action_check_call().unwrap();
+    }
+    assert_eq!(g.pot(), 30);
+    assert_eq!(g.legal_actions().unwrap().bet.unwrap().max, 30);
+    assert_eq!(g.action_bet(31), Err(ActionError::AmountTooLarge { max: 30, got: 31 }));
+    g.action_bet(30).unwrap();
+    assert_eq!(g.legal_actions().unwrap().raise.unwrap().max, 120);
+    g.action_raise_to(120).unwrap();
+}
+
+#[test]
+fn short_stacks_may_still_go_all_in() {
+    let mut g = plo(&[1000, 1000, 25]);
+    g.new_hand().unwrap();
+    while g.current() != 2 {
+        g.action_check_call().unwrap();
+    }
+    let legal = g.legal_actions().unwrap();
+    assert_eq!(legal.raise.unwrap().max, 25);
+    g.action_raise_to(25).unwrap();
+}
+
+#[test]
+fn snapshots_keep_the_bet_limit() {
+    let mut g = plo(&[1000, 1000]);
+    g.new_hand().unwrap();
+    let restored = Game::restore(g.snapshot()).unwrap();
+    assert_eq!(restored.bet_limit(), BetLimit::PotLimit);
+    assert_eq!(restored.legal_actions(), g.legal_actions());
+}
+
+#[test]
+fn bots_play_pot_limit_omaha_legally() {
+    let mut g = plo(&[1000; 6]);
+    let mut bots: Vec<BotAgent> =
+        (0..6).map(|i| BotAgent::new(BotProfile::default().with_seed(i))).collect();
+    for _ in 0..20 {
+        g.new_hand().unwrap();
+        for _ in 0..200 {
+            if g.street() == Street::Showdown {
+                break;
+            }
+            let seat = g.current();
+            assert_eq!(g.player_view(seat).hole.unwrap().as_slice().len(), 4);
+            assert!(play_turn(&mut bots[seat], &mut g, seat).unwrap());
+        }
+        assert_eq!(g.street(), Street::Showdown);
+        let total: u64 = g.players().iter().map(|p| p.stack()).sum();
+        assert_eq!(total, 6000);
+    }
+}
@@ -0,0 +1,111 @@
+use poker_rs::agents::{play_turn, BotAgent, BotProfile};
+use poker_rs::game::{DealScript, Game, PlayerStatus};
+use poker_rs::hand_class::HandClass;
+use poker_rs::push_fold::{
+    call_chart, push_chart, solve, EquityMatrix, PushFoldError, MAX_BEHIND, MAX_CHART_BB,
+};
+use rand::{rngs::StdRng, SeedableRng};
+
+fn class(label: &str) -> HandClass {
+    label.parse().unwrap()
+}
+
+fn cell(label: &str) -> usize {
+    class(label).index()
+}
+
+#[test]
+fn compiled_charts_rank_hands_sensibly() {
+    for behind in 1..=MAX_BEHIND {
+        let push = push_chart(behind).unwrap();
+        let call = call_chart(behind).unwrap();
+        assert_eq!(push.max_bb(class("AA")), MAX_CHART_BB);
+        assert_eq!(call.max_bb(class("AA")), MAX_CHART_BB);
+        assert!(push.max_bb(class("72o")) < push.max_bb(class("A7s")));
+    }
+    // Ranges tighten as more players are left to act.
+    let heads_up = push_chart(1).unwrap();
+    let under_the_gun = push_chart(MAX_BEHIND).unwrap();
+    assert!(HandClass::all().all(|c| under_the_gun.max_bb(c) <= heads_up.max_bb(c)));
+    assert!(heads_up.contains(&"Kc 2d".parse().unwrap(), 10.0));
+    assert!(!under_the_gun.contains(&"Kc 2d".parse().unwrap(), 10.0));
+    assert!(push_chart(0).is_none() && call_chart(MAX_BEHIND + 1).is_none());
+}
+
+#[test]
+fn chart_ranges_list_every_combo_in_range() {
+    let call = call_chart(2).unwrap();
+    let range = call.range(10.0);
+    assert!(range.iter().all(|h| call.contains(h, 10.0)));
+    let expected: usize =
+        HandClass::all().filter(|&c| call.max_bb(c) >= 10.0).map(|c| c.combos().len()).sum();
+    assert_eq!(range.len(), expected);
+    assert!(call.range(MAX_CHART_BB).len() < range.len());
+    assert_eq!(call.range(MAX_CHART_BB + 1.0), vec![]);
+}
+
+#[test]
+fn solving_a_spot_pushes_strong_hands() {
+    let mut rng = StdRng::seed_from_u64(3);
+    let matrix = EquityMatrix::compute(4, &mut rng).unwrap();
+    assert_eq!(matrix.weight(cell("AA"), cell("AA")), 1.0 * 6.0);
+    assert_eq!(matrix.weight(cell("AKs"), cell("AKo")), 4.0 * 6.0);
+
+    let eq = solve(&matrix, 1, 15.0, 50).unwrap();
+    assert_eq!(eq.call.len(), 1);
+    assert!(eq.push[cell("AA")] > 0.9 && eq.push[cell("72o")] < 0.1);
+    assert!(eq.call[0][cell("KK")] > 0.9 && eq.call[0][cell("83o")] < 0.1);
+
+    assert_eq!(solve(&matrix, 0, 10.0, 1), Err(PushFoldError::Behind(0)));
+    assert_eq!(solve(&matrix, 1, 0.5, 1), Err(PushFoldError::Stack(0.5)));
+}
+
+/// Three-handed with 8 big blinds each; the button is first to act.
+fn short_handed(button: &str, small_blind: &str) -> Game {
+    let mut g = Game::new(3, 80, 5, 10);
+    g.new_hand().unwrap();
+    let first = g.current();
+    let script = DealScript::new()
+        .hole((first + 1) % 3, small_blind.parse().unwrap())
+        .hole((first + 2) % 3, "7c 2d".parse().unwrap())
+        .hole((first + 3) % 3, button.parse().unwrap());
+    let mut g = Game::new(3, 80, 5, 10);
+    g.new_hand_scripted(&script).unwrap();
+    assert_eq!(g.current(), first);
+    g
+}
+
+#[test]
+fn short_stacked_bots_push_or_fold_first_in() {
+    assert_eq!(BotProfile::default().push_fold_bb, 0, "charts are opt-in");
+    let mut bot = BotAgent::new(BotProfile::default().with_seed(1).with_push_fold_bb(15));
+    let mut g = short_handed("As Kd", "Qs Qh");
+    let button = g.current();
+    play_turn(&mut bot, &mut g, button).unwrap();
+    assert_eq!(g.players()[button].status(), PlayerStatus::AllIn);
+
+    let mut g = short_handed("8c 3d", "Qs Qh");
+    play_turn(&mut bot, &mut g, button).unwrap();
+    assert_eq!(g.players()[button].status(), PlayerStatus::Folded);
+
+    let mut deep = BotAgent::new(BotProfile::default().with_seed(1).with_push_fold_bb(5));
+    let mut g = short_handed("As Kd", "Qs Qh");
+    play_turn(&mut deep, &mut g, button).unwrap();
+    assert_ne!(g.players()[button].status(), PlayerStatus::AllIn);
+}
+
+#[test]
+fn short_stacked_bots_call_pushes_by_the_chart() {
+    let mut bot = BotAgent::new(BotProfile::default().with_seed(1).with_push_fold_bb(15));
+    for (small_blind, calls) in [("Qs Qh", true), ("9c 4d", false)] {
+        let mut g = short_handed("As Kd", small_blind);
+        let button = g.current();
+        g.action_raise_to(80).unwrap();
+        let seat = g.current();
+        assert_eq!(seat, (button + 1) % 3);
+        play_turn(&mut bot, &mut g, seat).unwrap();
+        let status = g.players()[seat].status();
+        assert_eq!(status == PlayerStatus::AllIn, calls, "{small_blind}");
+        assert_eq!(status == PlayerStatus::Folded, !calls, "{small_blind}");
+    }
+}
@@ -0,0 +1,166 @@
+use poker_rs::cards::parse_cards;
+use poker_rs::game::{BuyInError, BuyInRules, DealScript, Game, PlayerStatus, SeatError, Street};
+
+/// All-in where seat 1's kings lose to seat 0's aces; seat 2 folds.
+fn bust_seat_one(g: &mut Game) {
+    let script = DealScript::new()
+        .hole(0, "As Ah".parse().unwrap())
+        .hole(1, "Ks Kh".parse().unwrap())
+        .board(parse_cards("2c 7d 9s 3h 4c").unwrap());
+    g.new_hand_scripted(&script).unwrap();
+    while g.street() != Street::Showdown {
+        let legal = g.legal_actions().unwrap();
+        if legal.seat == 2 {
+            g.action_fold().unwrap();
+        } else if g.current_bet() < 1000 {
+            g.action_raise_to(legal.all_in).unwrap();
+        } else {
+            g.action_check_call().unwrap();
+        }
+    }
+    assert_eq!(g.street(), Street::Showdown);
+    assert_eq!(g.players()[1].stack(), 0);
+}
+
+#[test]
+fn busted_players_stay_out_without_rebuys() {
+    let mut g = Game::new(3, 1000, 5, 10);
+    assert_eq!(g.buy_in_rules(), BuyInRules::default());
+    bust_seat_one(&mut g);
+    assert_eq!(g.rebuy(1, 1000), Err(BuyInError::RebuysNotAllowed));
+    assert_eq!(g.top_up(0, 100), Err(BuyInError::TopUpsNotAllowed));
+    g.new_hand().unwrap();
+    assert_eq!(g.players()[1].status(), PlayerStatus::Folded);
+}
+
+#[test]
+fn cash_game_rebuy_deals_the_player_back_in() {
+    let mut g = Game::new(3, 1000, 5, 10);
+    g.set_buy_in_rules(BuyInRules::cash(1000));
+    g.set_invariant_checks(true);
+    bust_seat_one(&mut g);
+
+    assert_eq!(g.rebuy(1, 1001), Err(BuyInError::AboveMaxBuyIn { max: 1000, stack: 1001 }));
+    assert_eq!(g.rebuy(0, 100), Err(BuyInError::NotBusted(0)));
+    assert_eq!(g.top_up(1, 100), Err(BuyInError::Busted(1)));
+    assert_eq!(g.rebuy(1, 0), Err(BuyInError::Seat(SeatError::ZeroBuyIn)));
+    g.rebuy(1, 600).unwrap();
+
+    g.new_hand().unwrap();
+    assert_eq!(g.players()[1].status(), PlayerStatus::Active);
+    assert!(g.players()[1].hole().is_some());
+    assert_eq!(g.rebuy(1, 100), Err(BuyInError::Seat(SeatError::HandInProgress)));
+    assert_eq!(g.check_invariants(), Ok(()));
+    assert_eq!(g.ledger().player("P2").unwrap().rebuys, 600);
+}
+
+#[test]
+fn top_ups_respect_the_maximum_buy_in() {
+    let mut g = Game::new(3, 1000, 5, 10);
+    g.set_buy_in_rules(BuyInRules::cash(1500));
+    g.top_up(2, 500).unwrap();
+    assert_eq!(g.top_up(2, 1), Err(BuyInError::AboveMaxBuyIn { max: 1500, stack: 1501 }));
+    assert_eq!(g.players()[2].stack(), 1500);
+    assert_eq!(g.ledger().player("P3").unwrap().invested(), 1500);
+}
+
+#[test]
+fn auto_top_up_refills_stacks_before_each_hand() {
+    let mut g = Game::new(3, 1000, 5, 10);
+    g.set_buy_in_rules(BuyInRules::cash(1000).with_auto_top_up());
+    bust_seat_one(&mut g);
+    g.sit_out(2).unwrap();
+    let stacks: Vec<u64> = g.players().iter().map(|p| p.stack()).collect();
+    g.new_hand().unwrap();
+    assert_eq!(g.players()[1].status(), PlayerStatus::Active);
+    let after: Vec<u64> = g.players().iter().map(|p| p.stack() + p.contributed()).collect();
+    assert!(stacks[0] > 1000);
+    assert_eq!(after, vec![stacks[0], 1000, stacks[2]], "seat 0 is over the cap, seat 2 sits out");
+    assert_eq!(g.ledger().player("P2").unwrap().rebuys, 1000);
+    assert_eq!(g.check_invariants(), Ok(()));
+}
+
+#[test]
+fn tournament_rebuys_close_after_the_rebuy_period() {
+    let mut g = Game::new(3, 1000, 5, 10);
+    g.set_buy_in_rules(BuyInRules::tournament(2, 1500));
+    assert_eq!(g.add_on(0), Ok(1500));
+    assert_eq!(g.add_on(0), Err(BuyInError::AddOnTaken(0)));
+    assert_eq!(g.top_up(0, 100), Err(BuyInError::TopUpsNotAllowed));
+
+    bust_seat_one(&mut g);
+    g.rebuy(1, 1000).unwrap();
+    g.new_hand().unwrap();
+    g.action_fold().unwrap();
+    g.action_fold().unwrap();
+    assert_eq!(g.add_on(2), Err(BuyInError::RebuyPeriodOver(2)));
+
+    let p1 = g.ledger().player("P1").unwrap();
+    assert_eq!((p1.add_ons, p1.invested()), (1500, 2500));
+}
+
+#[test]
+fn snapshots_keep_the_buy_in_rules() {
+    let mut g = Game::new(2, 1000, 5, 10);
+    g.set_buy_in_rules(BuyInRules::tournament(10, 500));
+    let restored = Game::restore(g.snapshot()).unwrap();
+    assert_eq!(restored.buy_in_rules(), g.buy_in_rules());
+}
+
+#[test]
+fn buying_chips_ends_undo_for_the_finished_hand() {
+    let mut g = Game::new(3, 1000, 5, 10);
+    g.set_buy_in_rules(BuyInRules::cash(1500));
+    g.set_invariant_checks(true);
+    bust_seat_one(&mut g);
+    assert!(g.undo_depth() > 0);
+    g.rebuy(1, 1000).unwrap();
+    assert!(!g.undo());
+    assert_eq!(g.check_invariants(), Ok(()));
+
+    g.new_hand().unwrap();
+    g.action_fold().unwrap();
+    g.action_fold().unwrap();
+    g.top_up(1, 100).unwrap();
+    assert!(!g.undo());
+    assert_eq!(g.check_invariants(), Ok(()));
+}
+
+#[test]
+fn taking_an_add_on_ends_undo_for_the_finished_hand() {
+    let mut g = Game::new(3, 1000, 5, 10);
+    g.set_buy_in_rules(BuyInRules::tournament(2, 1500));
+    g.new_hand().unwrap();
+    g.action_fold().unwrap();
+    g.action_fold().unwrap();
+    g.add_on(0).unwrap();
+    assert!(!g.undo());
+    assert_eq!(g.check_invariants(), Ok(()));
+    assert_eq!(g.ledger().player("P1").unwrap().add_ons, 1500);
+}
+
+#[test]
+fn a_seated_name_cannot_join_again() {
+    let mut g = Game::empty_table(3, 1000, 5, 10);
+    g.join(0, "Alice", 1000).unwrap();
+    assert_eq!(g.join(1, "Alice", 1000), Err(SeatError::NameTaken("Alice".to_string())));
+    g.leave(0).unwrap();
+    g.join(1, "Alice", 1000).unwrap();
+}
+
+#[test]
+fn scripted_hands_deal_players_the_auto_top_up_brings_back() {
+    let mut g = Game::new(3, 1000, 5, 10);
+    g.set_buy_in_rules(BuyInRules::cash(1000).with_auto_top_up());
+    bust_seat_one(&mut g);
+    let script = DealScript::new()
+        .hole(1, "Qs Qh".parse().unwrap())
+        .board(parse_cards("2c 7d 9s 3h 4c").unwrap());
+    g.new_hand_scripted(&script).unwrap();
+    assert_eq!(g.players()[1].hole(), Some("Qs Qh".parse().unwrap()));
+    assert_eq!(g.players()[1].stack() + g.players()[1].contributed(), 1000);
+    while g.street() != Street::Showdown {
+        g.action_check_call().unwrap();
+    }
+    assert_eq!(g.board().as_slice(), parse_cards("2c 7d 9s 3h 4c").unwrap());
+}
@@ -7,7 +7,7 @@ fn mk_game(n: usize) -> Game {
 #[test]
 fn postflop_starts_left_of_dealer_skipping_ineligible() {
     let mut g = mk_game(5);
-    g.new_hand();
+    g.new_hand().unwrap();
     // Force to flop immediately: everyone checks/calls until street ends
     // Preflop UTG..BB acts: with blinds posted, to_call for UTG=10
     // UTG..CO fold to speed up; BB checks (call 0)
@@ -21,7 +21,7 @@ fn postflop_starts_left_of_dealer_skipping_ineligible() {
 #[test]
 fn preflop_no_raise_everyone_acts_before_flop() {
     let mut g = mk_game(5);
-    g.new_hand();
+    g.new_hand().unwrap();
     // Everyone calls to match BB; sequence UTG, MP, CO, BTN, SB
     for _ in 0..5 {
         g.action_check_call().unwrap();
@@ -33,7 +33,7 @@ fn preflop_no_raise_everyone_acts_before_flop() {
 #[test]
 fn raise_round_ends_when_returns_to_last_raiser_with_matched_bets() {
     let mut g = mk_game(5);
-    g.new_hand();
+    g.new_hand().unwrap();
     // UTG raises min
     g.action_raise_min().unwrap();
     // Others call around to UTG
@@ -46,7 +46,7 @@ fn raise_round_ends_when_returns_to_last_raiser_with_matched_bets() {
 #[test]
 fn heads_up_blinds_and_preflop_order() {
     let mut g = mk_game(2);
-    g.new_hand();
+    g.new_hand().unwrap();
     let dealer = g.dealer();
     let sb = dealer;
     let bb = (dealer + 1) % g.players().len();
@@ -62,7 +62,7 @@ fn heads_up_blinds_and_preflop_order() {
 #[test]
 fn heads_up_postflop_starts_non_dealer() {
     let mut g = mk_game(2);
-    g.new_hand();
+    g.new_hand().unwrap();
     let dealer = g.dealer();
     let non_dealer = (dealer + 1) % g.players().len();
 
@@ -0,0 +1,116 @@
+use poker_rs::cards::{parse_cards, Card};
+use poker_rs::deck::Deck;
+use poker_rs::game::{DealError, DealScript, Game, Street};
+
+mod common;
+use common::{check_down, hole};
+
+fn board(s: &str) -> Vec<Card> {
+    parse_cards(s).expect("valid board")
+}
+
+fn shove(game: &mut Game) {
+    let p = &game.players()[game.current()];
+    let all_in = p.bet() + p.stack();
+    if game.current_bet() == 0 {
+        game.action_bet(all_in).unwrap();
+    } else if all_in > game.current_bet() {
+        game.action_raise_to(all_in).unwrap();
+    } else {
+        game.action_check_call().unwrap();
+    }
+}
+
+#[test]
+fn scripted_hand_deals_requested_cards() {
+    let mut game = Game::with_seed(3, 1000, 5, 10, 11);
+    let script = DealScript::new()
+        .hole(0, hole("As Ah"))
+        .hole(2, hole("Ks Kh"))
+        .board(board("2c 7d 9s Jh Qc"));
+    game.new_hand_scripted(&script).unwrap();
+
+    assert_eq!(game.players()[0].hole(), Some(hole("As Ah")));
+    assert_eq!(game.players()[2].hole(), Some(hole("Ks Kh")));
+    let seat1 = game.players()[1].hole().expect("unscripted seat still dealt");
+    for c in seat1.as_array().unwrap() {
+        assert!(!board("As Ah Ks Kh 2c 7d 9s Jh Qc").contains(&c));
+    }
+
+    check_down(&mut game);
+    assert_eq!(game.board().as_slice(), board("2c 7d 9s Jh Qc").as_slice());
+    assert_eq!(game.winners(), &[0]);
+}
+
+#[test]
+fn scripted_split_pot_divides_evenly() {
+    let mut game = Game::new(3, 1000, 5, 10);
+    let script = DealScript::new()
+        .hole(0, hole("7h 3d"))
+        .hole(1, hole("Th 4s"))
+        .hole(2, hole("Td 5s"))
+        .board(board("Ac Kd Qh Js 2c"));
+    game.new_hand_scripted(&script).unwrap();
+    check_down(&mut game);
+
+    let stacks: Vec<u64> = game.players().iter().map(|p| p.stack()).collect();
+    assert_eq!(stacks, vec![990, 1005, 1005]);
+}
+
+#[test]
+fn scripted_all_in_builds_side_pot() {
+    let mut game = Game::new(3, 1000, 5, 10);
+    // Hand 1: seats 1 and 2 chop, leaving seat 0 as the short stack.
+    let chop = DealScript::new()
+        .hole(0, hole("7h 3d"))
+        .hole(1, hole("Th 4s"))
+        .hole(2, hole("Td 5s"))
+        .board(board("Ac Kd Qh Js 2c"));
+    game.new_hand_scripted(&chop).unwrap();
+    check_down(&mut game);
+
+    // Hand 2: everyone all-in; the short stack holds the best hand.
+    let all_in = DealScript::new()
+        .hole(0, hole("As Ah"))
+        .hole(1, hole("Ks Kh"))
+        .hole(2, hole("Qs Qh"))
+        .board(board("2c 7d 9s 3h 4c"));
+    game.new_hand_scripted(&all_in).unwrap();
+    while !matches!(game.street(), Street::Showdown) {
+        shove(&mut game);
+    }
+
+    let stacks: Vec<u64> = game.players().iter().map(|p| p.stack()).collect();
+    assert_eq!(stacks, vec![2970, 30, 0], "main pot to seat 0, side pot to seat 1");
+}
+
+#[test]
+fn stacked_deck_is_dealt_in_seat_order() {
+    let mut order = board("As Ah Ks Kh Qs Qh 2c 7d 9s 3h 4c");
+    let mut rest = Deck::standard();
+    rest.shuffle_seeded(1);
+    let used = order.clone();
+    order.extend(rest.draw_order().into_iter().filter(|c| !used.contains(c)));
+
+    let mut game = Game::new(3, 1000, 5, 10);
+    game.new_hand_with_deck(Deck::from_draw_order(order).unwrap()).unwrap();
+    assert_eq!(game.players()[0].hole(), Some(hole("As Ah")));
+    assert_eq!(game.players()[1].hole(), Some(hole("Ks Kh")));
+    assert_eq!(game.players()[2].hole(), Some(hole("Qs Qh")));
+    check_down(&mut game);
+    assert_eq!(game.board().as_slice(), board("2c 7d 9s 3h 4c").as_slice());
+}
+
+#[test]
+fn invalid_scripts_are_rejected() {
+    let mut game = Game::new(2, 1000, 5, 10);
+    let dup = DealScript::new().hole(0, hole("As Ah")).board(board("As 2c 3d"));
+    assert!(matches!(game.new_hand_scripted(&dup), Err(DealError::DuplicateCard(_))));
+
+    let seat = DealScript::new().hole(5, hole("As Ah"));
+    assert!(matches!(
+        game.new_hand_scripted(&seat),
+        Err(DealError::SeatOutOfRange { seat: 5, num_players: 2 })
+    ));
+    assert_eq!(game.hand_number(), 0, "failed scripts leave the game untouched");
+}
@@ -0,0 +1,102 @@
+use poker_rs::game::{Game, PlayerStatus, SeatError, Street};
+
+fn dealt(g: &Game) -> Vec<usize> {
+    (0..g.players().len()).filter(|&i| g.players()[i].hole().is_some()).collect()
+}
+
+#[test]
+fn empty_table_deals_only_seated_players() {
+    let mut g = Game::empty_table(6, 1000, 5, 10);
+    assert_eq!(g.seated_count(), 0);
+    g.join(1, "Alice", 500).unwrap();
+    g.join(3, "Bob", 700).unwrap();
+    g.join(5, "Cara", 900).unwrap();
+    assert_eq!(g.players()[3].name(), "Bob");
+    assert_eq!(g.players()[3].stack(), 700);
+
+    g.new_hand().unwrap();
+    assert_eq!(dealt(&g), vec![1, 3, 5]);
+    let seated = [1, 3, 5];
+    assert!(seated.contains(&g.dealer()));
+    assert!(seated.contains(&g.sb_pos().unwrap()));
+    assert!(seated.contains(&g.bb_pos().unwrap()));
+    assert!(seated.contains(&g.current()));
+    assert_eq!(g.players()[0].status(), PlayerStatus::Empty);
+}
+
+#[test]
+fn button_and_blinds_skip_empty_and_sitting_out_seats() {
+    let mut g = Game::empty_table(6, 1000, 5, 10);
+    for seat in [0, 2, 3, 5] {
+        g.join(seat, &format!("S{seat}"), 1000).unwrap();
+    }
+    g.sit_out(3).unwrap();
+    let mut buttons = Vec::new();
+    for _ in 0..4 {
+        g.new_hand().unwrap();
+        assert_eq!(dealt(&g), vec![0, 2, 5]);
+        let dealer = g.dealer();
+        buttons.push(dealer);
+        let sb = g.sb_pos().unwrap();
+        let bb = g.bb_pos().unwrap();
+        assert_ne!(sb, dealer);
+        assert!(![1, 3, 4].contains(&sb) && ![1, 3, 4].contains(&bb));
+        while g.street() != Street::Showdown {
+            g.action_fold().unwrap();
+        }
+    }
+    assert_eq!(buttons, vec![2, 5, 0, 2]);
+}
+
+#[test]
+fn heads_up_with_sitting_out_player_uses_dealer_as_small_blind() {
+    let mut g = Game::new(3, 1000, 5, 10);
+    g.sit_out(1).unwrap();
+    g.new_hand().unwrap();
+    assert_eq!(g.sb_pos(), Some(g.dealer()));
+    assert_ne!(g.bb_pos(), Some(1));
+    assert!(g.players()[1].hole().is_none());
+    assert_eq!(g.players()[1].status(), PlayerStatus::SittingOut);
+}
+
+#[test]
+fn sit_in_and_leave_take_effect_next_hand() {
+    let mut g = Game::new(3, 1000, 5, 10);
+    g.sit_out(2).unwrap();
+    g.new_hand().unwrap();
+    assert_eq!(dealt(&g), vec![0, 1]);
+    while g.street() != Street::Showdown {
+        g.action_fold().unwrap();
+    }
+    g.sit_in(2).unwrap();
+    let stack = g.players()[0].stack();
+    assert_eq!(g.leave(0).unwrap(), stack);
+    assert!(!g.players()[0].is_seated());
+    g.new_hand().unwrap();
+    assert_eq!(dealt(&g), vec![1, 2]);
+}
+
+#[test]
+fn seating_changes_are_validated() {
+    let mut g = Game::empty_table(3, 1000, 5, 10);
+    assert_eq!(g.join(3, "X", 100), Err(SeatError::SeatOutOfRange { seat: 3, num_seats: 3 }));
+    assert_eq!(g.join(0, "X", 0), Err(SeatError::ZeroBuyIn));
+    assert_eq!(g.leave(0), Err(SeatError::SeatEmpty(0)));
+    assert_eq!(g.sit_out(0), Err(SeatError::SeatEmpty(0)));
+    g.join(0, "X", 100).unwrap();
+    assert_eq!(g.join(0, "Y", 100), Err(SeatError::SeatTaken(0)));
+    g.join(1, "Y", 100).unwrap();
+    g.new_hand().unwrap();
+    assert_eq!(g.join(2, "Z", 100), Err(SeatError::HandInProgress));
+    assert_eq!(g.leave(0), Err(SeatError::HandInProgress));
+}
+
+#[test]
+fn fewer_than_two_seated_players_posts_no_blinds() {
+    let mut g = Game::empty_table(4, 1000, 5, 10);
+    g.join(2, "Solo", 1000).unwrap();
+    g.new_hand().unwrap();
+    assert_eq!(g.street(), Street::Showdown);
+    assert_eq!(g.sb_pos(), None);
+    assert_eq!(g.pot(), 0);
+}
@@ -0,0 +1,57 @@
+use poker_rs::game::{derive_hand_seed, Game};
+
+fn holes(game: &Game) -> Vec<Option<poker_rs::hand::HoleCards>> {
+    game.players().iter().map(|p| p.hole()).collect()
+}
+
+#[test]
+fn same_master_seed_deals_same_hands() {
+    let mut a = Game::with_seed(4, 1000, 5, 10, 1234);
+    let mut b = Game::with_seed(4, 1000, 5, 10, 1234);
+    for _ in 0..5 {
+        a.new_hand().unwrap();
+        b.new_hand().unwrap();
+        assert_eq!(a.hand_number(), b.hand_number());
+        assert_eq!(a.hand_seed(), b.hand_seed());
+        assert_eq!(holes(&a), holes(&b));
+        while !matches!(a.street(), poker_rs::game::Street::Showdown) {
+            a.action_check_call().unwrap();
+            b.action_check_call().unwrap();
+        }
+        assert_eq!(a.board(), b.board());
+    }
+}
+
+#[test]
+fn hand_seeds_follow_master_seed_and_hand_number() {
+    let mut g = Game::with_seed(3, 1000, 5, 10, 99);
+    assert_eq!(g.seed(), 99);
+    assert_eq!(g.hand_number(), 0);
+    g.new_hand().unwrap();
+    assert_eq!(g.hand_number(), 1);
+    assert_eq!(g.hand_seed(), derive_hand_seed(99, 1));
+    let first = g.hand_seed();
+    g.new_hand().unwrap();
+    assert_eq!(g.hand_seed(), derive_hand_seed(99, 2));
+    assert_ne!(g.hand_seed(), first);
+}
+
+#[test]
+fn hand_seed_replays_a_single_hand() {
+    let mut original = Game::with_seed(3, 1000, 5, 10, 7);
+    original.new_hand().unwrap();
+    original.new_hand().unwrap();
+    let reported = original.hand_seed();
+
+    let mut replay = Game::new(3, 1000, 5, 10);
+    replay.new_hand_with_seed(reported).unwrap();
+    assert_eq!(replay.hand_seed(), reported);
+    // Dealer positions differ, but the deck order (and so the set of dealt cards) matches.
+    let mut dealt_a: Vec<_> =
+        holes(&original).into_iter().flatten().flat_map(|h| h.as_array().unwrap()).collect();
+    let mut dealt_b: Vec<_> =
+        holes(&replay).into_iter().flatten().flat_map(|h| h.as_array().unwrap()).collect();
+    dealt_a.sort();
+    dealt_b.sort();
+    assert_eq!(dealt_a, dealt_b);
+}
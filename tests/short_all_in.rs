@@ -0,0 +1,133 @@
+use poker_rs::game::{ActionError, BetRange, Game, Street};
+
+/// Table with blinds 50/100 and the given stacks; seat 1 deals first.
+fn table(stacks: &[u64]) -> Game {
+    let mut g = Game::empty_table(stacks.len(), 1000, 50, 100);
+    for (seat, &stack) in stacks.iter().enumerate() {
+        g.join(seat, &format!("P{seat}"), stack).unwrap();
+    }
+    g.new_hand().unwrap();
+    g
+}
+
+fn limp_to_flop(g: &mut Game) {
+    while g.street() == Street::Preflop {
+        g.action_check_call().unwrap();
+    }
+}
+
+#[test]
+fn short_all_in_does_not_reopen_for_the_bettor() {
+    // A bets 100, B moves all-in for 150, C calls: A may only call or fold.
+    let mut g = table(&[250, 1000, 1000]);
+    limp_to_flop(&mut g);
+    assert_eq!(g.current(), 2);
+    g.action_bet(100).unwrap();
+    g.action_raise_to(150).unwrap();
+    g.action_check_call().unwrap();
+
+    assert_eq!(g.current(), 2);
+    let legal = g.legal_actions().unwrap();
+    assert!(legal.can_call);
+    assert_eq!(legal.call_amount, 50);
+    assert_eq!(legal.raise, None);
+    assert_eq!(legal.all_in, 150);
+    assert_eq!(g.action_raise_to(400), Err(ActionError::RaiseNotReopened));
+    assert_eq!(g.action_raise_min(), Err(ActionError::RaiseNotReopened));
+
+    g.action_check_call().unwrap();
+    assert_eq!(g.street(), Street::Turn, "C is not asked to act again");
+}
+
+#[test]
+fn player_yet_to_act_may_raise_a_short_all_in() {
+    let mut g = table(&[250, 1000, 1000]);
+    limp_to_flop(&mut g);
+    g.action_bet(100).unwrap();
+    g.action_raise_to(150).unwrap();
+    assert_eq!(g.min_raise(), 100, "min raise tracks the last full raise");
+    let legal = g.legal_actions().unwrap();
+    assert_eq!(legal.raise, Some(BetRange { min: 250, max: 900 }));
+}
+
+#[test]
+fn cumulative_short_all_ins_reopen_for_earlier_actors() {
+    // A bets 100, B all-in 150, C all-in 225: A faces 125 more, a full raise.
+    let mut g = table(&[325, 1000, 1000, 250]);
+    limp_to_flop(&mut g);
+    // Seat 1 dealt, so postflop action starts with seat 2.
+    assert_eq!(g.current(), 2);
+    g.action_bet(100).unwrap();
+    g.action_raise_to(150).unwrap();
+    g.action_raise_to(225).unwrap();
+    assert_eq!(g.min_raise(), 100);
+    g.action_check_call().unwrap();
+
+    assert_eq!(g.current(), 2);
+    let legal = g.legal_actions().unwrap();
+    assert_eq!(legal.raise, Some(BetRange { min: 325, max: 900 }));
+    g.action_raise_to(325).unwrap();
+    assert_eq!(g.min_raise(), 100);
+}
+
+#[test]
+fn preflop_short_all_in_over_a_raise() {
+    // UTG raises to 300, the button moves all-in for 400: UTG may only call.
+    let mut g = table(&[1000, 400, 1000, 1000]);
+    assert_eq!(g.current(), 0);
+    g.action_raise_to(300).unwrap();
+    assert_eq!(g.min_raise(), 200);
+    g.action_raise_to(400).unwrap();
+    assert_eq!(g.current_bet(), 400);
+    assert_eq!(g.min_raise(), 200);
+
+    let legal = g.legal_actions().unwrap();
+    assert_eq!(legal.raise, Some(BetRange { min: 600, max: 1000 }));
+    g.action_check_call().unwrap();
+    g.action_check_call().unwrap();
+
+    assert_eq!(g.current(), 0);
+    let legal = g.legal_actions().unwrap();
+    assert_eq!(legal.call_amount, 100);
+    assert_eq!(legal.raise, None);
+    g.action_check_call().unwrap();
+    assert_eq!(g.street(), Street::Flop);
+}
+
+#[test]
+fn full_all_in_raise_reopens_betting() {
+    let mut g = table(&[300, 1000, 1000]);
+    limp_to_flop(&mut g);
+    g.action_bet(100).unwrap();
+    g.action_raise_to(200).unwrap();
+    g.action_check_call().unwrap();
+    assert_eq!(g.current(), 2);
+    let legal = g.legal_actions().unwrap();
+    assert_eq!(legal.raise, Some(BetRange { min: 300, max: 900 }));
+}
+
+#[test]
+fn all_in_bet_below_minimum_does_not_reopen_for_checkers() {
+    let mut g = table(&[150, 1000, 1000]);
+    limp_to_flop(&mut g);
+    g.action_check_call().unwrap();
+    g.action_bet(50).unwrap();
+    assert_eq!(g.current_bet(), 50);
+    assert_eq!(g.min_raise(), 100);
+    g.action_check_call().unwrap();
+
+    assert_eq!(g.current(), 2);
+    assert_eq!(g.legal_actions().unwrap().raise, None);
+    g.action_check_call().unwrap();
+    assert_eq!(g.street(), Street::Turn);
+}
+
+#[test]
+fn small_blind_all_in_heads_up_runs_the_board_out() {
+    // Seat 1 deals and is all-in posting the small blind; nobody can act.
+    let g = table(&[1000, 30]);
+    assert_eq!(g.street(), Street::Showdown);
+    assert_eq!(g.board().len(), 5);
+    assert_eq!(g.players().iter().map(|p| p.stack()).sum::<u64>(), 1030);
+    assert_eq!(g.check_invariants(), Ok(()));
+}
@@ -0,0 +1,209 @@
+use poker_rs::agents::{AgentTable, BotAgent, BotProfile};
+use poker_rs::cards::parse_cards;
+use poker_rs::game::{DealScript, Game, HandHistoryVerb, PlayerStatus, ShowdownError, Street};
+
+mod common;
+use common::hole;
+
+/// Seat 1 deals, so seat 2 is first to act after the flop.
+fn dealt() -> Game {
+    let mut g = Game::new(3, 1000, 5, 10);
+    let script = DealScript::new()
+        .hole(0, hole("As Ah"))
+        .hole(1, hole("Ks Kh"))
+        .hole(2, hole("Qs Qh"))
+        .board(parse_cards("2c 7d 9s 3h 4c").unwrap());
+    g.new_hand_scripted(&script).unwrap();
+    assert_eq!(g.dealer(), 1);
+    while g.street() != Street::River {
+        g.action_check_call().unwrap();
+    }
+    g
+}
+
+fn checked_down() -> Game {
+    let mut g = dealt();
+    while g.street() != Street::Showdown {
+        g.action_check_call().unwrap();
+    }
+    g
+}
+
+fn shows(g: &Game) -> Vec<usize> {
+    g.history_recent(20)
+        .into_iter()
+        .filter(|e| e.verb == HandHistoryVerb::Show)
+        .map(|e| e.seat)
+        .collect()
+}
+
+#[test]
+fn without_a_river_bet_first_seat_left_of_button_shows_first() {
+    let g = checked_down();
+    let result = g.showdown_result().unwrap();
+    let order: Vec<usize> = result.hands.iter().map(|h| h.seat).collect();
+    assert_eq!(order, vec![2, 0, 1]);
+    assert!(!result.hand(2).unwrap().can_muck, "the first hand must be shown");
+    assert!(!result.hand(0).unwrap().can_muck);
+    assert!(result.hand(1).unwrap().can_muck, "kings are beaten by the aces already shown");
+    assert_eq!(g.pending_mucks(), vec![1]);
+}
+
+#[test]
+fn last_river_aggressor_shows_first() {
+    let mut g = dealt();
+    g.action_check_call().unwrap();
+    assert_eq!(g.current(), 0);
+    g.action_bet(20).unwrap();
+    g.action_check_call().unwrap();
+    g.action_check_call().unwrap();
+    assert_eq!(g.street(), Street::Showdown);
+
+    let result = g.showdown_result().unwrap();
+    let order: Vec<usize> = result.hands.iter().map(|h| h.seat).collect();
+    assert_eq!(order, vec![0, 1, 2]);
+    assert_eq!(g.pending_mucks(), vec![1, 2]);
+    assert_eq!(shows(&g), vec![0]);
+}
+
+#[test]
+fn river_bettor_shows_first_after_an_uncalled_excess_is_returned() {
+    let mut g = Game::empty_table(3, 1000, 5, 10);
+    g.join(0, "Deep", 1000).unwrap();
+    g.join(1, "Folder", 1000).unwrap();
+    g.join(2, "Short", 300).unwrap();
+    let script = DealScript::new()
+        .hole(0, hole("As Ah"))
+        .hole(1, hole("Ks Kh"))
+        .hole(2, hole("Qs Qh"))
+        .board(parse_cards("2c 7d 9s 3h 4c").unwrap());
+    g.new_hand_scripted(&script).unwrap();
+    while g.street() != Street::River {
+        g.action_check_call().unwrap();
+    }
+    while g.street() != Street::Showdown {
+        match (g.current(), g.current_bet()) {
+            (0, 0) => g.action_bet(900).unwrap(),
+            (1, bet) if bet > 0 => g.action_fold().unwrap(),
+            _ => g.action_check_call().unwrap(),
+        }
+    }
+    let result = g.showdown_result().unwrap();
+    assert_eq!(result.uncalled, Some((0, 610)));
+    let order: Vec<usize> = result.hands.iter().map(|h| h.seat).collect();
+    assert_eq!(order, vec![0, 2]);
+    assert_eq!(shows(&g), vec![0, 2]);
+}
+
+#[test]
+fn hands_are_tabled_once_a_player_is_all_in() {
+    let mut g = Game::empty_table(3, 1000, 5, 10);
+    g.join(0, "Aces", 1000).unwrap();
+    g.join(1, "Kings", 1000).unwrap();
+    g.join(2, "Short", 100).unwrap();
+    let script = DealScript::new()
+        .hole(0, hole("As Ah"))
+        .hole(1, hole("Ks Kh"))
+        .hole(2, hole("Qs Qh"))
+        .board(parse_cards("2c 7d 9s 3h 4c").unwrap());
+    g.new_hand_scripted(&script).unwrap();
+    while g.street() != Street::Showdown {
+        if g.current() == 2 && g.current_bet() < 100 {
+            g.action_raise_to(100).unwrap();
+        } else {
+            g.action_check_call().unwrap();
+        }
+    }
+    assert_eq!(g.players()[2].status(), PlayerStatus::AllIn);
+    let result = g.showdown_result().unwrap();
+    assert!(result.hands.iter().all(|h| h.shown && !h.can_muck));
+    assert!(g.pending_mucks().is_empty());
+    assert_eq!(g.muck(1), Err(ShowdownError::MuckNotAllowed(1)));
+    for seat in 0..3 {
+        assert!(g.revealed_hole(seat).is_some(), "seat {seat} is tabled");
+    }
+}
+
+#[test]
+fn mucked_hand_stays_hidden() {
+    let mut g = checked_down();
+    assert_eq!(g.revealed_hole(1), None, "undecided hands are not revealed");
+    g.muck(1).unwrap();
+
+    assert_eq!(g.revealed_hole(0), Some(hole("As Ah")));
+    assert_eq!(g.revealed_hole(1), None);
+    let mucked = g.showdown_result().unwrap().hand(1).unwrap();
+    assert!(!mucked.shown);
+    assert_eq!((mucked.hole, mucked.evaluation), (None, None));
+    assert_eq!(g.showdown_categories()[1], None);
+
+    let view = g.player_view(2);
+    assert_eq!(view.seats[0].shown, Some(hole("As Ah")));
+    assert_eq!(view.seats[1].shown, None);
+    let debug = format!("{view:?}");
+    for card in hole("Ks Kh").as_array().unwrap() {
+        assert!(!debug.contains(&format!("{card:?}")), "mucked {card:?} leaked");
+    }
+
+    let history = g.history_recent(20);
+    let muck = history.iter().find(|e| e.verb == HandHistoryVerb::Muck).unwrap();
+    assert_eq!((muck.seat, muck.cards), (1, None));
+    let shown = history.iter().find(|e| e.verb == HandHistoryVerb::Show && e.seat == 0).unwrap();
+    assert_eq!(shown.cards, Some(hole("As Ah")));
+}
+
+#[test]
+fn only_losing_hands_awaiting_a_decision_may_muck() {
+    let mut g = checked_down();
+    assert_eq!(g.muck(0), Err(ShowdownError::MuckNotAllowed(0)));
+    assert_eq!(g.muck(2), Err(ShowdownError::MuckNotAllowed(2)));
+    g.show(1).unwrap();
+    assert_eq!(g.revealed_hole(1), Some(hole("Ks Kh")));
+    assert_eq!(g.muck(1), Err(ShowdownError::MuckNotAllowed(1)));
+    assert_eq!(shows(&g), vec![2, 0, 1]);
+}
+
+#[test]
+fn agents_choose_between_showing_and_mucking() {
+    let mut g = dealt();
+    g.action_check_call().unwrap();
+    g.action_bet(20).unwrap();
+    g.action_check_call().unwrap();
+    g.action_check_call().unwrap();
+
+    let mut table = AgentTable::for_seats(3);
+    table.set_agent(1, Some(Box::new(BotAgent::new(BotProfile::default()))));
+    assert!(g.player_view(1).can_muck);
+    assert_eq!(table.on_showdown(&mut g).unwrap(), 2);
+
+    assert_eq!(g.revealed_hole(1), None, "bots muck losing hands");
+    assert_eq!(g.revealed_hole(2), Some(hole("Qs Qh")), "seats without an agent show");
+    assert!(g.pending_mucks().is_empty());
+    assert_eq!(table.on_showdown(&mut g).unwrap(), 0);
+}
+
+#[test]
+fn side_pot_winner_cannot_muck() {
+    let mut g = Game::empty_table(3, 1000, 5, 10);
+    g.join(0, "Short", 100).unwrap();
+    g.join(1, "Mid", 300).unwrap();
+    g.join(2, "Deep", 1000).unwrap();
+    let script = DealScript::new()
+        .hole(0, hole("As Ah"))
+        .hole(1, hole("Ks Kh"))
+        .hole(2, hole("Qs Qh"))
+        .board(parse_cards("2c 7d 9s 3h 4c").unwrap());
+    g.new_hand_scripted(&script).unwrap();
+    while g.street() != Street::Showdown {
+        let p = &g.players()[g.current()];
+        let all_in = p.bet() + p.stack();
+        if all_in > g.current_bet() {
+            g.action_raise_to(all_in).unwrap();
+        } else {
+            g.action_check_call().unwrap();
+        }
+    }
+    let result = g.showdown_result().unwrap();
+    assert!(!result.hand(1).unwrap().can_muck);
+    assert_eq!(g.revealed_hole(1), Some(hole("Ks Kh")));
+}
@@ -0,0 +1,111 @@
+use poker_rs::cards::parse_cards;
+use poker_rs::evaluator::Category;
+use poker_rs::game::{DealScript, Game, PotShare, Street};
+
+mod common;
+use common::hole;
+
+fn shove(game: &mut Game) {
+    let p = &game.players()[game.current()];
+    let all_in = p.bet() + p.stack();
+    if all_in > game.current_bet() {
+        game.action_raise_to(all_in).unwrap();
+    } else {
+        game.action_check_call().unwrap();
+    }
+}
+
+fn three_way_all_in() -> Game {
+    let mut game = Game::empty_table(3, 1000, 5, 10);
+    game.join(0, "Short", 100).unwrap();
+    game.join(1, "Mid", 300).unwrap();
+    game.join(2, "Deep", 1000).unwrap();
+    let script = DealScript::new()
+        .hole(0, hole("As Ah"))
+        .hole(1, hole("Ks Kh"))
+        .hole(2, hole("Qs Qh"))
+        .board(parse_cards("2c 7d 9s 3h 4c").unwrap());
+    game.new_hand_scripted(&script).unwrap();
+    while game.street() != Street::Showdown {
+        shove(&mut game);
+    }
+    game
+}
+
+#[test]
+fn side_pots_list_amounts_eligibility_and_winners() {
+    let game = three_way_all_in();
+    let result = game.showdown_result().expect("hand finished");
+
+    assert_eq!(result.uncalled, Some((2, 700)));
+    assert_eq!(result.pots.len(), 2);
+    assert_eq!(result.pots[0].amount, 300);
+    assert_eq!(result.pots[0].eligible, vec![0, 1, 2]);
+    assert_eq!(result.pots[0].winners, vec![PotShare { seat: 0, amount: 300 }]);
+    assert_eq!(result.pots[1].amount, 400);
+    assert_eq!(result.pots[1].eligible, vec![1, 2]);
+    assert_eq!(result.pots[1].winners, vec![PotShare { seat: 1, amount: 400 }]);
+
+    assert_eq!(result.won_by(0), 300);
+    assert_eq!(result.won_by(1), 400);
+    assert_eq!(result.won_by(2), 0);
+    assert_eq!(game.players()[2].stack(), 700);
+}
+
+#[test]
+fn contenders_show_full_evaluations() {
+    let game = three_way_all_in();
+    let result = game.showdown_result().unwrap();
+    assert_eq!(result.hands.len(), 3);
+    for hand in &result.hands {
+        assert!(hand.shown);
+        assert_eq!(hand.hole, game.players()[hand.seat].hole());
+        let eval = hand.evaluation.expect("complete board");
+        assert_eq!(eval.category, Category::Pair);
+    }
+    let aces = result.hand(0).unwrap().evaluation.unwrap();
+    assert!(aces.best_five.contains(&"As".parse().unwrap()));
+    assert!(aces > result.hand(1).unwrap().evaluation.unwrap());
+}
+
+#[test]
+fn split_pot_records_each_share() {
+    let mut game = Game::with_seed(2, 1000, 5, 10, 3);
+    let script = DealScript::new()
+        .hole(0, hole("Ac 2d"))
+        .hole(1, hole("Ad 3c"))
+        .board(parse_cards("Ks Qs Js Ts 9h").unwrap());
+    game.new_hand_scripted(&script).unwrap();
+    while game.street() != Street::Showdown {
+        game.action_check_call().unwrap();
+    }
+    let result = game.showdown_result().unwrap();
+    assert_eq!(result.pots.len(), 1);
+    let mut shares = result.pots[0].winners.clone();
+    shares.sort_by_key(|s| s.seat);
+    assert_eq!(shares, vec![PotShare { seat: 0, amount: 10 }, PotShare { seat: 1, amount: 10 }]);
+}
+
+#[test]
+fn uncontested_winner_does_not_show() {
+    let mut game = Game::new(3, 1000, 5, 10);
+    game.new_hand().unwrap();
+    game.action_raise_to(30).unwrap();
+    game.action_fold().unwrap();
+    game.action_fold().unwrap();
+    let result = game.showdown_result().unwrap();
+
+    assert_eq!(result.hands.len(), 1);
+    let winner = result.hands[0].seat;
+    assert!(!result.hands[0].shown);
+    assert_eq!(result.uncalled, Some((winner, 20)));
+    assert_eq!(result.won_by(winner), 25);
+}
+
+#[test]
+fn result_is_cleared_by_next_hand() {
+    let mut game = three_way_all_in();
+    assert!(game.showdown_result().is_some());
+    game.new_hand().unwrap();
+    assert!(game.showdown_result().is_none());
+}
@@ -0,0 +1,113 @@
+use poker_rs::agents::Action;
+use poker_rs::game::{Game, Street};
+use poker_rs::invariants::InvariantViolation;
+use poker_rs::snapshot::{GameSnapshot, SnapshotError, SNAPSHOT_VERSION};
+
+fn mid_hand_game() -> Game {
+    let mut g = Game::with_seed(4, 1000, 5, 10, 2024);
+    g.new_hand().unwrap();
+    let seat = g.current();
+    g.act(seat, Action::RaiseTo(30)).unwrap();
+    while g.street() == Street::Preflop {
+        let seat = g.current();
+        g.act(seat, Action::CheckCall).unwrap();
+    }
+    let seat = g.current();
+    g.act(seat, Action::Bet(40)).unwrap();
+    g
+}
+
+#[test]
+fn restored_game_continues_identically() {
+    let mut original = mid_hand_game();
+    let json = original.snapshot().to_json().unwrap();
+    let mut restored = Game::restore(GameSnapshot::from_json(&json).unwrap()).unwrap();
+    assert_eq!(restored.snapshot(), original.snapshot());
+
+    for _ in 0..3 {
+        while original.street() != Street::Showdown {
+            let seat = original.current();
+            original.act(seat, Action::CheckCall).unwrap();
+            restored.act(seat, Action::CheckCall).unwrap();
+            assert_eq!(restored.snapshot(), original.snapshot());
+        }
+        let json = original.snapshot().to_json().unwrap();
+        restored = Game::restore(GameSnapshot::from_json(&json).unwrap()).unwrap();
+        assert!(restored.showdown_result().is_some());
+        assert_eq!(restored.showdown_result(), original.showdown_result());
+        assert_eq!(restored.snapshot(), original.snapshot());
+        original.new_hand().unwrap();
+        restored.new_hand().unwrap();
+        assert_eq!(restored.snapshot(), original.snapshot());
+    }
+}
+
+#[test]
+fn snapshot_round_trips_through_disk() {
+    let game = mid_hand_game();
+    let path = std::env::temp_dir().join(format!("poker-rs-snapshot-{}.json", std::process::id()));
+    game.snapshot().save(&path).unwrap();
+    let loaded = GameSnapshot::load(&path).unwrap();
+    std::fs::remove_file(&path).ok();
+    assert_eq!(loaded, game.snapshot());
+}
+
+#[test]
+fn newer_versions_are_rejected() {
+    let json = mid_hand_game().snapshot().to_json().unwrap();
+    let bumped = json.replacen(
+        &format!("\"version\": {SNAPSHOT_VERSION}"),
+        &format!("\"version\": {}", SNAPSHOT_VERSION + 1),
+        1,
+    );
+    let err = GameSnapshot::from_json(&bumped).unwrap_err();
+    assert!(
+        matches!(err, SnapshotError::UnsupportedVersion { found, .. } if found == SNAPSHOT_VERSION + 1)
+    );
+}
+
+#[test]
+fn duplicated_cards_are_rejected() {
+    let mut snap = mid_hand_game().snapshot();
+    let card = snap.board[0];
+    snap.deck.push(card);
+    assert!(matches!(Game::restore(snap), Err(SnapshotError::DuplicateCard(c)) if c == card));
+}
+
+#[test]
+fn undo_history_is_not_saved() {
+    let mut original = mid_hand_game();
+    assert!(original.undo_depth() > 0);
+    let mut restored = Game::restore(original.snapshot()).unwrap();
+    assert_eq!(restored.undo_depth(), 0);
+    assert!(!restored.undo());
+    assert_eq!(restored.snapshot(), original.snapshot());
+    assert!(original.undo());
+}
+
+#[test]
+fn invariant_state_is_saved() {
+    let mut g = mid_hand_game();
+    g.set_invariant_checks(true);
+    let mut snap = g.snapshot();
+    let repaired = InvariantViolation::PotRepaired { pot: 90, contributed: 100 };
+    snap.violations.push(repaired.clone());
+
+    let json = snap.to_json().unwrap();
+    let restored = Game::restore(GameSnapshot::from_json(&json).unwrap()).unwrap();
+    assert!(restored.invariant_checks());
+    assert_eq!(restored.check_invariants(), Err(repaired));
+    assert_eq!(restored.snapshot(), snap);
+}
+
+#[test]
+fn chips_that_do_not_add_up_are_rejected() {
+    let mut snap = mid_hand_game().snapshot();
+    snap.players[0].stack += 5;
+    assert!(matches!(Game::restore(snap.clone()), Err(SnapshotError::Invalid(_))));
+
+    // Older snapshots did not save the total and take it from the stacks.
+    snap.chips_in_play = None;
+    let restored = Game::restore(snap).unwrap();
+    assert_eq!(restored.check_invariants(), Ok(()));
+}
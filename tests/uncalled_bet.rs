@@ -0,0 +1,137 @@
+use poker_rs::game::{ButtonRule, Game, HandHistoryVerb, Street};
+
+fn play_out(g: &mut Game) {
+    while g.street() != Street::Showdown {
+        g.action_fold().unwrap();
+    }
+}
+
+#[test]
+fn uncalled_raise_is_returned_before_the_pot_is_awarded() {
+    let mut g = Game::new(3, 1000, 5, 10);
+    g.new_hand().unwrap();
+    let raiser = g.current();
+    g.action_raise_to(100).unwrap();
+    g.action_fold().unwrap();
+    g.action_fold().unwrap();
+    assert_eq!(g.street(), Street::Showdown);
+
+    let history = g.history_recent(2);
+    assert_eq!(history[0].seat, raiser);
+    assert_eq!(history[0].verb, HandHistoryVerb::UncalledBet);
+    assert_eq!(history[0].amount, Some(90));
+    assert_eq!(history[1].verb, HandHistoryVerb::Win);
+    assert_eq!(history[1].amount, Some(25));
+    assert_eq!(g.players()[raiser].stack(), 1015);
+}
+
+#[test]
+fn big_blind_walk_returns_the_unmatched_half() {
+    let mut g = Game::new(2, 1000, 5, 10);
+    g.new_hand().unwrap();
+    let bb = g.bb_pos().unwrap();
+    g.action_fold().unwrap();
+
+    let history = g.history_recent(2);
+    assert_eq!(history[0].verb, HandHistoryVerb::UncalledBet);
+    assert_eq!(history[0].amount, Some(5));
+    assert_eq!((history[1].seat, history[1].verb), (bb, HandHistoryVerb::Win));
+    assert_eq!(history[1].amount, Some(10));
+    assert_eq!(g.players()[bb].stack(), 1005);
+}
+
+#[test]
+fn all_in_overbet_returns_excess_over_the_largest_call() {
+    let mut g = Game::empty_table(2, 1000, 5, 10);
+    g.join(0, "Deep", 1000).unwrap();
+    g.join(1, "Short", 300).unwrap();
+    g.new_hand().unwrap();
+    let deep = 0;
+    while g.current() != deep {
+        g.action_check_call().unwrap();
+    }
+    g.action_raise_to(1000).unwrap();
+    g.action_check_call().unwrap();
+    assert_eq!(g.street(), Street::Showdown);
+
+    let uncalled: Vec<_> = g
+        .history_recent(g.history_len())
+        .into_iter()
+        .filter(|e| e.verb == HandHistoryVerb::UncalledBet)
+        .collect();
+    assert_eq!(uncalled.len(), 1);
+    assert_eq!((uncalled[0].seat, uncalled[0].amount), (deep, Some(700)));
+    let total: u64 = g.players().iter().map(|p| p.stack()).sum();
+    assert_eq!(total, 1300);
+    assert!(g.players()[deep].stack() >= 700);
+}
+
+#[test]
+fn called_bets_have_nothing_to_return() {
+    let mut g = Game::new(2, 1000, 5, 10);
+    g.new_hand().unwrap();
+    while g.street() != Street::Showdown {
+        g.action_check_call().unwrap();
+    }
+    assert!(g
+        .history_recent(g.history_len())
+        .iter()
+        .all(|e| e.verb != HandHistoryVerb::UncalledBet));
+}
+
+/// A table where seat 3 has just returned, posting a live big blind and a
+/// dead small blind.
+fn returning_player_table() -> Game {
+    let mut g = Game::new(4, 1000, 5, 10);
+    g.set_button_rule(ButtonRule::Dead);
+    g.new_hand().unwrap();
+    play_out(&mut g);
+    g.sit_out(3).unwrap();
+    for _ in 0..4 {
+        g.new_hand().unwrap();
+        play_out(&mut g);
+    }
+    g.sit_in(3).unwrap();
+    g.new_hand().unwrap();
+    assert_eq!(g.players()[3].contributed(), 15);
+    g
+}
+
+#[test]
+fn dead_small_blind_is_not_returned_as_an_uncalled_bet() {
+    let mut g = returning_player_table();
+
+    while g.street() != Street::Showdown {
+        if g.current() == 3 {
+            g.action_check_call().unwrap();
+        } else {
+            g.action_fold().unwrap();
+        }
+    }
+    assert_eq!(g.winners(), &[3]);
+    assert_eq!(g.showdown_result().unwrap().uncalled, None);
+    assert!(g
+        .history_recent(g.history_len())
+        .iter()
+        .all(|e| e.verb != HandHistoryVerb::UncalledBet));
+    let total: u64 = g.players().iter().map(|p| p.stack()).sum();
+    assert_eq!(total, 4000);
+}
+
+#[test]
+fn dead_small_blind_of_a_folded_player_goes_to_the_winner() {
+    let mut g = returning_player_table();
+    while g.current() != 3 {
+        g.action_check_call().unwrap();
+    }
+    g.action_fold().unwrap();
+    while g.street() != Street::Showdown {
+        g.action_check_call().unwrap();
+    }
+    let result = g.showdown_result().unwrap();
+    let awarded: u64 = result.pots.iter().map(|p| p.amount).sum();
+    assert_eq!(awarded, 45);
+    assert!(result.pots.iter().all(|p| !p.eligible.contains(&3)));
+    let total: u64 = g.players().iter().map(|p| p.stack()).sum();
+    assert_eq!(total, 4000);
+}
@@ -0,0 +1,87 @@
+use poker_rs::game::{Game, Street};
+
+#[test]
+fn undo_restores_exact_state_across_streets() {
+    let mut g = Game::with_seed(3, 1000, 5, 10, 42);
+    g.new_hand().unwrap();
+    let mut before = vec![g.snapshot()];
+    g.action_raise_to(30).unwrap();
+    before.push(g.snapshot());
+    g.action_check_call().unwrap();
+    before.push(g.snapshot());
+    g.action_check_call().unwrap();
+    assert_eq!(g.street(), Street::Flop);
+    assert_eq!(g.board().len(), 3);
+    before.push(g.snapshot());
+    g.action_bet(50).unwrap();
+    assert_eq!(g.undo_depth(), 4);
+
+    while let Some(expected) = before.pop() {
+        assert!(g.undo());
+        assert_eq!(g.snapshot(), expected);
+    }
+    assert!(!g.undo());
+    assert_eq!(g.street(), Street::Preflop);
+    assert!(g.board().is_empty());
+}
+
+#[test]
+fn undone_board_cards_are_dealt_again() {
+    let mut g = Game::with_seed(2, 1000, 5, 10, 7);
+    g.new_hand().unwrap();
+    g.action_check_call().unwrap();
+    g.action_check_call().unwrap();
+    let flop = g.board().clone();
+    assert_eq!(g.undo_n(2), 2);
+    g.action_check_call().unwrap();
+    g.action_check_call().unwrap();
+    assert_eq!(g.board(), &flop);
+}
+
+#[test]
+fn undo_allows_a_different_line() {
+    let mut g = Game::new(2, 1000, 5, 10);
+    g.new_hand().unwrap();
+    let seat = g.current();
+    g.action_fold().unwrap();
+    assert_eq!(g.street(), Street::Showdown);
+    assert!(g.undo());
+    assert_eq!(g.current(), seat);
+    g.action_raise_to(40).unwrap();
+    assert_eq!(g.current_bet(), 40);
+    assert_eq!(g.min_raise(), 30);
+}
+
+#[test]
+fn failed_actions_and_new_hands_are_not_undoable() {
+    let mut g = Game::new(2, 1000, 5, 10);
+    g.new_hand().unwrap();
+    assert!(g.action_bet(50).is_err());
+    assert_eq!(g.undo_depth(), 0);
+    g.action_check_call().unwrap();
+    g.new_hand().unwrap();
+    assert_eq!(g.undo_depth(), 0);
+    assert_eq!(g.undo_n(5), 0);
+}
+
+#[test]
+fn seat_changes_after_the_hand_clear_the_undo_stack() {
+    let mut g = Game::new(3, 1000, 5, 10);
+    g.new_hand().unwrap();
+    g.action_fold().unwrap();
+    g.action_fold().unwrap();
+    assert_eq!(g.street(), Street::Showdown);
+    assert_eq!(g.undo_depth(), 2);
+    let stack = g.players()[2].stack();
+    assert_eq!(g.leave(2).unwrap(), stack);
+    assert_eq!(g.undo_depth(), 0);
+    assert!(!g.undo());
+    g.check_invariants().unwrap();
+
+    g.join(2, "Carol", 1000).unwrap();
+    g.new_hand().unwrap();
+    g.action_fold().unwrap();
+    g.action_fold().unwrap();
+    g.sit_out(0).unwrap();
+    assert!(!g.undo());
+}
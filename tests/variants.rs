@@ -0,0 +1,177 @@
+use poker_rs::cards::{Card, Rank, Suit};
+use poker_rs::deck::Deck;
+use poker_rs::evaluator::Category;
+use poker_rs::game::{DealError, DealScript, Game};
+use poker_rs::hand::Board;
+use poker_rs::variants::{by_name, Holdem, Omaha, ShortDeck, Variant, VariantId};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+mod common;
+use common::{check_down, hole};
+
+fn board(s: &str) -> Board {
+    s.parse().expect("valid board")
+}
+
+fn playing(variant: impl Variant + 'static, n: usize) -> Game {
+    let mut g = Game::new(n, 1000, 5, 10);
+    g.set_variant(Arc::new(variant)).unwrap();
+    g
+}
+
+#[test]
+fn holdem_is_the_default() {
+    let mut g = Game::new(3, 1000, 5, 10);
+    assert_eq!(g.variant().name(), Holdem.name());
+    g.new_hand().unwrap();
+    assert!(g.players().iter().all(|p| p.hole().unwrap().as_slice().len() == 2));
+}
+
+#[test]
+fn omaha_deals_four_distinct_cards_each() {
+    let mut g = playing(Omaha, 6);
+    g.new_hand().unwrap();
+    let cards: HashSet<Card> =
+        g.players().iter().flat_map(|p| p.hole().unwrap().as_slice().to_vec()).collect();
+    assert_eq!(cards.len(), 24);
+    assert_eq!(g.player_view(0).variant, VariantId::Omaha);
+    assert_eq!(g.player_view(0).hole.unwrap().as_slice().len(), 4);
+}
+
+#[test]
+fn omaha_showdown_plays_exactly_two_hole_cards() {
+    // Four aces play as one pair; kings and jacks make two pair.
+    let mut g = playing(Omaha, 2);
+    let script = DealScript::new()
+        .hole(0, hole("As Ac Ad Ah"))
+        .hole(1, hole("Kh Jh 3d 4c"))
+        .board(poker_rs::cards::parse_cards("2s 5s 8s Js Kd").unwrap());
+    g.new_hand_scripted(&script).unwrap();
+    check_down(&mut g);
+
+    let result = g.showdown_result().unwrap();
+    assert_eq!(result.hand(0).unwrap().evaluation.unwrap().category, Category::Pair);
+    assert_eq!(result.hand(1).unwrap().evaluation.unwrap().category, Category::TwoPair);
+    assert_eq!(result.won_by(1), 20);
+    assert_eq!(g.revealed_hole(0).map(|h| h.as_slice().len()), Some(4));
+}
+
+#[test]
+fn scripted_hole_cards_must_match_the_variant() {
+    let mut g = playing(Omaha, 2);
+    let script = DealScript::new().hole(1, hole("As Ah"));
+    assert_eq!(
+        g.new_hand_scripted(&script),
+        Err(DealError::HoleCount { seat: 1, expected: 4, got: 2 })
+    );
+}
+
+#[test]
+fn short_deck_deals_sixes_and_up() {
+    assert_eq!(ShortDeck.deck().len(), 36);
+    let mut g = playing(ShortDeck, 9);
+    g.new_hand().unwrap();
+    check_down(&mut g);
+    let dealt = g.players().iter().flat_map(|p| p.hole().unwrap().as_slice().to_vec());
+    assert!(dealt.chain(g.board().as_slice().to_vec()).all(|c| c.rank() >= Rank::Six));
+
+    let script = DealScript::new().board([Card::new(Rank::Two, Suit::Clubs)]);
+    let two = Card::new(Rank::Two, Suit::Clubs);
+    assert_eq!(g.new_hand_scripted(&script), Err(DealError::CardNotInDeck(two)));
+}
+
+#[test]
+fn short_deck_flush_beats_full_house() {
+    let mut g = playing(ShortDeck, 2);
+    let script = DealScript::new()
+        .hole(0, hole("As Ts"))
+        .hole(1, hole("Kc 9d"))
+        .board(poker_rs::cards::parse_cards("Ks Kd 9s 8s 6h").unwrap());
+    g.new_hand_scripted(&script).unwrap();
+    check_down(&mut g);
+
+    let result = g.showdown_result().unwrap();
+    assert_eq!(result.hand(0).unwrap().evaluation.unwrap().category, Category::Flush);
+    assert_eq!(result.hand(1).unwrap().evaluation.unwrap().category, Category::FullHouse);
+    assert_eq!(result.won_by(0), 20);
+}
+
+#[test]
+fn short_deck_ace_plays_low_in_a_nine_high_straight() {
+    let b = board("8c 7h 6s Kd Qh");
+    let wheel = ShortDeck.evaluate(&hole("As 9d"), &b).unwrap();
+    assert_eq!(wheel.category, Category::Straight);
+    assert_eq!(Holdem.evaluate(&hole("As 9d"), &b).unwrap().category, Category::HighCard);
+
+    let ten_high = ShortDeck.evaluate(&hole("Td 9c"), &b).unwrap();
+    assert!(ShortDeck.compare(&ten_high, &wheel).is_gt());
+}
+
+#[test]
+fn variant_change_waits_for_the_next_hand() {
+    let mut g = Game::new(3, 1000, 5, 10);
+    g.new_hand().unwrap();
+    g.set_variant(Arc::new(Omaha)).unwrap();
+    assert_eq!(g.variant().name(), "Hold'em");
+
+    let restored = Game::restore(g.snapshot()).unwrap();
+    for mut game in [g, restored] {
+        check_down(&mut game);
+        game.new_hand().unwrap();
+        assert_eq!(game.variant().name(), "Omaha");
+        assert_eq!(game.players()[0].hole().unwrap().as_slice().len(), 4);
+    }
+}
+
+#[test]
+fn snapshots_keep_the_variant() {
+    let mut g = playing(Omaha, 3);
+    g.new_hand().unwrap();
+    let restored = Game::restore(g.snapshot()).unwrap();
+    assert_eq!(restored.variant().name(), "Omaha");
+    assert_eq!(restored.players()[0].hole(), g.players()[0].hole());
+}
+
+#[test]
+fn builtin_variants_are_found_by_name() {
+    for name in ["Hold'em", "Omaha", "Short Deck"] {
+        assert_eq!(by_name(name).unwrap().name(), name);
+    }
+}
+
+#[test]
+fn variants_that_cannot_deal_the_table_are_rejected() {
+    let mut g = Game::new(18, 1000, 5, 10);
+    assert_eq!(
+        g.set_variant(Arc::new(ShortDeck)),
+        Err(DealError::DeckTooSmall { needed: 41, available: 36 })
+    );
+    assert_eq!(g.variant().name(), Holdem.name());
+
+    let mut g = Game::new(13, 1000, 5, 10);
+    assert!(matches!(g.set_variant(Arc::new(Omaha)), Err(DealError::DeckTooSmall { .. })));
+    g.set_double_board(true).unwrap();
+
+    let mut g = playing(Omaha, 11);
+    assert_eq!(
+        g.set_double_board(true),
+        Err(DealError::DeckTooSmall { needed: 54, available: 52 })
+    );
+    assert!(!g.double_board());
+}
+
+#[test]
+fn new_hand_fails_when_the_deck_runs_short() {
+    let mut g = Game::new(24, 1000, 5, 10);
+    assert_eq!(g.new_hand(), Err(DealError::DeckTooSmall { needed: 53, available: 52 }));
+    assert_eq!(g.hand_number(), 0);
+
+    let mut g = Game::new(3, 1000, 5, 10);
+    let short = Deck::from_draw_order(Deck::standard().draw_n(10)).unwrap();
+    assert_eq!(
+        g.new_hand_with_deck(short),
+        Err(DealError::DeckTooSmall { needed: 11, available: 10 })
+    );
+    assert!(g.players().iter().all(|p| p.hole().is_none()));
+}